@@ -14,6 +14,65 @@ export type ArciumJobRequest = {
   payload: Buffer;
 };
 
+/** A `Shared` party a circuit re-encrypts an output to. */
+export type QueueRecipient = { publicKey: Buffer; nonce: Buffer };
+
+const isCiphertext = (type: any) => Array.isArray(type?.array) && type.array[0] === 'u8' && type.array[1] === 32;
+
+/**
+ * Reads `{ <name>: { public_key, nonce? } }` from a request's encrypted
+ * inputs. A recipient without a nonce gets a fresh random one.
+ */
+export function parseRecipients(raw: any): Record<string, QueueRecipient> {
+  const recipients: Record<string, QueueRecipient> = {};
+  for (const [name, value] of Object.entries<any>(raw || {})) {
+    const publicKey = Buffer.from(Uint8Array.from(value?.public_key || []));
+    if (publicKey.length !== 32) throw new Error(`Recipient ${name} needs a 32-byte public_key`);
+    const nonce = value.nonce === undefined
+      ? crypto.randomBytes(16)
+      : typeof value.nonce === 'string'
+        ? Buffer.from(value.nonce, 'base64')
+        : Buffer.from(Uint8Array.from(value.nonce));
+    if (nonce.length !== 16) throw new Error(`Recipient ${name} needs a 16-byte nonce`);
+    recipients[name] = { publicKey, nonce };
+  }
+  return recipients;
+}
+
+/**
+ * Lays the ciphertexts out in the shapes the instruction's IDL args expect:
+ * one per `[u8; 32]`, `N` per `[[u8; 32]; N]`, followed by the caller's key
+ * and nonce. `<name>_pub_key`/`<name>_nonce` args come from `recipients`.
+ */
+function queueArgs(idl: any, circuit: string, cts: Uint8Array[], pub: Uint8Array, nonce: Buffer, recipients: Record<string, QueueRecipient> = {}): any[] {
+  const ix = (idl.instructions || []).find((i: any) => i.name === circuit);
+  if (!ix) throw new Error(`Instruction ${circuit} not found in IDL`);
+  let next = 0;
+  const take = () => {
+    if (next >= cts.length) throw new Error(`Too few ciphertexts for ${circuit}`);
+    return Array.from(cts[next++]);
+  };
+  const args = ix.args.slice(1).map((arg: any) => {
+    if (arg.name === 'pub_key') return Array.from(pub);
+    if (arg.name === 'nonce') return new (anchor as any).BN(deserializeLE(nonce).toString());
+    const shared = /^(.+)_(pub_key|nonce)$/.exec(arg.name);
+    if (shared) {
+      const recipient = recipients[shared[1]];
+      if (!recipient) throw new Error(`${circuit} needs recipient ${shared[1]}`);
+      return shared[2] === 'pub_key'
+        ? Array.from(recipient.publicKey)
+        : new (anchor as any).BN(deserializeLE(recipient.nonce).toString());
+    }
+    if (isCiphertext(arg.type)) return take();
+    if (Array.isArray(arg.type?.array) && isCiphertext(arg.type.array[0])) {
+      return Array.from({ length: arg.type.array[1] }, take);
+    }
+    throw new Error(`${circuit} argument ${arg.name} cannot be filled from ciphertexts`);
+  });
+  if (next !== cts.length) throw new Error(`${circuit} takes ${next} ciphertexts, got ${cts.length}`);
+  return args;
+}
+
 export class ArciumClient {
   private provider: anchor.AnchorProvider;
 
@@ -78,7 +137,7 @@ export class ArciumClient {
     return { tx, computationOffset: computationOffset.toString(), nonceB64: Buffer.from(nonce).toString('base64'), clientPubKeyB64: Buffer.from(pub).toString('base64') };
   }
 
-  async submitQueue(input: { mxeProgramId: string; compDefOffset: number; circuit: string; accounts?: Record<string, string>; clientPublicKey: Buffer; clientNonce: Buffer; ciphertexts?: Array<number[] | Uint8Array>; payload?: Buffer }): Promise<{ tx: string; computationOffset: string; nonceB64: string; clientPubKeyB64: string }> {
+  async submitQueue(input: { mxeProgramId: string; compDefOffset: number; circuit: string; accounts?: Record<string, string>; clientPublicKey: Buffer; clientNonce: Buffer; recipients?: Record<string, QueueRecipient>; ciphertexts?: Array<number[] | Uint8Array>; payload?: Buffer }): Promise<{ tx: string; computationOffset: string; nonceB64: string; clientPubKeyB64: string }> {
     try {
       console.log('[Arcium Client] submitQueue called with:', {
         mxeProgramId: input.mxeProgramId,
@@ -251,7 +310,7 @@ export class ArciumClient {
       const { blockhash, lastValidBlockHeight } = await provider.connection.getLatestBlockhash('finalized');
       console.log('[Arcium Client] Latest blockhash:', blockhash);
 
-      const ciphertextArgs = cts.map((ct) => Array.from(ct));
+      const args = queueArgs(idl, input.circuit, cts, pub, nonce, input.recipients);
       const heapBytesRaw = process.env.ARCIUM_HEAP_FRAME_BYTES;
       const heapBytes = heapBytesRaw ? parseInt(heapBytesRaw, 10) : DEFAULT_HEAP_FRAME_BYTES;
       const heapInstruction = Number.isFinite(heapBytes) && heapBytes > 0 ? ComputeBudgetProgram.requestHeapFrame({ bytes: heapBytes }) : undefined;
@@ -262,9 +321,7 @@ export class ArciumClient {
       }
       const tx = await method(
         compOffset,
-        ...ciphertextArgs,
-        Array.from(pub),
-        new (anchor as any).BN(deserializeLE(nonce).toString())
+        ...args
       )
         .accountsPartial(accounts)
         .preInstructions(
@@ -287,9 +344,7 @@ export class ArciumClient {
         try {
           const sim = await (mxeProgram.methods as any)[input.circuit](
             compOffset,
-            ...ciphertextArgs,
-            Array.from(pub),
-            new (anchor as any).BN(deserializeLE(nonce).toString())
+            ...args
           )
             .accountsPartial(accounts)
             .preInstructions(
@@ -37,7 +37,8 @@ async function main() {
     const circuit = base.endsWith('_devnet.arcis')
       ? base.replace('_devnet.arcis', '')
       : base.replace('.arcis', '')
-    const uploadName = `${circuit}_devnet.arcis`
+    // Kept as built: flaek_mxe registers <base-url>/<artifact file name>.
+    const uploadName = base
     const rel = dirPrefix ? `${dirPrefix}/${uploadName}` : uploadName
     form.append('file', fs.createReadStream(abs), { filepath: rel })
   }
@@ -71,15 +72,17 @@ async function main() {
     const circuit = base.endsWith('_devnet.arcis')
       ? base.replace('_devnet.arcis', '')
       : base.replace('.arcis', '')
-    const uploadName = `${circuit}_devnet.arcis`
+    const uploadName = base
     const rel = dirPrefix ? `${dirPrefix}/${uploadName}` : uploadName
     const url = `https://${gateway}/ipfs/${dirCid}/${rel}`
     mapping[circuit] = { url, path: rel, cid: dirCid }
   }
 
   const out = path.join(buildDir, 'circuits_urls_pinata_devnet.json')
-  fs.writeFileSync(out, JSON.stringify({ dirCid, mapping }, null, 2))
+  const baseUrl = `https://${gateway}/ipfs/${dirCid}/${dirPrefix}`
+  fs.writeFileSync(out, JSON.stringify({ dirCid, baseUrl, mapping }, null, 2))
   console.log(`Pinned directory CID: ${dirCid}`)
+  console.log(`CIRCUITS_BASE_URL=${baseUrl}`)
   console.log(`Mapping saved to: ${out}`)
   console.log('Sample:', Object.entries(mapping).slice(0, 3))
 }
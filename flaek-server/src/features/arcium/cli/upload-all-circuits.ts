@@ -30,7 +30,8 @@ async function main() {
   for (const file of files) {
     const base = path.basename(file)
     const circuit = base.endsWith(suffix) ? base.replace(suffix, '') : base.replace('.arcis', '')
-    const publicId = `${circuit}${suffix}`
+    // Kept as built: flaek_mxe registers <base-url>/<artifact file name>.
+    const publicId = base
     const buf = fs.readFileSync(file)
     process.stdout.write(`Uploading ${base} -> ${folder}/${publicId} ... `)
     try {
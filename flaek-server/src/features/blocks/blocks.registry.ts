@@ -23,6 +23,7 @@ export interface BlockDefinition {
   circuit: string; // Arcium circuit name
   inputs: BlockInput[];
   outputs: BlockOutput[];
+  recipients?: string[]; // `Shared` parties passed as encrypted_inputs.recipients
   compDefOffset?: number;
   icon?: string;
   color?: string;
@@ -433,9 +434,9 @@ export const BLOCKS_REGISTRY: BlockDefinition[] = [
   },
   {
     id: 'median',
-    name: 'Median (Approximation)',
+    name: 'Median',
     category: 'statistical',
-    description: 'Returns average as median approximation',
+    description: 'Middle value of up to 10 numbers (mean of the middle pair when count is even)',
     circuit: 'median',
     compDefOffset: 396793464,
     inputs: [
@@ -455,6 +456,7 @@ export const BLOCKS_REGISTRY: BlockDefinition[] = [
     category: 'use_case',
     description: 'Calculate credit score and approval status',
     circuit: 'credit_score',
+    recipients: ['lender'],
     compDefOffset: 974272761,
     inputs: [
       { name: 'income', type: 'u64', description: 'Annual income', required: true },
@@ -483,6 +485,7 @@ export const BLOCKS_REGISTRY: BlockDefinition[] = [
     category: 'use_case',
     description: 'Assess health risk based on lifestyle factors',
     circuit: 'health_risk',
+    recipients: ['insurer'],
     compDefOffset: 3701593828,
     inputs: [
       { name: 'age', type: 'u8', description: 'Age in years', required: true },
@@ -510,14 +513,16 @@ export const BLOCKS_REGISTRY: BlockDefinition[] = [
     id: 'vote_tally',
     name: 'Vote Tally',
     category: 'use_case',
-    description: 'Anonymous voting/survey response',
+    description: 'Count yes votes among up to 10 ballots and reveal the tally',
     circuit: 'vote_tally',
+    recipients: ['tallier'],
     compDefOffset: 1697330667,
     inputs: [
-      { name: 'vote', type: 'u8', description: '0 for no, 1 for yes', required: true, min: 0, max: 1 },
+      { name: 'votes', type: 'array', description: 'Ballots, 0 for no and 1 for yes', required: true, arrayLength: 10 },
+      { name: 'count', type: 'u8', description: 'Number of ballots cast (1-10)', required: true, min: 1, max: 10 },
     ],
     outputs: [
-      { name: 'result', type: 'u8', description: 'Vote value (to be aggregated)' },
+      { name: 'result', type: 'u8', description: 'Number of yes votes, also revealed publicly' },
     ],
     icon: 'Vote',
     color: '#6366F1',
@@ -529,6 +534,7 @@ export const BLOCKS_REGISTRY: BlockDefinition[] = [
     category: 'use_case',
     description: 'Check if value meets or exceeds threshold',
     circuit: 'meets_threshold',
+    recipients: ['verifier'],
     compDefOffset: 2211568153,
     inputs: [
       { name: 'value', type: 'u64', description: 'Value to check', required: true },
@@ -547,6 +553,7 @@ export const BLOCKS_REGISTRY: BlockDefinition[] = [
     category: 'use_case',
     description: 'Calculate weighted average of values',
     circuit: 'weighted_average',
+    recipients: ['recipient'],
     compDefOffset: 4270605415,
     inputs: [
       { name: 'values', type: 'array', description: 'Array of 5 values', required: true, arrayLength: 5 },
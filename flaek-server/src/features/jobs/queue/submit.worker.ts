@@ -5,7 +5,7 @@ import { JobModel } from '@/features/jobs/job.model';
 import { jobRepository } from '@/features/jobs/job.repository';
 import { operationRepository } from '@/features/operations/operation.repository';
 import { DatasetModel } from '@/features/datasets/dataset.model';
-import { ArciumClient } from '@/clients/arcium-client';
+import { ArciumClient, parseRecipients } from '@/clients/arcium-client';
 import { executePipeline } from './pipeline.executor';
 
 export function startSubmitWorker() {
@@ -119,6 +119,7 @@ export function startSubmitWorker() {
         payload: payloadToSend,
         clientPublicKey: clientPublicKeyBuf,
         clientNonce: clientNonceBuf,
+        recipients: parseRecipients(clientEncryptedData.recipients),
       });
 
       console.log(`[Submit Worker] Arcium submission successful for job ${jobId}, tx: ${tx}`);
@@ -1,3 +1,5 @@
+import * as anchor from '@coral-xyz/anchor';
+import { PublicKey } from '@solana/web3.js';
 import { getCompDefAccOffset } from '@arcium-hq/client';
 
 export interface CircuitMapping {
@@ -5,6 +7,32 @@ export interface CircuitMapping {
   compDefOffset: number;
 }
 
+export interface CircuitRegistryEntry {
+  circuit: string;
+  compDefOffset: number;
+  /** Empty when the circuit was uploaded on-chain. */
+  source: string;
+  hashHex: string;
+}
+
+export function getCircuitRegistryAddress(mxeProgramId: PublicKey, circuitName: string): PublicKey {
+  const offset = Buffer.alloc(4);
+  offset.writeUInt32LE(getCircuitOffset(circuitName));
+  return PublicKey.findProgramAddressSync([Buffer.from('circuit_registry'), offset], mxeProgramId)[0];
+}
+
+export async function fetchCircuitRegistry(program: anchor.Program, circuitName: string): Promise<CircuitRegistryEntry | null> {
+  const address = getCircuitRegistryAddress(program.programId, circuitName);
+  const account: any = await (program.account as any).circuitRegistry.fetchNullable(address);
+  if (!account) return null;
+  return {
+    circuit: account.circuit,
+    compDefOffset: account.compDefOffset,
+    source: account.source,
+    hashHex: Buffer.from(account.hash).toString('hex'),
+  };
+}
+
 export function getCircuitOffset(circuitName: string): number {
   try {
     const offset = getCompDefAccOffset(circuitName);
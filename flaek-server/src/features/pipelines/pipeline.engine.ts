@@ -1,5 +1,5 @@
 import { getBlockById } from '../blocks/blocks.registry';
-import { ArciumClient } from '@/clients/arcium-client';
+import { ArciumClient, parseRecipients } from '@/clients/arcium-client';
 import { PublicKey } from '@solana/web3.js';
 import { getCircuitOffset } from './circuit-mapping';
 
@@ -196,6 +196,7 @@ export class PipelineEngine {
         clientNonce: typeof clientEncryptedData.nonce === 'string'
           ? Buffer.from(clientEncryptedData.nonce, 'base64')
           : Buffer.from(Uint8Array.from(clientEncryptedData.nonce)),
+        recipients: parseRecipients(clientEncryptedData.recipients),
       });
 
       console.log(`[Pipeline Executor] Circuit '${circuit}' submitted, tx: ${txInfo.tx}`);
@@ -477,8 +478,9 @@ export class PipelineEngine {
 
       // simple use cases
       case 'vote_tally': {
-        const v = this.asBool01(inputs.vote)
-        return out({ result: v })
+        const votes = Array.isArray(inputs.votes) ? inputs.votes.map(v => this.asBool01(v)) : []
+        const count = Math.min(10, this.asNum(inputs.count) || votes.length)
+        return out({ result: votes.slice(0, count).reduce((a, b) => a + b, 0) })
       }
       case 'credit_score': {
         const income = this.asNum(inputs.income)
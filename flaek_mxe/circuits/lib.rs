@@ -1,472 +0,0 @@
-use arcis_imports::*;
-
-#[encrypted]
-mod math {
-    use arcis_imports::*;
-
-    pub struct AddInputs {
-        pub a: u64,
-        pub b: u64,
-    }
-
-    #[instruction]
-    pub fn add(input_ctxt: Enc<Shared, AddInputs>) -> Enc<Shared, u64> {
-        let input = input_ctxt.to_arcis();
-        let result = input.a + input.b;
-        input_ctxt.owner.from_arcis(result)
-    }
-
-    pub struct SubtractInputs {
-        pub a: u64,
-        pub b: u64,
-    }
-
-    #[instruction]
-    pub fn subtract(input_ctxt: Enc<Shared, SubtractInputs>) -> Enc<Shared, u64> {
-        let input = input_ctxt.to_arcis();
-        let result = input.a.saturating_sub(input.b);
-        input_ctxt.owner.from_arcis(result)
-    }
-
-    pub struct MultiplyInputs {
-        pub a: u64,
-        pub b: u64,
-    }
-
-    #[instruction]
-    pub fn multiply(input_ctxt: Enc<Shared, MultiplyInputs>) -> Enc<Shared, u64> {
-        let input = input_ctxt.to_arcis();
-        let result = input.a * input.b;
-        input_ctxt.owner.from_arcis(result)
-    }
-
-    pub struct DivideInputs {
-        pub a: u64,
-        pub b: u64,
-    }
-
-    #[instruction]
-    pub fn divide(input_ctxt: Enc<Shared, DivideInputs>) -> Enc<Shared, u64> {
-        let input = input_ctxt.to_arcis();
-        let result = if input.b != 0 { input.a / input.b } else { 0 };
-        input_ctxt.owner.from_arcis(result)
-    }
-
-    pub struct ModuloInputs {
-        pub a: u64,
-        pub b: u64,
-    }
-
-    #[instruction]
-    pub fn modulo(input_ctxt: Enc<Shared, ModuloInputs>) -> Enc<Shared, u64> {
-        let input = input_ctxt.to_arcis();
-        let result = if input.b != 0 { input.a % input.b } else { 0 };
-        input_ctxt.owner.from_arcis(result)
-    }
-
-    pub struct PowerInputs {
-        pub base: u64,
-        pub exponent: u8,
-    }
-
-    #[instruction]
-    pub fn power(input_ctxt: Enc<Shared, PowerInputs>) -> Enc<Shared, u64> {
-        let input = input_ctxt.to_arcis();
-        let mut result: u64 = 1;
-        for _ in 0..input.exponent {
-            result = result.saturating_mul(input.base);
-        }
-        input_ctxt.owner.from_arcis(result)
-    }
-
-    pub struct AbsDiffInputs {
-        pub a: u64,
-        pub b: u64,
-    }
-
-    #[instruction]
-    pub fn abs_diff(input_ctxt: Enc<Shared, AbsDiffInputs>) -> Enc<Shared, u64> {
-        let input = input_ctxt.to_arcis();
-        let result = if input.a > input.b {
-            input.a - input.b
-        } else {
-            input.b - input.a
-        };
-        input_ctxt.owner.from_arcis(result)
-    }
-}
-
-#[encrypted]
-mod comparison {
-    use arcis_imports::*;
-
-    pub struct CompareInputs {
-        pub a: u64,
-        pub b: u64,
-    }
-
-    #[instruction]
-    pub fn greater_than(input_ctxt: Enc<Shared, CompareInputs>) -> Enc<Shared, u8> {
-        let input = input_ctxt.to_arcis();
-        let result = if input.a > input.b { 1u8 } else { 0u8 };
-        input_ctxt.owner.from_arcis(result)
-    }
-
-    #[instruction]
-    pub fn less_than(input_ctxt: Enc<Shared, CompareInputs>) -> Enc<Shared, u8> {
-        let input = input_ctxt.to_arcis();
-        let result = if input.a < input.b { 1u8 } else { 0u8 };
-        input_ctxt.owner.from_arcis(result)
-    }
-
-    #[instruction]
-    pub fn equal(input_ctxt: Enc<Shared, CompareInputs>) -> Enc<Shared, u8> {
-        let input = input_ctxt.to_arcis();
-        let result = if input.a == input.b { 1u8 } else { 0u8 };
-        input_ctxt.owner.from_arcis(result)
-    }
-
-    #[instruction]
-    pub fn greater_equal(input_ctxt: Enc<Shared, CompareInputs>) -> Enc<Shared, u8> {
-        let input = input_ctxt.to_arcis();
-        let result = if input.a >= input.b { 1u8 } else { 0u8 };
-        input_ctxt.owner.from_arcis(result)
-    }
-
-    #[instruction]
-    pub fn less_equal(input_ctxt: Enc<Shared, CompareInputs>) -> Enc<Shared, u8> {
-        let input = input_ctxt.to_arcis();
-        let result = if input.a <= input.b { 1u8 } else { 0u8 };
-        input_ctxt.owner.from_arcis(result)
-    }
-
-    // Range check (a <= value <= b)
-    pub struct RangeCheckInputs {
-        pub value: u64,
-        pub min: u64,
-        pub max: u64,
-    }
-
-    #[instruction]
-    pub fn in_range(input_ctxt: Enc<Shared, RangeCheckInputs>) -> Enc<Shared, u8> {
-        let input = input_ctxt.to_arcis();
-        let result = if input.value >= input.min && input.value <= input.max {
-            1u8
-        } else {
-            0u8
-        };
-        input_ctxt.owner.from_arcis(result)
-    }
-}
-
-#[encrypted]
-mod logical {
-    use arcis_imports::*;
-
-    pub struct LogicalInputs {
-        pub a: u8,
-        pub b: u8,
-    }
-
-    #[instruction]
-    pub fn and(input_ctxt: Enc<Shared, LogicalInputs>) -> Enc<Shared, u8> {
-        let input = input_ctxt.to_arcis();
-        let result = if input.a != 0 && input.b != 0 { 1u8 } else { 0u8 };
-        input_ctxt.owner.from_arcis(result)
-    }
-
-    #[instruction]
-    pub fn or(input_ctxt: Enc<Shared, LogicalInputs>) -> Enc<Shared, u8> {
-        let input = input_ctxt.to_arcis();
-        let result = if input.a != 0 || input.b != 0 { 1u8 } else { 0u8 };
-        input_ctxt.owner.from_arcis(result)
-    }
-
-    pub struct NotInputs {
-        pub a: u8,
-    }
-
-    #[instruction]
-    pub fn not(input_ctxt: Enc<Shared, NotInputs>) -> Enc<Shared, u8> {
-        let input = input_ctxt.to_arcis();
-        let result = if input.a == 0 { 1u8 } else { 0u8 };
-        input_ctxt.owner.from_arcis(result)
-    }
-
-    #[instruction]
-    pub fn xor(input_ctxt: Enc<Shared, LogicalInputs>) -> Enc<Shared, u8> {
-        let input = input_ctxt.to_arcis();
-        let a_bool = input.a != 0;
-        let b_bool = input.b != 0;
-        let result = if a_bool != b_bool { 1u8 } else { 0u8 };
-        input_ctxt.owner.from_arcis(result)
-    }
-
-    // Conditional (ternary operator)
-    pub struct ConditionalInputs {
-        pub condition: u8,
-        pub true_value: u64,
-        pub false_value: u64,
-    }
-
-    #[instruction]
-    pub fn if_else(input_ctxt: Enc<Shared, ConditionalInputs>) -> Enc<Shared, u64> {
-        let input = input_ctxt.to_arcis();
-        let result = if input.condition != 0 {
-            input.true_value
-        } else {
-            input.false_value
-        };
-        input_ctxt.owner.from_arcis(result)
-    }
-}
-
-#[encrypted]
-mod statistics {
-    use arcis_imports::*;
-
-    pub struct AverageInputs {
-        pub values: [u64; 10],
-        pub count: u8,
-    }
-
-    #[instruction]
-    pub fn average(input_ctxt: Enc<Shared, AverageInputs>) -> Enc<Shared, u64> {
-        let input = input_ctxt.to_arcis();
-        let count = input.count.min(10) as usize;
-        if count == 0 {
-            return input_ctxt.owner.from_arcis(0);
-        }
-        
-        let mut sum: u64 = 0;
-        for i in 0..count {
-            sum = sum.saturating_add(input.values[i]);
-        }
-        let result = sum / (count as u64);
-        input_ctxt.owner.from_arcis(result)
-    }
-
-    #[instruction]
-    pub fn sum(input_ctxt: Enc<Shared, AverageInputs>) -> Enc<Shared, u64> {
-        let input = input_ctxt.to_arcis();
-        let count = input.count.min(10) as usize;
-        
-        let mut sum: u64 = 0;
-        for i in 0..count {
-            sum = sum.saturating_add(input.values[i]);
-        }
-        input_ctxt.owner.from_arcis(sum)
-    }
-
-    #[instruction]
-    pub fn min(input_ctxt: Enc<Shared, AverageInputs>) -> Enc<Shared, u64> {
-        let input = input_ctxt.to_arcis();
-        let count = input.count.min(10) as usize;
-        if count == 0 {
-            return input_ctxt.owner.from_arcis(0);
-        }
-        
-        let mut min_val = input.values[0];
-        for i in 1..count {
-            if input.values[i] < min_val {
-                min_val = input.values[i];
-            }
-        }
-        input_ctxt.owner.from_arcis(min_val)
-    }
-
-    #[instruction]
-    pub fn max(input_ctxt: Enc<Shared, AverageInputs>) -> Enc<Shared, u64> {
-        let input = input_ctxt.to_arcis();
-        let count = input.count.min(10) as usize;
-        if count == 0 {
-            return input_ctxt.owner.from_arcis(0);
-        }
-        
-        let mut max_val = input.values[0];
-        for i in 1..count {
-            if input.values[i] > max_val {
-                max_val = input.values[i];
-            }
-        }
-        input_ctxt.owner.from_arcis(max_val)
-    }
-
-    #[instruction]
-    pub fn median(input_ctxt: Enc<Shared, AverageInputs>) -> Enc<Shared, u64> {
-        let input = input_ctxt.to_arcis();
-        let count = input.count.min(10) as usize;
-        if count == 0 {
-            return input_ctxt.owner.from_arcis(0);
-        }
-        
-        let mut sorted = input.values;
-        for i in 0..count {
-            for j in 0..(count - i - 1) {
-                if sorted[j] > sorted[j + 1] {
-                    let temp = sorted[j];
-                    sorted[j] = sorted[j + 1];
-                    sorted[j + 1] = temp;
-                }
-            }
-        }
-        
-        let result = if count % 2 == 0 {
-            (sorted[count / 2 - 1] + sorted[count / 2]) / 2
-        } else {
-            sorted[count / 2]
-        };
-        input_ctxt.owner.from_arcis(result)
-    }
-}
-
-#[encrypted]
-mod use_cases {
-    use arcis_imports::*;
-
-    pub struct CreditScoreInputs {
-        pub income: u64,
-        pub debt: u64,
-        pub credit_history: u8,
-        pub missed_payments: u8,
-    }
-
-    pub struct CreditScoreOutput {
-        pub score: u16,
-        pub approved: u8,
-    }
-
-    #[instruction]
-    pub fn credit_score(input_ctxt: Enc<Shared, CreditScoreInputs>) -> Enc<Shared, CreditScoreOutput> {
-        let input = input_ctxt.to_arcis();
-        let dti_ratio = if input.income > 0 {
-            ((input.debt * 100) / input.income) as u16
-        } else {
-            100u16
-        };
-        
-        let mut score: u16 = 650;
-        if dti_ratio < 20 {
-            score += 100;
-        } else if dti_ratio < 35 {
-            score += 50;
-        } else if dti_ratio > 50 {
-            score = score.saturating_sub(100);
-        }
-        
-        score += (input.credit_history as u16) * 5;
-        score = score.saturating_sub((input.missed_payments as u16) * 30);
-        score = score.max(300).min(850);
-        let approved = if score >= 700 && dti_ratio < 43 { 1u8 } else { 0u8 };
-        
-        let output = CreditScoreOutput { score, approved };
-        input_ctxt.owner.from_arcis(output)
-    }
-
-    pub struct HealthRiskInputs {
-        pub age: u8,
-        pub bmi: u8,
-        pub smoker: u8,
-        pub exercise_hours: u8,
-        pub family_history: u8,
-    }
-
-    pub struct HealthRiskOutput {
-        pub risk_score: u8,
-        pub risk_category: u8,
-    }
-
-    #[instruction]
-    pub fn health_risk(input_ctxt: Enc<Shared, HealthRiskInputs>) -> Enc<Shared, HealthRiskOutput> {
-        let input = input_ctxt.to_arcis();
-        let mut risk: u8 = 0;
-        if input.age > 65 {
-            risk += 30;
-        } else if input.age > 50 {
-            risk += 20;
-        } else if input.age > 35 {
-            risk += 10;
-        }
-        if input.bmi < 18 || input.bmi > 30 {
-            risk += 20;
-        } else if input.bmi > 25 {
-            risk += 10;
-        }
-        if input.smoker != 0 {
-            risk += 25;
-        }
-        if input.exercise_hours >= 5 {
-            risk = risk.saturating_sub(10);
-        } else if input.exercise_hours < 2 {
-            risk += 10;
-        }
-        if input.family_history != 0 {
-            risk += 15;
-        }
-        
-        risk = risk.min(100);
-        let category = if risk < 25 {
-            0u8
-        } else if risk < 50 {
-            1u8
-        } else if risk < 75 {
-            2u8
-        } else {
-            3u8
-        };
-        
-        let output = HealthRiskOutput {
-            risk_score: risk,
-            risk_category: category,
-        };
-        input_ctxt.owner.from_arcis(output)
-    }
-
-    pub struct VoteInputs {
-        pub vote: u8,
-    }
-
-    #[instruction]
-    pub fn vote_tally(input_ctxt: Enc<Shared, VoteInputs>) -> Enc<Shared, u8> {
-        let input = input_ctxt.to_arcis();
-        input_ctxt.owner.from_arcis(input.vote)
-    }
-
-    pub struct ThresholdInputs {
-        pub value: u64,
-        pub threshold: u64,
-    }
-
-    #[instruction]
-    pub fn meets_threshold(input_ctxt: Enc<Shared, ThresholdInputs>) -> Enc<Shared, u8> {
-        let input = input_ctxt.to_arcis();
-        let result = if input.value >= input.threshold { 1u8 } else { 0u8 };
-        input_ctxt.owner.from_arcis(result)
-    }
-
-    pub struct WeightedAverageInputs {
-        pub values: [u64; 5],
-        pub weights: [u8; 5],
-    }
-
-    #[instruction]
-    pub fn weighted_average(input_ctxt: Enc<Shared, WeightedAverageInputs>) -> Enc<Shared, u64> {
-        let input = input_ctxt.to_arcis();
-        
-        let mut weighted_sum: u64 = 0;
-        let mut total_weight: u64 = 0;
-        
-        for i in 0..5 {
-            weighted_sum += input.values[i] * (input.weights[i] as u64);
-            total_weight += input.weights[i] as u64;
-        }
-        
-        let result = if total_weight > 0 {
-            weighted_sum / total_weight
-        } else {
-            0
-        };
-        
-        input_ctxt.owner.from_arcis(result)
-    }
-}
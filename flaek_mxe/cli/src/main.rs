@@ -0,0 +1,947 @@
+//! `flaek-mxe-cli`: sets up and inspects the flaek_mxe computation
+//! definitions. Circuit names, offsets and seeds all come from the program
+//! crate, so this cannot drift from what is deployed with it.
+
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    process::ExitCode,
+};
+
+use anchor_lang::{
+    prelude::*, solana_program::instruction::Instruction, AccountDeserialize, InstructionData,
+    ToAccountMetas,
+};
+use anchor_spl::{associated_token::get_associated_token_address, token};
+use anyhow::{bail, Context as _, Result};
+use arcium_anchor::prelude::*;
+use arcium_client::idl::arcium::{
+    client::{accounts as arcium_accounts, args as arcium_args},
+    types::{CircuitSource, X25519Pubkey},
+};
+use base64::{engine::general_purpose::STANDARD, Engine};
+use clap::{Parser, Subcommand};
+use flaek_client::{
+    attestation_address, cluster_address, computation_result_address, config_address,
+    credits_address, intermediate_address, registry_address, treasury_address,
+};
+use flaek_mxe::{Attestation, CircuitRegistry, Gate, CIRCUITS, ID};
+use serde_json::{json, Map, Value};
+use sha2::{Digest, Sha256};
+use solana_client::rpc_client::RpcClient;
+use solana_sdk::{
+    commitment_config::CommitmentConfig,
+    signature::{read_keypair_file, write_keypair_file, Keypair, Signer},
+    transaction::Transaction,
+};
+
+/// Sources that hardcode the program id, relative to the flaek_mxe
+/// workspace; missing ones are skipped.
+const PROGRAM_ID_FILES: &[&str] = &[
+    "programs/flaek_mxe/src/lib.rs",
+    "Anchor.toml",
+    "../flaek-client/src/lib/api.ts",
+    "../flaek-client/src/pages/dashboard/jobs.tsx",
+    "../flaek-client/src/pages/dashboard/pipeline-builder.tsx",
+];
+
+/// Arcium's raw circuit accounts hold a discriminator and bump before the
+/// circuit bytes, are at most 10 MiB, and grow 10 KiB per instruction.
+const RAW_CIRCUIT_METADATA: usize = 9;
+const MAX_RAW_CIRCUIT_BYTES: usize = 10 * 1024 * 1024 - RAW_CIRCUIT_METADATA;
+const MAX_REALLOC_PER_IX: usize = 10 * 1024;
+/// Circuit bytes one `upload_circuit` carries.
+const UPLOAD_CHUNK: usize = 814;
+
+#[derive(Parser)]
+#[command(version, about)]
+struct Cli {
+    /// RPC endpoint of the cluster the program is deployed to.
+    #[arg(
+        long,
+        global = true,
+        env = "SOLANA_RPC_URL",
+        default_value = "https://api.devnet.solana.com"
+    )]
+    url: String,
+    /// Keypair paying for and signing transactions.
+    #[arg(long, global = true, default_value = "~/.config/solana/id.json")]
+    keypair: String,
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Makes the signing keypair, which must be the program's upgrade
+    /// authority, the admin that may initialize computation definitions.
+    InitConfig,
+    /// Hands the admin role to another key.
+    TransferAuthority { new_authority: Pubkey },
+    /// Chooses who may queue computations.
+    SetGate {
+        #[command(subcommand)]
+        gate: GateArg,
+    },
+    /// Adds a payer to the allow-list.
+    AllowPayer { payer: Pubkey },
+    /// Removes a payer from the allow-list.
+    DisallowPayer { payer: Pubkey },
+    /// Sets what queuing a circuit costs; 0 makes it free.
+    SetFee { circuit: String, amount: u64 },
+    /// Charges fees in an SPL mint, or in lamports without one.
+    SetFeeMint { mint: Option<Pubkey> },
+    /// Sets how many credits queuing a circuit debits; 0 makes it free.
+    SetCreditCost { circuit: String, amount: u64 },
+    /// Tops up the signing keypair's prepaid credits with lamports.
+    DepositCredits { amount: u64 },
+    /// Refunds unspent prepaid credits to the signing keypair.
+    WithdrawCredits { amount: u64 },
+    /// Moves a tenant's spent credits into the treasury.
+    SweepCredits { tenant: Pubkey },
+    /// Withdraws collected fees to the signing keypair.
+    WithdrawTreasury {
+        amount: u64,
+        /// Withdraws this SPL mint, between associated token accounts,
+        /// instead of lamports.
+        #[arg(long)]
+        mint: Option<Pubkey>,
+    },
+    /// Initializes the computation definition of every circuit that has none;
+    /// the keypair must be the config authority.
+    Init {
+        /// Directory serving the build artifacts under their own file
+        /// names, e.g. `add_devnet.arcis`. Without it each artifact is
+        /// uploaded on-chain and its comp def finalized.
+        #[arg(long, env = "CIRCUITS_BASE_URL")]
+        base_url: Option<String>,
+        #[command(flatten)]
+        artifacts: Artifacts,
+        /// Only these circuits instead of all of them.
+        circuits: Vec<String>,
+    },
+    /// Resyncs the `CircuitRegistry` of initialized circuits with their
+    /// comp defs, creating it for comp defs that predate the registry.
+    UpdateRegistry {
+        /// Only these circuits instead of all of them.
+        circuits: Vec<String>,
+    },
+    /// Finalizes the computation definitions of on-chain circuits whose
+    /// upload `init` did not finish.
+    Finalize {
+        /// Only these circuits instead of all of them.
+        circuits: Vec<String>,
+    },
+    /// Prints the program's PDAs, plus the comp def offset and accounts of
+    /// a circuit or a computation when given.
+    Pdas {
+        #[arg(long)]
+        circuit: Option<String>,
+        #[arg(long)]
+        computation_offset: Option<u64>,
+        #[arg(long)]
+        cluster_offset: Option<u32>,
+    },
+    /// Prints the MXE's x25519 public key, which clients encrypt inputs to.
+    GetMxePubkey,
+    /// Generates a new program keypair into `target/deploy` and swaps the
+    /// current program id for it wherever the sources hardcode it.
+    RegenerateProgramId {
+        /// The flaek_mxe workspace.
+        #[arg(long, default_value = ".")]
+        root: PathBuf,
+    },
+    /// Prints the attestation of a computation, checking its circuit hash
+    /// against the one pinned in the circuit's registry.
+    Attestation { computation_offset: u64 },
+    /// Prints the SHA-256 of every circuit artifact in the build directory.
+    Hash {
+        #[command(flatten)]
+        artifacts: Artifacts,
+    },
+    /// Checks each comp def's circuit against the local build artifact: the
+    /// hash pinned in its off-chain source, or the bytes uploaded on-chain.
+    Verify {
+        #[command(flatten)]
+        artifacts: Artifacts,
+    },
+}
+
+#[derive(Subcommand)]
+enum GateArg {
+    /// Any payer.
+    Open,
+    /// Only payers added with `allow-payer`.
+    AllowList,
+    /// Any payer, as long as this key co-signs the queue transaction.
+    CoSigner { key: Pubkey },
+}
+
+impl From<&GateArg> for Gate {
+    fn from(gate: &GateArg) -> Self {
+        match gate {
+            GateArg::Open => Gate::Open,
+            GateArg::AllowList => Gate::AllowList,
+            GateArg::CoSigner { key } => Gate::CoSigner(*key),
+        }
+    }
+}
+
+#[derive(clap::Args)]
+struct Artifacts {
+    /// Where `arcium build` wrote the `.arcis` files.
+    #[arg(long, default_value = "build")]
+    build_dir: PathBuf,
+    /// Prefers `<circuit>_<net>.arcis` over `<circuit>.arcis`.
+    #[arg(long, env = "CIRCUITS_NET", default_value = "devnet")]
+    net: String,
+}
+
+fn main() -> ExitCode {
+    match run(Cli::parse()) {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => ExitCode::FAILURE,
+        Err(err) => {
+            eprintln!("error: {err:#}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Whether every circuit the command touched succeeded.
+fn run(cli: Cli) -> Result<bool> {
+    match &cli.command {
+        Command::InitConfig => {
+            let (rpc, authority) = connect(&cli)?;
+            let ix = instruction(
+                flaek_mxe::accounts::InitConfig {
+                    authority: authority.pubkey(),
+                    config: config_address(),
+                    treasury: treasury_address(),
+                    program: ID,
+                    program_data: Pubkey::find_program_address(
+                        &[ID.as_ref()],
+                        &anchor_lang::solana_program::bpf_loader_upgradeable::ID,
+                    )
+                    .0,
+                    system_program: anchor_lang::system_program::ID,
+                },
+                flaek_mxe::instruction::InitConfig {},
+            );
+            println!("config initialized in {}", send(&rpc, &authority, ix)?);
+            Ok(true)
+        }
+        Command::TransferAuthority { new_authority } => {
+            let (rpc, authority) = connect(&cli)?;
+            let ix = instruction(
+                flaek_mxe::accounts::TransferAuthority {
+                    authority: authority.pubkey(),
+                    config: config_address(),
+                },
+                flaek_mxe::instruction::TransferAuthority {
+                    new_authority: *new_authority,
+                },
+            );
+            println!("authority transferred in {}", send(&rpc, &authority, ix)?);
+            Ok(true)
+        }
+        Command::SetGate { gate } => {
+            let (rpc, authority) = connect(&cli)?;
+            let ix = instruction(
+                update_config(&authority),
+                flaek_mxe::instruction::SetGate { gate: gate.into() },
+            );
+            println!("gate set in {}", send(&rpc, &authority, ix)?);
+            Ok(true)
+        }
+        Command::AllowPayer { payer } => {
+            let (rpc, authority) = connect(&cli)?;
+            let ix = instruction(
+                update_config(&authority),
+                flaek_mxe::instruction::AllowPayer { payer: *payer },
+            );
+            println!("{payer} allowed in {}", send(&rpc, &authority, ix)?);
+            Ok(true)
+        }
+        Command::DisallowPayer { payer } => {
+            let (rpc, authority) = connect(&cli)?;
+            let ix = instruction(
+                update_config(&authority),
+                flaek_mxe::instruction::DisallowPayer { payer: *payer },
+            );
+            println!("{payer} disallowed in {}", send(&rpc, &authority, ix)?);
+            Ok(true)
+        }
+        Command::SetFee { circuit, amount } => {
+            if !CIRCUITS.contains(&circuit.as_str()) {
+                bail!("flaek_mxe has no circuit named {circuit}");
+            }
+            let (rpc, authority) = connect(&cli)?;
+            let ix = instruction(
+                update_config(&authority),
+                flaek_mxe::instruction::SetFee {
+                    comp_def_offset: comp_def_offset(circuit),
+                    amount: *amount,
+                },
+            );
+            println!("{circuit} fee set in {}", send(&rpc, &authority, ix)?);
+            Ok(true)
+        }
+        Command::SetCreditCost { circuit, amount } => {
+            if !CIRCUITS.contains(&circuit.as_str()) {
+                bail!("flaek_mxe has no circuit named {circuit}");
+            }
+            let (rpc, authority) = connect(&cli)?;
+            let ix = instruction(
+                update_config(&authority),
+                flaek_mxe::instruction::SetCreditCost {
+                    comp_def_offset: comp_def_offset(circuit),
+                    amount: *amount,
+                },
+            );
+            println!(
+                "{circuit} credit cost set in {}",
+                send(&rpc, &authority, ix)?
+            );
+            Ok(true)
+        }
+        Command::DepositCredits { amount } => {
+            let (rpc, tenant) = connect(&cli)?;
+            let ix = instruction(
+                flaek_mxe::accounts::DepositCredits {
+                    tenant: tenant.pubkey(),
+                    credits: credits_address(tenant.pubkey()),
+                    system_program: anchor_lang::system_program::ID,
+                },
+                flaek_mxe::instruction::DepositCredits { amount: *amount },
+            );
+            println!("credits deposited in {}", send(&rpc, &tenant, ix)?);
+            Ok(true)
+        }
+        Command::WithdrawCredits { amount } => {
+            let (rpc, tenant) = connect(&cli)?;
+            let ix = instruction(
+                flaek_mxe::accounts::WithdrawCredits {
+                    tenant: tenant.pubkey(),
+                    credits: credits_address(tenant.pubkey()),
+                },
+                flaek_mxe::instruction::WithdrawCredits { amount: *amount },
+            );
+            println!("credits withdrawn in {}", send(&rpc, &tenant, ix)?);
+            Ok(true)
+        }
+        Command::SweepCredits { tenant } => {
+            let (rpc, authority) = connect(&cli)?;
+            let ix = instruction(
+                flaek_mxe::accounts::SweepCredits {
+                    authority: authority.pubkey(),
+                    config: config_address(),
+                    credits: credits_address(*tenant),
+                    treasury: treasury_address(),
+                },
+                flaek_mxe::instruction::SweepCredits {},
+            );
+            println!("{tenant} credits swept in {}", send(&rpc, &authority, ix)?);
+            Ok(true)
+        }
+        Command::SetFeeMint { mint } => {
+            let (rpc, authority) = connect(&cli)?;
+            let ix = instruction(
+                update_config(&authority),
+                flaek_mxe::instruction::SetFeeMint { mint: *mint },
+            );
+            println!("fee mint set in {}", send(&rpc, &authority, ix)?);
+            Ok(true)
+        }
+        Command::WithdrawTreasury { amount, mint } => {
+            let (rpc, authority) = connect(&cli)?;
+            let treasury = treasury_address();
+            let token_account =
+                |owner: &Pubkey| mint.map(|mint| get_associated_token_address(owner, &mint));
+            let ix = instruction(
+                flaek_mxe::accounts::WithdrawTreasury {
+                    authority: authority.pubkey(),
+                    config: config_address(),
+                    treasury,
+                    recipient: authority.pubkey(),
+                    treasury_fee_account: token_account(&treasury),
+                    recipient_fee_account: token_account(&authority.pubkey()),
+                    token_program: mint.map(|_| token::ID),
+                    system_program: anchor_lang::system_program::ID,
+                },
+                flaek_mxe::instruction::WithdrawTreasury { amount: *amount },
+            );
+            println!("treasury withdrawn in {}", send(&rpc, &authority, ix)?);
+            Ok(true)
+        }
+        Command::Init {
+            base_url,
+            artifacts,
+            circuits,
+        } => {
+            let (rpc, payer) = connect(&cli)?;
+            for_each_circuit(circuits, |circuit| {
+                init(&rpc, &payer, artifacts, circuit, base_url.clone())
+            })
+        }
+        Command::UpdateRegistry { circuits } => {
+            let (rpc, authority) = connect(&cli)?;
+            for_each_circuit(circuits, |circuit| {
+                let ix = instruction(
+                    flaek_mxe::accounts::UpdateCircuitRegistry {
+                        authority: authority.pubkey(),
+                        config: config_address(),
+                        circuit_registry: registry_address(circuit),
+                        comp_def_account: comp_def_address(circuit),
+                        system_program: anchor_lang::system_program::ID,
+                    },
+                    flaek_mxe::instruction::UpdateCircuitRegistry {
+                        circuit: circuit.to_string(),
+                    },
+                );
+                println!(
+                    "{circuit}: registry updated in {}",
+                    send(&rpc, &authority, ix)?
+                );
+                Ok(())
+            })
+        }
+        Command::Finalize { circuits } => {
+            let (rpc, payer) = connect(&cli)?;
+            for_each_circuit(circuits, |circuit| finalize(&rpc, &payer, circuit))
+        }
+        Command::Pdas {
+            circuit,
+            computation_offset,
+            cluster_offset,
+        } => {
+            print_json(&pdas(
+                circuit.as_deref(),
+                *computation_offset,
+                *cluster_offset,
+            )?);
+            Ok(true)
+        }
+        Command::GetMxePubkey => {
+            let rpc = RpcClient::new_with_commitment(&cli.url, CommitmentConfig::confirmed());
+            let data = rpc
+                .get_account_data(&derive_mxe_pda!())
+                .context("no MXE account on-chain")?;
+            let mxe = MXEAccount::try_deserialize(&mut data.as_slice())?;
+            let X25519Pubkey::Set(key) = mxe.x25519_pubkey else {
+                bail!("the MXE's x25519 key is not set yet");
+            };
+            print_json(&json!({
+                "mxe": derive_mxe_pda!().to_string(),
+                "pubKeyHex": hex(&key),
+                "pubKeyBase64": STANDARD.encode(key),
+            }));
+            Ok(true)
+        }
+        Command::RegenerateProgramId { root } => {
+            let keypair = Keypair::new();
+            let path = root.join("target/deploy/flaek_mxe-keypair.json");
+            fs::create_dir_all(path.parent().expect("joined path"))?;
+            write_keypair_file(&keypair, &path)
+                .map_err(|err| anyhow::anyhow!("writing {}: {err}", path.display()))?;
+
+            let (old, new) = (ID.to_string(), keypair.pubkey().to_string());
+            let mut patched = Vec::new();
+            for file in PROGRAM_ID_FILES {
+                let file = root.join(file);
+                let Ok(source) = fs::read_to_string(&file) else {
+                    continue;
+                };
+                if source.contains(&old) {
+                    fs::write(&file, source.replace(&old, &new))?;
+                    patched.push(file.display().to_string());
+                }
+            }
+            print_json(&json!({
+                "oldProgramId": old,
+                "newProgramId": new,
+                "keypair": path.display().to_string(),
+                "patched": patched,
+            }));
+            Ok(true)
+        }
+        Command::Attestation { computation_offset } => {
+            let rpc = RpcClient::new_with_commitment(&cli.url, CommitmentConfig::confirmed());
+            let data = rpc
+                .get_account_data(&attestation_address(*computation_offset))
+                .context("no Attestation on-chain")?;
+            let attestation = Attestation::try_deserialize(&mut data.as_slice())?;
+            let circuit = CIRCUITS
+                .iter()
+                .find(|circuit| comp_def_offset(circuit) == attestation.comp_def_offset);
+            let pinned = circuit
+                .and_then(|circuit| rpc.get_account_data(&registry_address(circuit)).ok())
+                .and_then(|data| CircuitRegistry::try_deserialize(&mut data.as_slice()).ok());
+            let completed = attestation.slot != 0;
+            let matches = pinned.is_some_and(|registry| registry.hash == attestation.circuit_hash);
+            print_json(&json!({
+                "computationOffset": attestation.computation_offset,
+                "circuit": circuit,
+                "compDefOffset": attestation.comp_def_offset,
+                "circuitHashHex": hex(&attestation.circuit_hash),
+                "circuitHashMatchesRegistry": matches,
+                "cluster": attestation.cluster.to_string(),
+                "inputsHashHex": hex(&attestation.inputs_hash),
+                "outputsHashHex": hex(&attestation.outputs_hash),
+                "slot": attestation.slot,
+                "completed": completed,
+            }));
+            Ok(completed && matches)
+        }
+        Command::Hash { artifacts } => {
+            let mut hashes = Map::new();
+            for circuit in CIRCUITS {
+                let path = artifacts.resolve(circuit)?;
+                hashes.insert(
+                    circuit.to_string(),
+                    json!({
+                        "artifact": path.display().to_string(),
+                        "hashHex": hex(&hash_file(&path)?),
+                    }),
+                );
+            }
+            print_json(&Value::Object(hashes));
+            Ok(true)
+        }
+        Command::Verify { artifacts } => {
+            let rpc = RpcClient::new_with_commitment(&cli.url, CommitmentConfig::confirmed());
+            for_each_circuit(&[], |circuit| verify(&rpc, artifacts, circuit))
+        }
+    }
+}
+
+fn connect(cli: &Cli) -> Result<(RpcClient, Keypair)> {
+    let path = match cli.keypair.strip_prefix("~/") {
+        Some(rest) => {
+            PathBuf::from(std::env::var_os("HOME").context("HOME is not set")?).join(rest)
+        }
+        None => PathBuf::from(&cli.keypair),
+    };
+    let payer = read_keypair_file(&path)
+        .map_err(|err| anyhow::anyhow!("reading keypair {}: {err}", path.display()))?;
+    let rpc = RpcClient::new_with_commitment(&cli.url, CommitmentConfig::confirmed());
+    Ok((rpc, payer))
+}
+
+/// Runs `f` on `circuits`, or on every circuit when empty, reporting each
+/// failure and carrying on with the rest.
+fn for_each_circuit(circuits: &[String], mut f: impl FnMut(&str) -> Result<()>) -> Result<bool> {
+    let selected: Vec<&str> = if circuits.is_empty() {
+        CIRCUITS.to_vec()
+    } else {
+        circuits.iter().map(String::as_str).collect()
+    };
+    if let Some(unknown) = selected.iter().find(|c| !CIRCUITS.contains(*c)) {
+        bail!("flaek_mxe has no circuit named {unknown}");
+    }
+
+    let mut failed = Vec::new();
+    for circuit in &selected {
+        if let Err(err) = f(circuit) {
+            eprintln!("{circuit}: {err:#}");
+            failed.push(*circuit);
+        }
+    }
+    println!(
+        "{} of {} circuits ok{}",
+        selected.len() - failed.len(),
+        selected.len(),
+        if failed.is_empty() {
+            String::new()
+        } else {
+            format!(", failed: {}", failed.join(", "))
+        }
+    );
+    Ok(failed.is_empty())
+}
+
+/// Initializes `circuit`'s comp def, then without `base_url` uploads its
+/// artifact on-chain and finalizes it, unless that already happened.
+fn init(
+    rpc: &RpcClient,
+    payer: &Keypair,
+    artifacts: &Artifacts,
+    circuit: &str,
+    base_url: Option<String>,
+) -> Result<()> {
+    if rpc.get_account(&comp_def_address(circuit)).is_ok() {
+        println!("{circuit}: already initialized");
+    } else {
+        let ix = flaek_mxe::init_comp_def_instruction(circuit, payer.pubkey(), base_url)
+            .context("no init instruction")?;
+        println!("{circuit}: initialized in {}", send(rpc, payer, ix)?);
+    }
+    match comp_def(rpc, circuit)?.circuit_source {
+        CircuitSource::OnChain(source) if !source.is_completed => {
+            let path = artifacts.resolve(circuit)?;
+            let bytes = fs::read(&path).with_context(|| format!("reading {}", path.display()))?;
+            upload(rpc, payer, circuit, &bytes)?;
+            finalize(rpc, payer, circuit)
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Writes `bytes` into `circuit`'s raw circuit accounts, creating and
+/// growing each as needed.
+fn upload(rpc: &RpcClient, payer: &Keypair, circuit: &str, bytes: &[u8]) -> Result<()> {
+    let comp_def_acc = comp_def_address(circuit);
+    let comp_offset = comp_def_offset(circuit);
+    for (index, part) in bytes.chunks(MAX_RAW_CIRCUIT_BYTES).enumerate() {
+        let raw_circuit_index = u8::try_from(index).context("circuit has too many parts")?;
+        let comp_def_raw = raw_circuit_address(&comp_def_acc, raw_circuit_index);
+        // Init, embiggen and upload all take the same accounts.
+        macro_rules! accounts {
+            ($accounts:ident) => {
+                arcium_accounts::$accounts {
+                    signer: payer.pubkey(),
+                    comp_def_acc,
+                    comp_def_raw,
+                    system_program: anchor_lang::system_program::ID,
+                }
+            };
+        }
+
+        let size = match rpc.get_account(&comp_def_raw) {
+            Ok(account) => account.data.len(),
+            Err(_) => {
+                let ix = arcium_instruction(
+                    accounts!(InitRawCircuitAcc),
+                    arcium_args::InitRawCircuitAcc {
+                        comp_offset,
+                        mxe_program: ID,
+                        raw_circuit_index,
+                    },
+                );
+                send(rpc, payer, ix)?;
+                rpc.get_account(&comp_def_raw)?.data.len()
+            }
+        };
+        let needed = RAW_CIRCUIT_METADATA + part.len();
+        for _ in 0..needed.saturating_sub(size).div_ceil(MAX_REALLOC_PER_IX) {
+            let ix = arcium_instruction(
+                accounts!(EmbiggenRawCircuitAcc),
+                arcium_args::EmbiggenRawCircuitAcc {
+                    comp_offset,
+                    mxe_program: ID,
+                    raw_circuit_index,
+                },
+            );
+            send(rpc, payer, ix)?;
+        }
+
+        for (offset, upload_data) in upload_chunks(part) {
+            let ix = arcium_instruction(
+                accounts!(UploadCircuit),
+                arcium_args::UploadCircuit {
+                    comp_offset,
+                    mxe_program: ID,
+                    raw_circuit_index,
+                    upload_data,
+                    offset,
+                },
+            );
+            send(rpc, payer, ix)?;
+        }
+        println!("{circuit}: uploaded {} bytes into part {index}", part.len());
+    }
+    Ok(())
+}
+
+/// `part` in `upload_circuit`-sized pieces with their offsets, the last one
+/// zero-padded.
+fn upload_chunks(part: &[u8]) -> impl Iterator<Item = (u32, [u8; UPLOAD_CHUNK])> + '_ {
+    part.chunks(UPLOAD_CHUNK).enumerate().map(|(i, piece)| {
+        let mut data = [0; UPLOAD_CHUNK];
+        data[..piece.len()].copy_from_slice(piece);
+        ((i * UPLOAD_CHUNK) as u32, data)
+    })
+}
+
+fn finalize(rpc: &RpcClient, payer: &Keypair, circuit: &str) -> Result<()> {
+    let ix = arcium_instruction(
+        arcium_accounts::FinalizeComputationDefinition {
+            signer: payer.pubkey(),
+            comp_def_acc: comp_def_address(circuit),
+        },
+        arcium_args::FinalizeComputationDefinition {
+            comp_offset: comp_def_offset(circuit),
+            mxe_program: ID,
+        },
+    );
+    println!("{circuit}: finalized in {}", send(rpc, payer, ix)?);
+    Ok(())
+}
+
+fn verify(rpc: &RpcClient, artifacts: &Artifacts, circuit: &str) -> Result<()> {
+    let path = artifacts.resolve(circuit)?;
+    let local = fs::read(&path).with_context(|| format!("reading {}", path.display()))?;
+    let local_hash: [u8; 32] = Sha256::digest(&local).into();
+    let on_chain_hash: [u8; 32] = match comp_def(rpc, circuit)?.circuit_source {
+        CircuitSource::OffChain(source) => source.hash,
+        CircuitSource::OnChain(_) => {
+            Sha256::digest(uploaded_circuit(rpc, circuit, local.len())?).into()
+        }
+        CircuitSource::Local(_) => bail!("the comp def has a local circuit source"),
+    };
+    if on_chain_hash != local_hash {
+        bail!(
+            "on-chain hash {} does not match the local artifact {}",
+            hex(&on_chain_hash),
+            hex(&local_hash)
+        );
+    }
+    println!("{circuit}: {} matches", hex(&local_hash));
+    Ok(())
+}
+
+/// The first `len` circuit bytes held by `circuit`'s raw circuit accounts.
+fn uploaded_circuit(rpc: &RpcClient, circuit: &str, len: usize) -> Result<Vec<u8>> {
+    let comp_def_acc = comp_def_address(circuit);
+    let mut bytes = Vec::with_capacity(len);
+    for index in 0..len.div_ceil(MAX_RAW_CIRCUIT_BYTES) {
+        let index = u8::try_from(index).context("circuit has too many parts")?;
+        let data = rpc
+            .get_account_data(&raw_circuit_address(&comp_def_acc, index))
+            .with_context(|| format!("no raw circuit account {index} on-chain"))?;
+        bytes.extend_from_slice(data.get(RAW_CIRCUIT_METADATA..).unwrap_or_default());
+    }
+    bytes.truncate(len);
+    Ok(bytes)
+}
+
+fn comp_def(rpc: &RpcClient, circuit: &str) -> Result<ComputationDefinitionAccount> {
+    let data = rpc
+        .get_account_data(&comp_def_address(circuit))
+        .context("no comp def on-chain")?;
+    Ok(ComputationDefinitionAccount::try_deserialize(
+        &mut data.as_slice(),
+    )?)
+}
+
+fn pdas(
+    circuit: Option<&str>,
+    computation_offset: Option<u64>,
+    cluster_offset: Option<u32>,
+) -> Result<Value> {
+    let mut out = json!({
+        "programId": ID.to_string(),
+        "mxe": derive_mxe_pda!().to_string(),
+        "mempool": derive_mempool_pda!().to_string(),
+        "executingPool": derive_execpool_pda!().to_string(),
+        "signPda": derive_sign_pda!().to_string(),
+        "config": config_address().to_string(),
+        "treasury": treasury_address().to_string(),
+    });
+    let fields = out.as_object_mut().expect("object literal");
+    if let Some(offset) = cluster_offset {
+        fields.insert("cluster".into(), cluster_address(offset).to_string().into());
+    }
+    match circuit {
+        Some(circuit) => {
+            if !CIRCUITS.contains(&circuit) {
+                bail!("flaek_mxe has no circuit named {circuit}");
+            }
+            fields.insert("compDefOffset".into(), comp_def_offset(circuit).into());
+            fields.insert(
+                "compDef".into(),
+                comp_def_address(circuit).to_string().into(),
+            );
+            fields.insert(
+                "circuitRegistry".into(),
+                registry_address(circuit).to_string().into(),
+            );
+        }
+        None => {
+            let offsets: Map<String, Value> = CIRCUITS
+                .iter()
+                .map(|circuit| (circuit.to_string(), comp_def_offset(circuit).into()))
+                .collect();
+            fields.insert("compDefOffsets".into(), offsets.into());
+        }
+    }
+    if let Some(offset) = computation_offset {
+        fields.insert(
+            "computation".into(),
+            derive_comp_pda!(offset).to_string().into(),
+        );
+        fields.insert(
+            "computationResult".into(),
+            computation_result_address(offset).to_string().into(),
+        );
+        fields.insert(
+            "intermediate".into(),
+            intermediate_address(offset).to_string().into(),
+        );
+        fields.insert(
+            "attestation".into(),
+            attestation_address(offset).to_string().into(),
+        );
+    }
+    Ok(out)
+}
+
+impl Artifacts {
+    fn resolve(&self, circuit: &str) -> Result<PathBuf> {
+        [
+            format!("{circuit}_{}.arcis", self.net.to_lowercase()),
+            format!("{circuit}.arcis"),
+        ]
+        .into_iter()
+        .map(|name| self.build_dir.join(name))
+        .find(|path| path.exists())
+        .with_context(|| format!("no {circuit} artifact in {}", self.build_dir.display()))
+    }
+}
+
+fn comp_def_address(circuit: &str) -> Pubkey {
+    derive_comp_def_pda!(comp_def_offset(circuit))
+}
+
+fn raw_circuit_address(comp_def: &Pubkey, index: u8) -> Pubkey {
+    Pubkey::find_program_address(
+        &[b"ComputationDefinitionRaw", comp_def.as_ref(), &[index]],
+        &ARCIUM_PROG_ID,
+    )
+    .0
+}
+
+fn update_config(authority: &Keypair) -> flaek_mxe::accounts::UpdateConfig {
+    flaek_mxe::accounts::UpdateConfig {
+        authority: authority.pubkey(),
+        config: config_address(),
+    }
+}
+
+fn instruction(accounts: impl ToAccountMetas, data: impl InstructionData) -> Instruction {
+    Instruction {
+        program_id: ID,
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+fn arcium_instruction(accounts: impl ToAccountMetas, data: impl InstructionData) -> Instruction {
+    Instruction {
+        program_id: ARCIUM_PROG_ID,
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+fn send(rpc: &RpcClient, payer: &Keypair, ix: Instruction) -> Result<String> {
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&payer.pubkey()),
+        &[payer],
+        rpc.get_latest_blockhash()?,
+    );
+    Ok(rpc.send_and_confirm_transaction(&tx)?.to_string())
+}
+
+fn hash_file(path: &Path) -> Result<[u8; 32]> {
+    let bytes = fs::read(path).with_context(|| format!("reading {}", path.display()))?;
+    Ok(Sha256::digest(bytes).into())
+}
+
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{b:02x}")).collect()
+}
+
+fn print_json(value: &Value) {
+    println!(
+        "{}",
+        serde_json::to_string_pretty(value).expect("JSON values serialize")
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A build directory holding `files`.
+    fn artifacts(files: &[&str]) -> Artifacts {
+        let build_dir = std::env::temp_dir().join(format!(
+            "flaek-mxe-cli-{}-{}",
+            std::process::id(),
+            files.join("+")
+        ));
+        fs::create_dir_all(&build_dir).unwrap();
+        for file in files {
+            fs::write(build_dir.join(file), file).unwrap();
+        }
+        Artifacts {
+            build_dir,
+            net: "Devnet".into(),
+        }
+    }
+
+    #[test]
+    fn artifacts_prefer_the_net_build() {
+        let artifacts = artifacts(&["add.arcis", "add_devnet.arcis"]);
+        assert_eq!(
+            artifacts.resolve("add").unwrap(),
+            artifacts.build_dir.join("add_devnet.arcis")
+        );
+    }
+
+    #[test]
+    fn artifacts_fall_back_to_the_plain_build() {
+        let artifacts = artifacts(&["add.arcis", "add_testnet.arcis"]);
+        assert_eq!(
+            artifacts.resolve("add").unwrap(),
+            artifacts.build_dir.join("add.arcis")
+        );
+        assert!(artifacts.resolve("subtract").is_err());
+    }
+
+    #[test]
+    fn pdas_list_every_comp_def_offset() {
+        let out = pdas(None, None, None).unwrap();
+        assert_eq!(out["programId"], ID.to_string());
+        assert_eq!(out["mxe"], derive_mxe_pda!().to_string());
+        assert_eq!(out["config"], config_address().to_string());
+        assert_eq!(out["treasury"], treasury_address().to_string());
+        let offsets = out["compDefOffsets"].as_object().unwrap();
+        assert_eq!(offsets.len(), CIRCUITS.len());
+        for circuit in CIRCUITS {
+            assert_eq!(offsets[*circuit], comp_def_offset(circuit));
+        }
+        assert!(out.get("cluster").is_none());
+        assert!(out.get("computation").is_none());
+    }
+
+    #[test]
+    fn pdas_of_a_circuit_and_a_computation() {
+        let out = pdas(Some("add"), Some(7), Some(1)).unwrap();
+        assert_eq!(out["compDefOffset"], comp_def_offset("add"));
+        assert_eq!(out["compDef"], comp_def_address("add").to_string());
+        assert_eq!(out["circuitRegistry"], registry_address("add").to_string());
+        assert_eq!(out["cluster"], cluster_address(1).to_string());
+        assert_eq!(out["computation"], derive_comp_pda!(7).to_string());
+        assert_eq!(
+            out["computationResult"],
+            computation_result_address(7).to_string()
+        );
+        assert_eq!(out["attestation"], attestation_address(7).to_string());
+        assert!(out.get("compDefOffsets").is_none());
+
+        assert!(pdas(Some("nope"), None, None).is_err());
+    }
+
+    #[test]
+    fn upload_chunks_cover_the_circuit_and_pad_the_last() {
+        let part: Vec<u8> = (0..2 * UPLOAD_CHUNK + 10).map(|i| i as u8).collect();
+        let chunks: Vec<_> = upload_chunks(&part).collect();
+        let offsets: Vec<_> = chunks.iter().map(|(offset, _)| *offset).collect();
+        assert_eq!(offsets, [0, 814, 1628]);
+        assert_eq!(chunks[1].1[..], part[814..1628]);
+        assert_eq!(chunks[2].1[..10], part[1628..]);
+        assert!(chunks[2].1[10..].iter().all(|byte| *byte == 0));
+    }
+}
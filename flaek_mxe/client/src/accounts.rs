@@ -0,0 +1,157 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{associated_token::get_associated_token_address, token};
+use arcium_anchor::prelude::*;
+use flaek_mxe::{
+    ATTESTATION_SEED, CIRCUIT_REGISTRY_SEED, COMPUTATION_RESULT_SEED, CONFIG_SEED, CREDITS_SEED,
+    ID, INTERMEDIATE_SEED, TREASURY_SEED,
+};
+
+/// The accounts every queue instruction takes, for one computation of one
+/// circuit.
+#[derive(Clone, Copy, Debug)]
+pub struct QueueAccounts {
+    pub payer: Pubkey,
+    pub sign_pda_account: Pubkey,
+    pub mxe_account: Pubkey,
+    pub mempool_account: Pubkey,
+    pub executing_pool: Pubkey,
+    pub computation_account: Pubkey,
+    pub comp_def_account: Pubkey,
+    pub circuit_registry: Pubkey,
+    pub cluster_account: Pubkey,
+    pub computation_result: Pubkey,
+    pub attestation: Pubkey,
+    pub config: Pubkey,
+    /// Must sign too when the config gates payers behind a co-signer.
+    pub co_signer: Option<Pubkey>,
+    pub treasury: Pubkey,
+    /// The token accounts fees move between when they are charged in SPL.
+    pub payer_fee_account: Option<Pubkey>,
+    pub treasury_fee_account: Option<Pubkey>,
+    pub token_program: Option<Pubkey>,
+    /// The payer's `TenantCredits`, when the circuit has a credit cost.
+    pub credits: Option<Pubkey>,
+}
+
+impl QueueAccounts {
+    pub fn new(payer: Pubkey, cluster_offset: u32, circuit: &str, computation_offset: u64) -> Self {
+        QueueAccounts {
+            payer,
+            sign_pda_account: derive_sign_pda!(),
+            mxe_account: derive_mxe_pda!(),
+            mempool_account: derive_mempool_pda!(),
+            executing_pool: derive_execpool_pda!(),
+            computation_account: derive_comp_pda!(computation_offset),
+            comp_def_account: derive_comp_def_pda!(comp_def_offset(circuit)),
+            circuit_registry: registry_address(circuit),
+            cluster_account: cluster_address(cluster_offset),
+            computation_result: computation_result_address(computation_offset),
+            attestation: attestation_address(computation_offset),
+            config: config_address(),
+            co_signer: None,
+            treasury: treasury_address(),
+            payer_fee_account: None,
+            treasury_fee_account: None,
+            token_program: None,
+            credits: None,
+        }
+    }
+
+    /// Pays the fee in `mint`, from the payer's associated token account
+    /// into the treasury's.
+    pub fn with_fee_mint(self, mint: Pubkey) -> Self {
+        QueueAccounts {
+            payer_fee_account: Some(get_associated_token_address(&self.payer, &mint)),
+            treasury_fee_account: Some(get_associated_token_address(&self.treasury, &mint)),
+            token_program: Some(token::ID),
+            ..self
+        }
+    }
+}
+
+pub fn cluster_address(cluster_offset: u32) -> Pubkey {
+    Pubkey::find_program_address(
+        &[CLUSTER_PDA_SEED, &cluster_offset.to_le_bytes()],
+        &ARCIUM_PROG_ID,
+    )
+    .0
+}
+
+/// The `CircuitRegistry` entry of `circuit`.
+pub fn registry_address(circuit: &str) -> Pubkey {
+    Pubkey::find_program_address(
+        &[
+            CIRCUIT_REGISTRY_SEED,
+            &comp_def_offset(circuit).to_le_bytes(),
+        ],
+        &ID,
+    )
+    .0
+}
+
+pub fn config_address() -> Pubkey {
+    Pubkey::find_program_address(&[CONFIG_SEED], &ID).0
+}
+
+pub fn treasury_address() -> Pubkey {
+    Pubkey::find_program_address(&[TREASURY_SEED], &ID).0
+}
+
+/// The `TenantCredits` of `tenant`.
+pub fn credits_address(tenant: Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[CREDITS_SEED, tenant.as_ref()], &ID).0
+}
+
+/// Where the callback of `computation_offset` writes its `ComputationResult`.
+pub fn computation_result_address(computation_offset: u64) -> Pubkey {
+    Pubkey::find_program_address(
+        &[COMPUTATION_RESULT_SEED, &computation_offset.to_le_bytes()],
+        &ID,
+    )
+    .0
+}
+
+/// The `Attestation` of the computation at `computation_offset`.
+pub fn attestation_address(computation_offset: u64) -> Pubkey {
+    Pubkey::find_program_address(&[ATTESTATION_SEED, &computation_offset.to_le_bytes()], &ID).0
+}
+
+/// The `Intermediate` a pipeline circuit queued at `computation_offset` fills.
+pub fn intermediate_address(computation_offset: u64) -> Pubkey {
+    Pubkey::find_program_address(&[INTERMEDIATE_SEED, &computation_offset.to_le_bytes()], &ID).0
+}
+
+/// `flaek_mxe::accounts::$accs` from a [`QueueAccounts`], plus any accounts
+/// specific to the circuit.
+macro_rules! queue_accounts {
+    ($accs:ident, $accounts:expr $(, $field:ident: $value:expr)* $(,)?) => {{
+        let accounts: &$crate::QueueAccounts = $accounts;
+        flaek_mxe::accounts::$accs {
+            payer: accounts.payer,
+            sign_pda_account: accounts.sign_pda_account,
+            mxe_account: accounts.mxe_account,
+            mempool_account: accounts.mempool_account,
+            executing_pool: accounts.executing_pool,
+            computation_account: accounts.computation_account,
+            comp_def_account: accounts.comp_def_account,
+            circuit_registry: accounts.circuit_registry,
+            cluster_account: accounts.cluster_account,
+            pool_account: ARCIUM_FEE_POOL_ACCOUNT_ADDRESS,
+            clock_account: ARCIUM_CLOCK_ACCOUNT_ADDRESS,
+            computation_result: accounts.computation_result,
+            config: accounts.config,
+            co_signer: accounts.co_signer,
+            treasury: accounts.treasury,
+            payer_fee_account: accounts.payer_fee_account,
+            treasury_fee_account: accounts.treasury_fee_account,
+            token_program: accounts.token_program,
+            credits: accounts.credits,
+            attestation: accounts.attestation,
+            system_program: anchor_lang::system_program::ID,
+            arcium_program: ARCIUM_PROG_ID,
+            $($field: $value,)*
+        }
+    }};
+}
+
+pub(crate) use queue_accounts;
@@ -0,0 +1,106 @@
+//! Rescue over the Curve25519 base field, from Arcium's own `arcis` crate
+//! so client ciphertexts are exactly what the MXE decrypts: HKDF over the
+//! Rescue-Prime hash derives the key from the x25519 shared secret, and the
+//! Rescue block cipher runs in counter mode over field elements.
+
+use std::sync::OnceLock;
+
+use arcis::{
+    traits::FromLeBytes,
+    utils::{
+        crypto::{
+            hkdf::{HKDF_RescuePrime, HKDF},
+            rescue_desc::RescueDesc,
+        },
+        matrix::Matrix,
+    },
+    ArcisField,
+};
+use num_bigint::{BigInt, BigUint, Sign};
+
+/// Field elements per cipher block, and so per counter.
+const BLOCK_SIZE: usize = 5;
+
+/// `2^255 - 19`.
+pub(crate) fn modulus() -> &'static BigUint {
+    static P: OnceLock<BigUint> = OnceLock::new();
+    P.get_or_init(|| (BigUint::from(1u8) << 255) - 19u8)
+}
+
+/// Maps a signed integer into the field, negatives as `p - |x|`.
+pub(crate) fn to_field(value: BigInt) -> BigUint {
+    let p = BigInt::from(modulus().clone());
+    ((value % &p + &p) % &p).magnitude().clone()
+}
+
+/// Inverse of [`to_field`]: elements above `p / 2` read as negative.
+pub(crate) fn from_field(value: BigUint) -> BigInt {
+    if value > modulus() >> 1 {
+        BigInt::from_biguint(Sign::Minus, modulus() - value)
+    } else {
+        BigInt::from(value)
+    }
+}
+
+pub(crate) fn to_bytes(value: &BigUint) -> [u8; 32] {
+    let mut bytes = [0; 32];
+    let le = value.to_bytes_le();
+    bytes[..le.len()].copy_from_slice(&le);
+    bytes
+}
+
+/// The cipher an x25519 shared secret gives both the client and the MXE.
+/// Ciphertexts are 32-byte little-endian field elements.
+pub struct RescueCipher {
+    desc: RescueDesc<ArcisField, ArcisField>,
+}
+
+impl RescueCipher {
+    pub fn new(shared_secret: [u8; 32]) -> Self {
+        RescueCipher {
+            desc: RescueDesc::new_cipher_desc(Matrix::from(derive_key(shared_secret))),
+        }
+    }
+
+    /// Encrypts `plaintext`, each element below `p`, under `nonce`.
+    pub fn encrypt(&self, plaintext: &[BigUint], nonce: [u8; 16]) -> Vec<[u8; 32]> {
+        self.keystream(plaintext.len(), nonce)
+            .zip(plaintext)
+            .map(|(key, value)| (field(value) + key).to_le_bytes())
+            .collect()
+    }
+
+    pub fn decrypt(&self, ciphertexts: &[[u8; 32]], nonce: [u8; 16]) -> Vec<BigUint> {
+        self.keystream(ciphertexts.len(), nonce)
+            .zip(ciphertexts)
+            .map(|(key, ciphertext)| {
+                let value = field(&BigUint::from_bytes_le(ciphertext)) - key;
+                BigUint::from_bytes_le(&value.to_le_bytes())
+            })
+            .collect()
+    }
+
+    /// Block `i` encrypts the counter `[nonce, i, 0, 0, 0]`.
+    fn keystream(&self, len: usize, nonce: [u8; 16]) -> impl Iterator<Item = ArcisField> + '_ {
+        let nonce = field(&BigUint::from_bytes_le(&nonce));
+        (0..len.div_ceil(BLOCK_SIZE)).flat_map(move |block| {
+            let mut counter = vec![ArcisField::from(0u64); BLOCK_SIZE];
+            counter[0] = nonce;
+            counter[1] = ArcisField::from(block as u64);
+            self.desc.permute(&Matrix::from(counter))
+        })
+    }
+}
+
+/// The cipher key: HKDF with empty salt and info over the shared secret.
+pub(crate) fn derive_key(shared_secret: [u8; 32]) -> Vec<ArcisField> {
+    let secret = field(&BigUint::from_bytes_le(&shared_secret));
+    HKDF_RescuePrime::new()
+        .okm(Vec::new(), vec![secret], Vec::new())
+        .to_vec()
+}
+
+/// `value` reduced into the field.
+fn field(value: &BigUint) -> ArcisField {
+    ArcisField::from_le_bytes(to_bytes(&(value % modulus())))
+}
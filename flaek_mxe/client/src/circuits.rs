@@ -0,0 +1,163 @@
+//! Typed inputs and outputs for every circuit whose inputs are all encrypted
+//! by the caller. The field order of each struct is the circuit's.
+
+use anchor_lang::solana_program::instruction::Instruction;
+use arcium_anchor::prelude::*;
+
+use crate::{
+    accounts::queue_accounts, instruction, Inputs, Output, Plaintext, QueueAccounts, Result,
+    Session,
+};
+
+macro_rules! circuits {
+    ($(
+        $circuit:ident => $ix:ident($inputs:ident { $($arg:ident: $ty:ty),* $(,)? })
+            -> $event:ident($output:ident { $($field:ident: $out:ty),* $(,)? });
+    )*) => {$(
+        #[doc = concat!("Inputs of `", stringify!($circuit), "`.")]
+        #[derive(Clone, Debug, Default, PartialEq, Eq)]
+        pub struct $inputs {
+            $(pub $arg: $ty,)*
+        }
+
+        impl Inputs for $inputs {
+            const CIRCUIT: &'static str = stringify!($circuit);
+
+            fn instruction(
+                &self,
+                session: &Session,
+                accounts: &QueueAccounts,
+                computation_offset: u64,
+            ) -> Instruction {
+                let mut fields = Vec::new();
+                $(self.$arg.encode(&mut fields);)*
+                let (ciphertexts, nonce) = session.encrypt(&fields);
+                let mut ciphertexts = ciphertexts.into_iter();
+                instruction(
+                    queue_accounts!($ix, accounts),
+                    flaek_mxe::instruction::$ix {
+                        computation_offset,
+                        $($arg: <$ty as Plaintext>::pack(&mut ciphertexts),)*
+                        pub_key: session.public_key(),
+                        nonce,
+                    },
+                )
+            }
+        }
+
+        #[doc = concat!("Decrypted output of `", stringify!($circuit), "`.")]
+        #[derive(Clone, Debug, PartialEq, Eq)]
+        pub struct $output {
+            $(pub $field: $out,)*
+        }
+
+        impl Output for $output {
+            type Event = flaek_mxe::$event;
+
+            fn decrypt(session: &Session, event: &flaek_mxe::$event) -> Result<Self> {
+                let mut ciphertexts = Vec::new();
+                $(<$out as Plaintext>::unpack(&event.$field, &mut ciphertexts);)*
+                let mut fields = session.decrypt_fields(&ciphertexts, event.nonce).into_iter();
+                Ok($output {
+                    $($field: <$out as Plaintext>::decode(&mut fields)?,)*
+                })
+            }
+        }
+    )*
+
+        /// Every circuit above with the queue instruction its inputs build,
+        /// to check this list against the program's.
+        #[cfg(test)]
+        pub(crate) fn queue_instructions(session: &Session) -> Vec<(&'static str, Instruction)> {
+            vec![$((
+                stringify!($circuit),
+                $inputs::default().instruction(
+                    session,
+                    &QueueAccounts::new(
+                        anchor_lang::prelude::Pubkey::default(),
+                        0,
+                        stringify!($circuit),
+                        0,
+                    ),
+                    0,
+                ),
+            )),*]
+        }
+    };
+}
+
+circuits! {
+    // math
+    add => Add(AddInputs { a: u64, b: u64 }) -> AddEvent(AddResult { result: u64 });
+    subtract => Subtract(SubtractInputs { a: u64, b: u64 })
+        -> SubtractEvent(SubtractResult { result: u64 });
+    multiply => Multiply(MultiplyInputs { a: u64, b: u64 })
+        -> MultiplyEvent(MultiplyResult { result: u64 });
+    divide => Divide(DivideInputs { a: u64, b: u64 }) -> DivideEvent(DivideResult { result: u64 });
+    modulo => Modulo(ModuloInputs { a: u64, b: u64 }) -> ModuloEvent(ModuloResult { result: u64 });
+    abs_diff => AbsDiff(AbsDiffInputs { a: u64, b: u64 })
+        -> AbsDiffEvent(AbsDiffResult { result: u64 });
+    power => Power(PowerInputs { base: u64, exponent: u8 })
+        -> PowerEvent(PowerResult { result: u64 });
+    checked_add => CheckedAdd(CheckedAddInputs { a: u64, b: u64 })
+        -> CheckedAddEvent(CheckedAddResult { result: u64, overflow: u8 });
+    checked_multiply => CheckedMultiply(CheckedMultiplyInputs { a: u64, b: u64 })
+        -> CheckedMultiplyEvent(CheckedMultiplyResult { result: u64, overflow: u8 });
+    checked_power => CheckedPower(CheckedPowerInputs { base: u64, exponent: u8 })
+        -> CheckedPowerEvent(CheckedPowerResult { result: u64, overflow: u8 });
+    add_i64 => AddI64(AddI64Inputs { a: i64, b: i64 }) -> AddI64Event(AddI64Result { result: i64 });
+    subtract_i64 => SubtractI64(SubtractI64Inputs { a: i64, b: i64 })
+        -> SubtractI64Event(SubtractI64Result { result: i64 });
+    multiply_i64 => MultiplyI64(MultiplyI64Inputs { a: i64, b: i64 })
+        -> MultiplyI64Event(MultiplyI64Result { result: i64 });
+    divide_i64 => DivideI64(DivideI64Inputs { a: i64, b: i64 })
+        -> DivideI64Event(DivideI64Result { result: i64 });
+    abs_diff_i64 => AbsDiffI64(AbsDiffI64Inputs { a: i64, b: i64 })
+        -> AbsDiffI64Event(AbsDiffI64Result { result: u64 });
+
+    // comparison
+    greater_than => GreaterThan(GreaterThanInputs { a: u64, b: u64 })
+        -> GreaterThanEvent(GreaterThanResult { result: u8 });
+    less_than => LessThan(LessThanInputs { a: u64, b: u64 })
+        -> LessThanEvent(LessThanResult { result: u8 });
+    equal => Equal(EqualInputs { a: u64, b: u64 }) -> EqualEvent(EqualResult { result: u8 });
+    greater_equal => GreaterEqual(GreaterEqualInputs { a: u64, b: u64 })
+        -> GreaterEqualEvent(GreaterEqualResult { result: u8 });
+    less_equal => LessEqual(LessEqualInputs { a: u64, b: u64 })
+        -> LessEqualEvent(LessEqualResult { result: u8 });
+    in_range => InRange(InRangeInputs { value: u64, min: u64, max: u64 })
+        -> InRangeEvent(InRangeResult { result: u8 });
+    compare_i64 => CompareI64(CompareI64Inputs { a: i64, b: i64 })
+        -> CompareI64Event(CompareI64Result { result: i8 });
+
+    // logical
+    and => And(AndInputs { a: u8, b: u8 }) -> AndEvent(AndResult { result: u8 });
+    or => Or(OrInputs { a: u8, b: u8 }) -> OrEvent(OrResult { result: u8 });
+    xor => Xor(XorInputs { a: u8, b: u8 }) -> XorEvent(XorResult { result: u8 });
+    not => Not(NotInputs { a: u8 }) -> NotEvent(NotResult { result: u8 });
+    if_else => IfElse(IfElseInputs { condition: u8, true_value: u64, false_value: u64 })
+        -> IfElseEvent(IfElseResult { result: u64 });
+
+    // statistics
+    average => Average(AverageInputs { values: [u64; 10], count: u8 })
+        -> AverageEvent(AverageResult { result: u64 });
+    sum => Sum(SumInputs { values: [u64; 10], count: u8 }) -> SumEvent(SumResult { result: u64 });
+    min => Min(MinInputs { values: [u64; 10], count: u8 }) -> MinEvent(MinResult { result: u64 });
+    max => Max(MaxInputs { values: [u64; 10], count: u8 }) -> MaxEvent(MaxResult { result: u64 });
+    median => Median(MedianInputs { values: [u64; 10], count: u8 })
+        -> MedianEvent(MedianResult { result: u64 });
+
+    // fixed point (Q32.32, raw i64)
+    fixed_add => FixedAdd(FixedAddInputs { a: i64, b: i64 })
+        -> FixedAddEvent(FixedAddResult { result: i64 });
+    fixed_sub => FixedSub(FixedSubInputs { a: i64, b: i64 })
+        -> FixedSubEvent(FixedSubResult { result: i64 });
+    fixed_mul => FixedMul(FixedMulInputs { a: i64, b: i64 })
+        -> FixedMulEvent(FixedMulResult { result: i64 });
+    fixed_div => FixedDiv(FixedDivInputs { a: i64, b: i64 })
+        -> FixedDivEvent(FixedDivResult { result: i64 });
+    fixed_round => FixedRound(FixedRoundInputs { value: i64 })
+        -> FixedRoundEvent(FixedRoundResult { result: i64 });
+    fixed_percent => FixedPercent(FixedPercentInputs { value: i64, percent: i64 })
+        -> FixedPercentEvent(FixedPercentResult { result: i64 });
+}
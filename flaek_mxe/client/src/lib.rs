@@ -0,0 +1,380 @@
+//! Rust client for flaek_mxe: encrypts circuit inputs to the MXE, builds the
+//! queue instruction with every account it needs, and decrypts the `*Event`
+//! the callback emits. Does what tests/flaek_mxe.ts does with
+//! @arcium-hq/client, for backends that submit jobs directly.
+//!
+//! ```ignore
+//! let session = Session::new(mxe_public_key);
+//! let client = FlaekClient::new(cluster_offset);
+//! let ix = client.queue(&session, payer, computation_offset, &CreditScoreInputs { .. });
+//! // send ix, then from the transaction logs:
+//! let event = events::<flaek_mxe::CreditScoreEvent>(&logs).pop().unwrap();
+//! let CreditScoreResult { score, approved } = session.decrypt(&event)?;
+//! ```
+
+mod accounts;
+mod cipher;
+mod circuits;
+mod pipeline;
+mod plaintext;
+mod session;
+mod use_cases;
+
+use std::fmt;
+
+use anchor_lang::{
+    prelude::*, solana_program::instruction::Instruction, Event, InstructionData, ToAccountMetas,
+};
+use base64::{engine::general_purpose::STANDARD, Engine};
+
+pub use accounts::{
+    attestation_address, cluster_address, computation_result_address, config_address,
+    credits_address, intermediate_address, registry_address, treasury_address, QueueAccounts,
+};
+pub use cipher::RescueCipher;
+pub use circuits::*;
+pub use flaek_mxe::{self, ID};
+pub use pipeline::*;
+pub use plaintext::Plaintext;
+pub use session::Session;
+pub use use_cases::*;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Error {
+    /// The event carried fewer ciphertexts than the output has fields.
+    MissingCiphertext,
+    /// A decrypted value does not fit its output field, which means the
+    /// event was not encrypted for this session.
+    OutOfRange,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::MissingCiphertext => write!(f, "event is missing a ciphertext"),
+            Error::OutOfRange => write!(f, "decrypted value is out of range for its type"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+/// A circuit's inputs, ready to be encrypted and queued.
+pub trait Inputs {
+    /// The circuit's name in encrypted-ixs.
+    const CIRCUIT: &'static str;
+
+    /// Encrypts the inputs for `session` and builds the queue instruction.
+    fn instruction(
+        &self,
+        session: &Session,
+        accounts: &QueueAccounts,
+        computation_offset: u64,
+    ) -> Instruction;
+}
+
+/// A circuit's decrypted output.
+pub trait Output: Sized {
+    type Event: Event + AnchorDeserialize;
+
+    fn decrypt(session: &Session, event: &Self::Event) -> Result<Self>;
+}
+
+/// Builds queue instructions against one Arcium cluster.
+#[derive(Clone, Copy, Debug)]
+pub struct FlaekClient {
+    pub cluster_offset: u32,
+    /// Added as a signer to every queue instruction, for programs whose
+    /// config gates payers behind a co-signer.
+    pub co_signer: Option<Pubkey>,
+    /// The SPL mint the config charges fees in, if not lamports.
+    pub fee_mint: Option<Pubkey>,
+    /// Debits the payer's prepaid `TenantCredits` for circuits that cost
+    /// credits.
+    pub use_credits: bool,
+}
+
+impl FlaekClient {
+    pub fn new(cluster_offset: u32) -> Self {
+        FlaekClient {
+            cluster_offset,
+            co_signer: None,
+            fee_mint: None,
+            use_credits: false,
+        }
+    }
+
+    pub fn with_co_signer(self, co_signer: Pubkey) -> Self {
+        FlaekClient {
+            co_signer: Some(co_signer),
+            ..self
+        }
+    }
+
+    pub fn with_fee_mint(self, fee_mint: Pubkey) -> Self {
+        FlaekClient {
+            fee_mint: Some(fee_mint),
+            ..self
+        }
+    }
+
+    pub fn with_credits(self) -> Self {
+        FlaekClient {
+            use_credits: true,
+            ..self
+        }
+    }
+
+    /// The instruction queuing `inputs` at `computation_offset`, paid for by
+    /// `payer`, which must sign, as must the co-signer if there is one.
+    pub fn queue<I: Inputs>(
+        &self,
+        session: &Session,
+        payer: Pubkey,
+        computation_offset: u64,
+        inputs: &I,
+    ) -> Instruction {
+        let mut accounts = QueueAccounts {
+            co_signer: self.co_signer,
+            credits: self.use_credits.then(|| credits_address(payer)),
+            ..QueueAccounts::new(payer, self.cluster_offset, I::CIRCUIT, computation_offset)
+        };
+        if let Some(mint) = self.fee_mint {
+            accounts = accounts.with_fee_mint(mint);
+        }
+        inputs.instruction(session, &accounts, computation_offset)
+    }
+}
+
+/// Events of type `E` among a transaction's log messages.
+pub fn events<E: Event + AnchorDeserialize>(logs: &[String]) -> Vec<E> {
+    logs.iter()
+        .filter_map(|line| line.strip_prefix("Program data: "))
+        .filter_map(|data| STANDARD.decode(data).ok())
+        .filter_map(|bytes| decode_event(&bytes))
+        .collect()
+}
+
+/// An event from its emitted bytes, if they hold an `E`.
+pub fn decode_event<E: Event + AnchorDeserialize>(bytes: &[u8]) -> Option<E> {
+    let mut body = bytes.strip_prefix(E::DISCRIMINATOR)?;
+    E::deserialize(&mut body).ok()
+}
+
+fn instruction(accounts: impl ToAccountMetas, data: impl InstructionData) -> Instruction {
+    Instruction {
+        program_id: ID,
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anchor_lang::solana_program::hash::hash;
+    use num_bigint::BigUint;
+    use x25519_dalek::{PublicKey, StaticSecret};
+
+    use super::*;
+
+    /// The MXE's side of a session: the same shared secret, derived from
+    /// the MXE's private key and the client's public key.
+    fn mxe_cipher(mxe_secret: &StaticSecret, client: &Session) -> RescueCipher {
+        let shared = mxe_secret.diffie_hellman(&PublicKey::from(client.public_key()));
+        RescueCipher::new(shared.to_bytes())
+    }
+
+    #[test]
+    fn inputs_decrypt_on_the_mxe_side() {
+        let mxe_secret = StaticSecret::from([3; 32]);
+        let session = Session::new(PublicKey::from(&mxe_secret).to_bytes());
+        let ix = FlaekClient::new(1).queue(
+            &session,
+            Pubkey::new_unique(),
+            9,
+            &FixedAddInputs { a: -5, b: 1 << 40 },
+        );
+
+        let mut data = &ix.data[8..];
+        let queued = flaek_mxe::instruction::FixedAdd::deserialize(&mut data).unwrap();
+        assert_eq!(queued.computation_offset, 9);
+        assert_eq!(queued.pub_key, session.public_key());
+        let mut fields = mxe_cipher(&mxe_secret, &session)
+            .decrypt(&[queued.a, queued.b], queued.nonce.to_le_bytes())
+            .into_iter();
+        assert_eq!(i64::decode(&mut fields), Ok(-5));
+        assert_eq!(i64::decode(&mut fields), Ok(1 << 40));
+    }
+
+    #[test]
+    fn events_decrypt_into_typed_outputs() {
+        let mxe_secret = StaticSecret::from([5; 32]);
+        let session = Session::new(PublicKey::from(&mxe_secret).to_bytes());
+        let lender = Session::new(PublicKey::from(&mxe_secret).to_bytes());
+        let nonce = [9; 16];
+        let mut fields = Vec::new();
+        712u16.encode(&mut fields);
+        1u8.encode(&mut fields);
+        let ciphertexts = mxe_cipher(&mxe_secret, &session).encrypt(&fields, nonce);
+        let lender_ciphertexts = mxe_cipher(&mxe_secret, &lender).encrypt(&fields[1..], nonce);
+
+        let event = flaek_mxe::CreditScoreEvent {
+            computation_offset: 1,
+            payer: Pubkey::new_unique(),
+            circuit: 0,
+            score: ciphertexts[0],
+            approved: ciphertexts[1],
+            nonce,
+            lender_approved: lender_ciphertexts[0],
+            lender_pub_key: lender.public_key(),
+            lender_nonce: nonce,
+            revealed_approved: 1,
+        };
+        let logs = vec![format!("Program data: {}", STANDARD.encode(event.data()))];
+        let event = events::<flaek_mxe::CreditScoreEvent>(&logs).pop().unwrap();
+        assert_eq!(
+            session.decrypt::<CreditScoreResult>(&event),
+            Ok(CreditScoreResult {
+                score: 712,
+                approved: 1
+            })
+        );
+
+        assert_eq!(
+            lender.decrypt::<LenderDecision>(&event),
+            Ok(LenderDecision { approved: 1 })
+        );
+
+        let stranger = Session::new(PublicKey::from(&mxe_secret).to_bytes());
+        assert_ne!(
+            stranger.decrypt::<CreditScoreResult>(&event),
+            Ok(CreditScoreResult {
+                score: 712,
+                approved: 1
+            })
+        );
+    }
+
+    fn bytes(hex: &str) -> [u8; 32] {
+        let mut bytes = [0; 32];
+        for (i, byte) in bytes.iter_mut().enumerate() {
+            *byte = u8::from_str_radix(&hex[2 * i..2 * i + 2], 16).unwrap();
+        }
+        bytes
+    }
+
+    /// The x25519 secret between client key `[4; 32]` and MXE key `[3; 32]`.
+    const SHARED: &str = "40e47a3f525bdcac491d418978d7db5af623ac7afe7623c6d78a5d4fce9d0f63";
+
+    #[test]
+    fn shared_secret_derives_the_known_key() {
+        let client = StaticSecret::from([4; 32]);
+        let mxe = PublicKey::from(&StaticSecret::from([3; 32]));
+        assert_eq!(client.diffie_hellman(&mxe).to_bytes(), bytes(SHARED));
+
+        let key: Vec<_> = cipher::derive_key(bytes(SHARED))
+            .iter()
+            .map(|k| k.to_le_bytes())
+            .collect();
+        assert_eq!(
+            key,
+            [
+                "180e750fb2cc286a68f0b3f3edc1d29695366786f9f2e478d94f6d6a0b5bf62f",
+                "ac1d87f84420084aa844f0719fbe5a8ac012d57a97eeaeed1d1a65225746f666",
+                "22f9771a6419709eeba8373632008eac2d94f9b1f495cb31b1d41f76351e0e06",
+                "3010bd5f03392525fb6c341891452b8da4585c249ad460bf141dfc4d450c5f6c",
+                "1daa242e70fdef02244fcddcb09ac468514b5484be9b6c503825204a7d3a4875",
+            ]
+            .map(bytes)
+        );
+    }
+
+    #[test]
+    fn plaintext_encrypts_to_the_known_ciphertext() {
+        // Seven elements, so the second counter block is used too.
+        let plaintext: Vec<_> = (1..=7u32).map(|i| BigUint::from(i * 1000 + 7)).collect();
+        let ciphertexts = [
+            "5ba90909b24c0852d328c98c13338ffb71e6b13e4b71dbf22a41c0fed675ff3f",
+            "5d5c70a57dab497df80fe511efcf83abde0ddff598d5fa719f50a292c2f6080f",
+            "090fb18e32247d14a98f031136a9dec0e7c1e47cc0ed0589e7e0a793a4e89473",
+            "70f4feb32d529c107adac0a4d4ae17b191fb8e5fe5c38c1428811f1115088f18",
+            "d2dd37963bafb8a3ece3bde3bad61ea274c8f5c5a87afbf798734192bab38677",
+            "391103869a96765d86e750031f5675061027ec47c1ec7d0a1dc2925d309dcd5a",
+            "d612bcbb2ab5dbcb3d36fe53f76ecd0a133f5dfbee2a262ea6eaf028909d9319",
+        ]
+        .map(bytes);
+
+        let cipher = RescueCipher::new(bytes(SHARED));
+        assert_eq!(cipher.encrypt(&plaintext, [9; 16]), ciphertexts);
+        assert_eq!(cipher.decrypt(&ciphertexts, [9; 16]), plaintext);
+    }
+
+    #[test]
+    fn ciphertexts_match_the_mxe_cipher() {
+        use arcis::{
+            traits::FromLeBytes,
+            utils::crypto::{key, rescue_cipher},
+            ArcisField,
+        };
+
+        let mxe_secret = [3; 32];
+        let session = Session::new(PublicKey::from(&StaticSecret::from(mxe_secret)).to_bytes());
+        let mxe = rescue_cipher::RescueCipher::<ArcisField>::new_with_client_from_key_pair(
+            key::PrivateKey::<bool>::from_le_bytes(mxe_secret),
+            key::PublicKey::new_from_inner(ArcisField::from_le_bytes(session.public_key())),
+        );
+        let mut nonce = [0; 32];
+        nonce[..16].copy_from_slice(&[7; 16]);
+        let expected: Vec<_> = mxe
+            .encrypt(
+                (0..6u64).map(|i| ArcisField::from(i * 31)).collect(),
+                ArcisField::from_le_bytes(nonce),
+            )
+            .iter()
+            .map(|c| c.to_le_bytes())
+            .collect();
+
+        let plaintext: Vec<_> = (0..6u64).map(|i| BigUint::from(i * 31)).collect();
+        let shared = StaticSecret::from(session.secret())
+            .diffie_hellman(&PublicKey::from(&StaticSecret::from(mxe_secret)));
+        assert_eq!(
+            RescueCipher::new(shared.to_bytes()).encrypt(&plaintext, [7; 16]),
+            expected
+        );
+    }
+
+    #[test]
+    fn typed_circuits_are_the_programs() {
+        let session = Session::new(PublicKey::from(&StaticSecret::from([3; 32])).to_bytes());
+        let mut seen = std::collections::HashSet::new();
+        for (circuit, ix) in circuits::queue_instructions(&session) {
+            assert!(seen.insert(circuit), "{circuit} is listed twice");
+            assert!(
+                flaek_mxe::CIRCUITS.contains(&circuit),
+                "{circuit} is not a flaek_mxe circuit"
+            );
+            // Comp def offsets are `comp_def_offset(name)` on both sides, so
+            // the names and the instruction each queues are what can drift.
+            let discriminator = hash(format!("global:{circuit}").as_bytes()).to_bytes();
+            assert_eq!(
+                ix.data[..8],
+                discriminator[..8],
+                "{circuit} queues another instruction"
+            );
+        }
+    }
+
+    #[test]
+    fn signed_values_round_trip_through_the_field() {
+        for value in [i64::MIN, -1, 0, 1, i64::MAX] {
+            let mut fields = Vec::new();
+            value.encode(&mut fields);
+            assert_eq!(i64::decode(&mut fields.into_iter()), Ok(value));
+        }
+        let mut fields = Vec::new();
+        (-1i64).encode(&mut fields);
+        assert_eq!(u64::decode(&mut fields.into_iter()), Err(Error::OutOfRange));
+    }
+}
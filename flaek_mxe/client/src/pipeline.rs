@@ -0,0 +1,176 @@
+//! The pipeline circuits, whose inputs live in `Intermediate` accounts or
+//! are passed in plaintext.
+
+use anchor_lang::{prelude::*, solana_program::instruction::Instruction};
+use arcium_anchor::prelude::*;
+
+use crate::{
+    accounts::{intermediate_address, queue_accounts},
+    instruction,
+    session::random_nonce,
+    Inputs, Output, Plaintext, QueueAccounts, Result, Session,
+};
+
+/// Seals `value` into a new `Intermediate` at the computation's
+/// [`intermediate_address`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SealInputs {
+    pub value: u64,
+}
+
+impl Inputs for SealInputs {
+    const CIRCUIT: &'static str = "seal";
+
+    fn instruction(
+        &self,
+        session: &Session,
+        accounts: &QueueAccounts,
+        computation_offset: u64,
+    ) -> Instruction {
+        let mut fields = Vec::new();
+        self.value.encode(&mut fields);
+        let (ciphertexts, nonce) = session.encrypt(&fields);
+        instruction(
+            queue_accounts!(
+                Seal,
+                accounts,
+                intermediate: intermediate_address(computation_offset),
+            ),
+            flaek_mxe::instruction::Seal {
+                computation_offset,
+                value: ciphertexts[0],
+                pub_key: session.public_key(),
+                nonce,
+            },
+        )
+    }
+}
+
+/// Applies `op` to two finished intermediates the payer owns.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PipelineStepInputs {
+    pub op: u8,
+    pub lhs: Pubkey,
+    pub rhs: Pubkey,
+}
+
+impl Inputs for PipelineStepInputs {
+    const CIRCUIT: &'static str = "pipeline_step";
+
+    fn instruction(
+        &self,
+        _session: &Session,
+        accounts: &QueueAccounts,
+        computation_offset: u64,
+    ) -> Instruction {
+        instruction(
+            queue_accounts!(
+                PipelineStep,
+                accounts,
+                lhs: self.lhs,
+                rhs: self.rhs,
+                intermediate: intermediate_address(computation_offset),
+            ),
+            flaek_mxe::instruction::PipelineStep {
+                computation_offset,
+                op: self.op,
+            },
+        )
+    }
+}
+
+/// Re-encrypts the intermediate at `value` to `recipient`, who decrypts the
+/// `UnsealEvent` with their own session.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UnsealInputs {
+    pub recipient: [u8; 32],
+    pub value: Pubkey,
+}
+
+impl Inputs for UnsealInputs {
+    const CIRCUIT: &'static str = "unseal";
+
+    fn instruction(
+        &self,
+        _session: &Session,
+        accounts: &QueueAccounts,
+        computation_offset: u64,
+    ) -> Instruction {
+        instruction(
+            queue_accounts!(Unseal, accounts, value: self.value),
+            flaek_mxe::instruction::Unseal {
+                computation_offset,
+                recipient_pub_key: self.recipient,
+                recipient_nonce: u128::from_le_bytes(random_nonce()),
+            },
+        )
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UnsealResult {
+    pub result: u64,
+}
+
+impl Output for UnsealResult {
+    type Event = flaek_mxe::UnsealEvent;
+
+    fn decrypt(session: &Session, event: &flaek_mxe::UnsealEvent) -> Result<Self> {
+        let mut fields = session
+            .decrypt_fields(&[event.result], event.nonce)
+            .into_iter();
+        Ok(UnsealResult {
+            result: u64::decode(&mut fields)?,
+        })
+    }
+}
+
+/// Runs the plaintext `program` over the encrypted `registers`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PipelineEvalInputs {
+    pub program: [u8; 64],
+    pub registers: [u64; 8],
+}
+
+impl Inputs for PipelineEvalInputs {
+    const CIRCUIT: &'static str = "pipeline_eval";
+
+    fn instruction(
+        &self,
+        session: &Session,
+        accounts: &QueueAccounts,
+        computation_offset: u64,
+    ) -> Instruction {
+        let mut fields = Vec::new();
+        self.registers.encode(&mut fields);
+        let (ciphertexts, nonce) = session.encrypt(&fields);
+        instruction(
+            queue_accounts!(PipelineEval, accounts),
+            flaek_mxe::instruction::PipelineEval {
+                computation_offset,
+                program: self.program,
+                registers: <[u64; 8]>::pack(&mut ciphertexts.into_iter()),
+                pub_key: session.public_key(),
+                nonce,
+            },
+        )
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PipelineEvalResult {
+    pub registers: [u64; 8],
+}
+
+impl Output for PipelineEvalResult {
+    type Event = flaek_mxe::PipelineEvalEvent;
+
+    fn decrypt(session: &Session, event: &flaek_mxe::PipelineEvalEvent) -> Result<Self> {
+        let mut fields = session
+            .decrypt_fields(&event.registers, event.nonce)
+            .into_iter();
+        Ok(PipelineEvalResult {
+            registers: <[u64; 8]>::decode(&mut fields)?,
+        })
+    }
+}
@@ -0,0 +1,103 @@
+use num_bigint::{BigInt, BigUint};
+
+use crate::{
+    cipher::{from_field, to_field},
+    Error, Result,
+};
+
+/// A value a circuit takes or returns, as the field elements the cipher
+/// works on and the ciphertext shape the program passes around.
+pub trait Plaintext: Sized {
+    /// `[u8; 32]` for scalars, `[[u8; 32]; N]` for arrays, matching the
+    /// instruction argument and event field.
+    type Ciphertext;
+
+    fn encode(&self, fields: &mut Vec<BigUint>);
+
+    fn decode(fields: &mut impl Iterator<Item = BigUint>) -> Result<Self>;
+
+    /// Takes this value's ciphertexts off `ciphertexts`, which holds at least
+    /// one per encoded field.
+    fn pack(ciphertexts: &mut impl Iterator<Item = [u8; 32]>) -> Self::Ciphertext;
+
+    fn unpack(ciphertext: &Self::Ciphertext, ciphertexts: &mut Vec<[u8; 32]>);
+}
+
+macro_rules! integer {
+    ($($ty:ty),*) => {$(
+        impl Plaintext for $ty {
+            type Ciphertext = [u8; 32];
+
+            fn encode(&self, fields: &mut Vec<BigUint>) {
+                fields.push(to_field(BigInt::from(*self)));
+            }
+
+            fn decode(fields: &mut impl Iterator<Item = BigUint>) -> Result<Self> {
+                let field = fields.next().ok_or(Error::MissingCiphertext)?;
+                <$ty>::try_from(from_field(field)).map_err(|_| Error::OutOfRange)
+            }
+
+            fn pack(ciphertexts: &mut impl Iterator<Item = [u8; 32]>) -> [u8; 32] {
+                ciphertexts.next().expect("one ciphertext per field")
+            }
+
+            fn unpack(ciphertext: &[u8; 32], ciphertexts: &mut Vec<[u8; 32]>) {
+                ciphertexts.push(*ciphertext);
+            }
+        }
+    )*};
+}
+
+integer!(u8, u16, u32, u64, u128, i8, i16, i32, i64, i128);
+
+impl Plaintext for bool {
+    type Ciphertext = [u8; 32];
+
+    fn encode(&self, fields: &mut Vec<BigUint>) {
+        u8::from(*self).encode(fields);
+    }
+
+    fn decode(fields: &mut impl Iterator<Item = BigUint>) -> Result<Self> {
+        match u8::decode(fields)? {
+            0 => Ok(false),
+            1 => Ok(true),
+            _ => Err(Error::OutOfRange),
+        }
+    }
+
+    fn pack(ciphertexts: &mut impl Iterator<Item = [u8; 32]>) -> [u8; 32] {
+        u8::pack(ciphertexts)
+    }
+
+    fn unpack(ciphertext: &[u8; 32], ciphertexts: &mut Vec<[u8; 32]>) {
+        ciphertexts.push(*ciphertext);
+    }
+}
+
+impl<T: Plaintext<Ciphertext = [u8; 32]>, const N: usize> Plaintext for [T; N] {
+    type Ciphertext = [[u8; 32]; N];
+
+    fn encode(&self, fields: &mut Vec<BigUint>) {
+        for value in self {
+            value.encode(fields);
+        }
+    }
+
+    fn decode(fields: &mut impl Iterator<Item = BigUint>) -> Result<Self> {
+        let values = (0..N)
+            .map(|_| T::decode(fields))
+            .collect::<Result<Vec<T>>>()?;
+        match values.try_into() {
+            Ok(values) => Ok(values),
+            Err(_) => unreachable!("decoded exactly N values"),
+        }
+    }
+
+    fn pack(ciphertexts: &mut impl Iterator<Item = [u8; 32]>) -> [[u8; 32]; N] {
+        std::array::from_fn(|_| T::pack(ciphertexts))
+    }
+
+    fn unpack(ciphertext: &[[u8; 32]; N], ciphertexts: &mut Vec<[u8; 32]>) {
+        ciphertexts.extend(ciphertext);
+    }
+}
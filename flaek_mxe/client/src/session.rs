@@ -0,0 +1,67 @@
+use num_bigint::BigUint;
+use rand::{rngs::OsRng, RngCore};
+use x25519_dalek::{PublicKey, StaticSecret};
+
+use crate::{cipher::RescueCipher, Output, Result};
+
+/// An x25519 key pair and the cipher it shares with the MXE. Inputs
+/// encrypted here can only be read back by the MXE and by this session.
+pub struct Session {
+    secret: StaticSecret,
+    cipher: RescueCipher,
+}
+
+impl Session {
+    /// A session with a fresh key pair.
+    pub fn new(mxe_public_key: [u8; 32]) -> Self {
+        Self::from_secret(
+            StaticSecret::random_from_rng(OsRng).to_bytes(),
+            mxe_public_key,
+        )
+    }
+
+    /// Restores a session, e.g. to decrypt the result of a job queued by
+    /// another process.
+    pub fn from_secret(secret: [u8; 32], mxe_public_key: [u8; 32]) -> Self {
+        let secret = StaticSecret::from(secret);
+        let shared = secret.diffie_hellman(&PublicKey::from(mxe_public_key));
+        Session {
+            cipher: RescueCipher::new(shared.to_bytes()),
+            secret,
+        }
+    }
+
+    /// Goes out with every queued job as `pub_key`.
+    pub fn public_key(&self) -> [u8; 32] {
+        PublicKey::from(&self.secret).to_bytes()
+    }
+
+    pub fn secret(&self) -> [u8; 32] {
+        self.secret.to_bytes()
+    }
+
+    /// Decrypts the output a callback emitted for this session's key.
+    pub fn decrypt<O: Output>(&self, event: &O::Event) -> Result<O> {
+        O::decrypt(self, event)
+    }
+
+    /// Encrypts `fields` under a fresh nonce, returned as the `u128` the
+    /// queue instructions take.
+    pub(crate) fn encrypt(&self, fields: &[BigUint]) -> (Vec<[u8; 32]>, u128) {
+        let nonce = random_nonce();
+        (
+            self.cipher.encrypt(fields, nonce),
+            u128::from_le_bytes(nonce),
+        )
+    }
+
+    pub(crate) fn decrypt_fields(&self, ciphertexts: &[[u8; 32]], nonce: [u8; 16]) -> Vec<BigUint> {
+        self.cipher.decrypt(ciphertexts, nonce)
+    }
+}
+
+pub(crate) fn random_nonce() -> [u8; 16] {
+    let mut nonce = [0; 16];
+    OsRng.fill_bytes(&mut nonce);
+    nonce
+}
@@ -0,0 +1,410 @@
+//! The use-case circuits, which also encrypt part of their output to a
+//! second party, such as a lender, besides the submitter.
+
+use anchor_lang::solana_program::instruction::Instruction;
+use arcium_anchor::prelude::*;
+
+use crate::{
+    accounts::queue_accounts, instruction, session::random_nonce, Inputs, Output, Plaintext,
+    QueueAccounts, Result, Session,
+};
+
+/// Inputs of `credit_score`. The decision is also encrypted to `lender`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CreditScoreInputs {
+    pub income: u64,
+    pub debt: u64,
+    pub credit_history: u8,
+    pub missed_payments: u8,
+    /// The lender's x25519 public key.
+    pub lender: [u8; 32],
+}
+
+impl Inputs for CreditScoreInputs {
+    const CIRCUIT: &'static str = "credit_score";
+
+    fn instruction(
+        &self,
+        session: &Session,
+        accounts: &QueueAccounts,
+        computation_offset: u64,
+    ) -> Instruction {
+        let mut fields = Vec::new();
+        self.income.encode(&mut fields);
+        self.debt.encode(&mut fields);
+        self.credit_history.encode(&mut fields);
+        self.missed_payments.encode(&mut fields);
+        let (ciphertexts, nonce) = session.encrypt(&fields);
+        instruction(
+            queue_accounts!(CreditScore, accounts),
+            flaek_mxe::instruction::CreditScore {
+                computation_offset,
+                income: ciphertexts[0],
+                debt: ciphertexts[1],
+                credit_history: ciphertexts[2],
+                missed_payments: ciphertexts[3],
+                lender_pub_key: self.lender,
+                lender_nonce: u128::from_le_bytes(random_nonce()),
+                pub_key: session.public_key(),
+                nonce,
+            },
+        )
+    }
+}
+
+/// Decrypted output of `credit_score`, for the applicant.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CreditScoreResult {
+    pub score: u16,
+    pub approved: u8,
+}
+
+impl Output for CreditScoreResult {
+    type Event = flaek_mxe::CreditScoreEvent;
+
+    fn decrypt(session: &Session, event: &flaek_mxe::CreditScoreEvent) -> Result<Self> {
+        let mut fields = session
+            .decrypt_fields(&[event.score, event.approved], event.nonce)
+            .into_iter();
+        Ok(CreditScoreResult {
+            score: u16::decode(&mut fields)?,
+            approved: u8::decode(&mut fields)?,
+        })
+    }
+}
+
+/// Decrypted output of `credit_score`, for the lender's session.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LenderDecision {
+    pub approved: u8,
+}
+
+impl Output for LenderDecision {
+    type Event = flaek_mxe::CreditScoreEvent;
+
+    fn decrypt(session: &Session, event: &flaek_mxe::CreditScoreEvent) -> Result<Self> {
+        let mut fields = session
+            .decrypt_fields(&[event.lender_approved], event.lender_nonce)
+            .into_iter();
+        Ok(LenderDecision {
+            approved: u8::decode(&mut fields)?,
+        })
+    }
+}
+
+/// Inputs of `health_risk`. The category is also encrypted to `insurer`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct HealthRiskInputs {
+    pub age: u8,
+    pub bmi: u8,
+    pub smoker: u8,
+    pub exercise_hours: u8,
+    pub family_history: u8,
+    /// The insurer's x25519 public key.
+    pub insurer: [u8; 32],
+}
+
+impl Inputs for HealthRiskInputs {
+    const CIRCUIT: &'static str = "health_risk";
+
+    fn instruction(
+        &self,
+        session: &Session,
+        accounts: &QueueAccounts,
+        computation_offset: u64,
+    ) -> Instruction {
+        let mut fields = Vec::new();
+        self.age.encode(&mut fields);
+        self.bmi.encode(&mut fields);
+        self.smoker.encode(&mut fields);
+        self.exercise_hours.encode(&mut fields);
+        self.family_history.encode(&mut fields);
+        let (ciphertexts, nonce) = session.encrypt(&fields);
+        instruction(
+            queue_accounts!(HealthRisk, accounts),
+            flaek_mxe::instruction::HealthRisk {
+                computation_offset,
+                age: ciphertexts[0],
+                bmi: ciphertexts[1],
+                smoker: ciphertexts[2],
+                exercise_hours: ciphertexts[3],
+                family_history: ciphertexts[4],
+                insurer_pub_key: self.insurer,
+                insurer_nonce: u128::from_le_bytes(random_nonce()),
+                pub_key: session.public_key(),
+                nonce,
+            },
+        )
+    }
+}
+
+/// Decrypted output of `health_risk`, for the patient.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct HealthRiskResult {
+    pub risk_score: u8,
+    pub risk_category: u8,
+}
+
+impl Output for HealthRiskResult {
+    type Event = flaek_mxe::HealthRiskEvent;
+
+    fn decrypt(session: &Session, event: &flaek_mxe::HealthRiskEvent) -> Result<Self> {
+        let mut fields = session
+            .decrypt_fields(&[event.risk_score, event.risk_category], event.nonce)
+            .into_iter();
+        Ok(HealthRiskResult {
+            risk_score: u8::decode(&mut fields)?,
+            risk_category: u8::decode(&mut fields)?,
+        })
+    }
+}
+
+/// Decrypted output of `health_risk`, for the insurer's session.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct InsurerCategory {
+    pub risk_category: u8,
+}
+
+impl Output for InsurerCategory {
+    type Event = flaek_mxe::HealthRiskEvent;
+
+    fn decrypt(session: &Session, event: &flaek_mxe::HealthRiskEvent) -> Result<Self> {
+        let mut fields = session
+            .decrypt_fields(&[event.insurer_risk_category], event.insurer_nonce)
+            .into_iter();
+        Ok(InsurerCategory {
+            risk_category: u8::decode(&mut fields)?,
+        })
+    }
+}
+
+/// Inputs of `vote_tally`: up to ten ballots, of which the first `count`
+/// are tallied. The count is also encrypted to `tallier` and revealed in
+/// `VoteTallyEvent::revealed_result`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct VoteTallyInputs {
+    pub votes: [u8; 10],
+    pub count: u8,
+    /// The tallier's x25519 public key.
+    pub tallier: [u8; 32],
+}
+
+impl Inputs for VoteTallyInputs {
+    const CIRCUIT: &'static str = "vote_tally";
+
+    fn instruction(
+        &self,
+        session: &Session,
+        accounts: &QueueAccounts,
+        computation_offset: u64,
+    ) -> Instruction {
+        let mut fields = Vec::new();
+        self.votes.encode(&mut fields);
+        self.count.encode(&mut fields);
+        let (ciphertexts, nonce) = session.encrypt(&fields);
+        let mut ciphertexts = ciphertexts.into_iter();
+        instruction(
+            queue_accounts!(VoteTally, accounts),
+            flaek_mxe::instruction::VoteTally {
+                computation_offset,
+                votes: <[u8; 10]>::pack(&mut ciphertexts),
+                count: u8::pack(&mut ciphertexts),
+                tallier_pub_key: self.tallier,
+                tallier_nonce: u128::from_le_bytes(random_nonce()),
+                pub_key: session.public_key(),
+                nonce,
+            },
+        )
+    }
+}
+
+/// Decrypted output of `vote_tally`, for the submitter.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct VoteTallyResult {
+    pub result: u8,
+}
+
+impl Output for VoteTallyResult {
+    type Event = flaek_mxe::VoteTallyEvent;
+
+    fn decrypt(session: &Session, event: &flaek_mxe::VoteTallyEvent) -> Result<Self> {
+        let mut fields = session
+            .decrypt_fields(&[event.result], event.nonce)
+            .into_iter();
+        Ok(VoteTallyResult {
+            result: u8::decode(&mut fields)?,
+        })
+    }
+}
+
+/// Decrypted output of `vote_tally`, for the tallier's session.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TallierCount {
+    pub count: u8,
+}
+
+impl Output for TallierCount {
+    type Event = flaek_mxe::VoteTallyEvent;
+
+    fn decrypt(session: &Session, event: &flaek_mxe::VoteTallyEvent) -> Result<Self> {
+        let mut fields = session
+            .decrypt_fields(&[event.tallier_result], event.tallier_nonce)
+            .into_iter();
+        Ok(TallierCount {
+            count: u8::decode(&mut fields)?,
+        })
+    }
+}
+
+/// Inputs of `meets_threshold`. The verdict is also encrypted to
+/// `verifier`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MeetsThresholdInputs {
+    pub value: u64,
+    pub threshold: u64,
+    /// The verifier's x25519 public key.
+    pub verifier: [u8; 32],
+}
+
+impl Inputs for MeetsThresholdInputs {
+    const CIRCUIT: &'static str = "meets_threshold";
+
+    fn instruction(
+        &self,
+        session: &Session,
+        accounts: &QueueAccounts,
+        computation_offset: u64,
+    ) -> Instruction {
+        let mut fields = Vec::new();
+        self.value.encode(&mut fields);
+        self.threshold.encode(&mut fields);
+        let (ciphertexts, nonce) = session.encrypt(&fields);
+        instruction(
+            queue_accounts!(MeetsThreshold, accounts),
+            flaek_mxe::instruction::MeetsThreshold {
+                computation_offset,
+                value: ciphertexts[0],
+                threshold: ciphertexts[1],
+                verifier_pub_key: self.verifier,
+                verifier_nonce: u128::from_le_bytes(random_nonce()),
+                pub_key: session.public_key(),
+                nonce,
+            },
+        )
+    }
+}
+
+/// Decrypted output of `meets_threshold`, for the submitter.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MeetsThresholdResult {
+    pub result: u8,
+}
+
+impl Output for MeetsThresholdResult {
+    type Event = flaek_mxe::MeetsThresholdEvent;
+
+    fn decrypt(session: &Session, event: &flaek_mxe::MeetsThresholdEvent) -> Result<Self> {
+        let mut fields = session
+            .decrypt_fields(&[event.result], event.nonce)
+            .into_iter();
+        Ok(MeetsThresholdResult {
+            result: u8::decode(&mut fields)?,
+        })
+    }
+}
+
+/// Decrypted output of `meets_threshold`, for the verifier's session.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct VerifierVerdict {
+    pub result: u8,
+}
+
+impl Output for VerifierVerdict {
+    type Event = flaek_mxe::MeetsThresholdEvent;
+
+    fn decrypt(session: &Session, event: &flaek_mxe::MeetsThresholdEvent) -> Result<Self> {
+        let mut fields = session
+            .decrypt_fields(&[event.verifier_result], event.verifier_nonce)
+            .into_iter();
+        Ok(VerifierVerdict {
+            result: u8::decode(&mut fields)?,
+        })
+    }
+}
+
+/// Inputs of `weighted_average`. The average is also encrypted to
+/// `recipient`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct WeightedAverageInputs {
+    pub values: [u64; 5],
+    pub weights: [u8; 5],
+    /// The recipient's x25519 public key.
+    pub recipient: [u8; 32],
+}
+
+impl Inputs for WeightedAverageInputs {
+    const CIRCUIT: &'static str = "weighted_average";
+
+    fn instruction(
+        &self,
+        session: &Session,
+        accounts: &QueueAccounts,
+        computation_offset: u64,
+    ) -> Instruction {
+        let mut fields = Vec::new();
+        self.values.encode(&mut fields);
+        self.weights.encode(&mut fields);
+        let (ciphertexts, nonce) = session.encrypt(&fields);
+        let mut ciphertexts = ciphertexts.into_iter();
+        instruction(
+            queue_accounts!(WeightedAverage, accounts),
+            flaek_mxe::instruction::WeightedAverage {
+                computation_offset,
+                values: <[u64; 5]>::pack(&mut ciphertexts),
+                weights: <[u8; 5]>::pack(&mut ciphertexts),
+                recipient_pub_key: self.recipient,
+                recipient_nonce: u128::from_le_bytes(random_nonce()),
+                pub_key: session.public_key(),
+                nonce,
+            },
+        )
+    }
+}
+
+/// Decrypted output of `weighted_average`, for the submitter.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct WeightedAverageResult {
+    pub result: u64,
+}
+
+impl Output for WeightedAverageResult {
+    type Event = flaek_mxe::WeightedAverageEvent;
+
+    fn decrypt(session: &Session, event: &flaek_mxe::WeightedAverageEvent) -> Result<Self> {
+        let mut fields = session
+            .decrypt_fields(&[event.result], event.nonce)
+            .into_iter();
+        Ok(WeightedAverageResult {
+            result: u64::decode(&mut fields)?,
+        })
+    }
+}
+
+/// Decrypted output of `weighted_average`, for the recipient's session.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RecipientAverage {
+    pub result: u64,
+}
+
+impl Output for RecipientAverage {
+    type Event = flaek_mxe::WeightedAverageEvent;
+
+    fn decrypt(session: &Session, event: &flaek_mxe::WeightedAverageEvent) -> Result<Self> {
+        let mut fields = session
+            .decrypt_fields(&[event.recipient_result], event.recipient_nonce)
+            .into_iter();
+        Ok(RecipientAverage {
+            result: u64::decode(&mut fields)?,
+        })
+    }
+}
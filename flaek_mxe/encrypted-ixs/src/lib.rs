@@ -1,5 +1,9 @@
 use arcis_imports::*;
 
+// Plaintext versions of every circuit, for property tests and simulators.
+#[cfg(any(test, feature = "reference"))]
+pub mod reference;
+
 #[encrypted]
 mod math {
     use arcis_imports::*;
@@ -103,6 +107,104 @@ mod math {
         };
         input_ctxt.owner.from_arcis(result)
     }
+
+    // Checked variants: overflow is 1 when the true result does not fit in a
+    // u64, in which case result saturates at u64::MAX.
+    pub struct CheckedOutput {
+        pub result: u64,
+        pub overflow: u8,
+    }
+
+    #[instruction]
+    pub fn checked_add(input_ctxt: Enc<Shared, AddInputs>) -> Enc<Shared, CheckedOutput> {
+        let input = input_ctxt.to_arcis();
+        let wide = input.a as u128 + input.b as u128;
+        let overflow = wide > 18446744073709551615;
+        let result = CheckedOutput {
+            result: if overflow { 18446744073709551615 } else { wide as u64 },
+            overflow: if overflow { 1u8 } else { 0u8 },
+        };
+        input_ctxt.owner.from_arcis(result)
+    }
+
+    #[instruction]
+    pub fn checked_multiply(input_ctxt: Enc<Shared, MultiplyInputs>) -> Enc<Shared, CheckedOutput> {
+        let input = input_ctxt.to_arcis();
+        let wide = input.a as u128 * input.b as u128;
+        let overflow = wide > 18446744073709551615;
+        let result = CheckedOutput {
+            result: if overflow { 18446744073709551615 } else { wide as u64 },
+            overflow: if overflow { 1u8 } else { 0u8 },
+        };
+        input_ctxt.owner.from_arcis(result)
+    }
+
+    // Unlike power, the exponent is not capped: any exponent past 63 with a
+    // base above 1 is reported as overflow.
+    #[instruction]
+    pub fn checked_power(input_ctxt: Enc<Shared, PowerInputs>) -> Enc<Shared, CheckedOutput> {
+        let input = input_ctxt.to_arcis();
+        let mut acc: u64 = 1;
+        let mut overflow = input.exponent > 63 && input.base > 1;
+        for i in 0..63 {
+            if (i as u8) < input.exponent {
+                let wide = acc as u128 * input.base as u128;
+                if wide > 18446744073709551615 {
+                    overflow = true;
+                }
+                acc = if overflow { 18446744073709551615 } else { wide as u64 };
+            }
+        }
+        let result = CheckedOutput {
+            result: if overflow { 18446744073709551615 } else { acc },
+            overflow: if overflow { 1u8 } else { 0u8 },
+        };
+        input_ctxt.owner.from_arcis(result)
+    }
+
+    // Signed variants: results may go negative instead of clamping at 0.
+    pub struct SignedInputs {
+        pub a: i64,
+        pub b: i64,
+    }
+
+    #[instruction]
+    pub fn add_i64(input_ctxt: Enc<Shared, SignedInputs>) -> Enc<Shared, i64> {
+        let input = input_ctxt.to_arcis();
+        let result = input.a + input.b;
+        input_ctxt.owner.from_arcis(result)
+    }
+
+    #[instruction]
+    pub fn subtract_i64(input_ctxt: Enc<Shared, SignedInputs>) -> Enc<Shared, i64> {
+        let input = input_ctxt.to_arcis();
+        let result = input.a - input.b;
+        input_ctxt.owner.from_arcis(result)
+    }
+
+    #[instruction]
+    pub fn multiply_i64(input_ctxt: Enc<Shared, SignedInputs>) -> Enc<Shared, i64> {
+        let input = input_ctxt.to_arcis();
+        let result = input.a * input.b;
+        input_ctxt.owner.from_arcis(result)
+    }
+
+    // Truncates toward zero, like Rust's `/`.
+    #[instruction]
+    pub fn divide_i64(input_ctxt: Enc<Shared, SignedInputs>) -> Enc<Shared, i64> {
+        let input = input_ctxt.to_arcis();
+        let result = if input.b != 0 { input.a / input.b } else { 0 };
+        input_ctxt.owner.from_arcis(result)
+    }
+
+    // Unsigned so the distance between i64::MIN and i64::MAX still fits.
+    #[instruction]
+    pub fn abs_diff_i64(input_ctxt: Enc<Shared, SignedInputs>) -> Enc<Shared, u64> {
+        let input = input_ctxt.to_arcis();
+        let diff = input.a as i128 - input.b as i128;
+        let result = if diff < 0 { (-diff) as u64 } else { diff as u64 };
+        input_ctxt.owner.from_arcis(result)
+    }
 }
 
 #[encrypted]
@@ -149,6 +251,25 @@ mod comparison {
         input_ctxt.owner.from_arcis(result)
     }
 
+    pub struct SignedCompareInputs {
+        pub a: i64,
+        pub b: i64,
+    }
+
+    // -1 when a < b, 0 when equal, 1 when a > b.
+    #[instruction]
+    pub fn compare_i64(input_ctxt: Enc<Shared, SignedCompareInputs>) -> Enc<Shared, i8> {
+        let input = input_ctxt.to_arcis();
+        let result = if input.a < input.b {
+            -1i8
+        } else if input.a > input.b {
+            1i8
+        } else {
+            0i8
+        };
+        input_ctxt.owner.from_arcis(result)
+    }
+
     // Range check (a <= value <= b)
     pub struct RangeCheckInputs {
         pub value: u64,
@@ -322,21 +443,41 @@ mod statistics {
     pub fn median(input_ctxt: Enc<Shared, AverageInputs>) -> Enc<Shared, u64> {
         let input = input_ctxt.to_arcis();
         let cnt = if input.count > 10 { 10 } else { input.count };
-        
-        // Simplified: return middle value or average (proper sorting requires loops)
-        let mut sum: u64 = 0;
-        if cnt >= 1 { sum = sum + input.values[0]; }
-        if cnt >= 2 { sum = sum + input.values[1]; }
-        if cnt >= 3 { sum = sum + input.values[2]; }
-        if cnt >= 4 { sum = sum + input.values[3]; }
-        if cnt >= 5 { sum = sum + input.values[4]; }
-        if cnt >= 6 { sum = sum + input.values[5]; }
-        if cnt >= 7 { sum = sum + input.values[6]; }
-        if cnt >= 8 { sum = sum + input.values[7]; }
-        if cnt >= 9 { sum = sum + input.values[8]; }
-        if cnt >= 10 { sum = sum + input.values[9]; }
-        
-        let result = if cnt > 0 { sum / (cnt as u64) } else { 0 };
+
+        // Slots past `count` are padded with u64::MAX so they sort to the end
+        // and the first `cnt` sorted slots hold exactly the real values.
+        let mut sorted = [0u64; 10];
+        for i in 0..10 {
+            sorted[i] = if (i as u8) < cnt { input.values[i] } else { u64::MAX };
+        }
+
+        // Odd-even transposition sort: 10 rounds of fixed compare-exchanges,
+        // so the comparator layout never depends on the encrypted values.
+        for round in 0..10 {
+            for j in 0..5 {
+                let lo = 2 * j + (round % 2);
+                if lo + 1 < 10 {
+                    let a = sorted[lo];
+                    let b = sorted[lo + 1];
+                    let swap = a > b;
+                    sorted[lo] = if swap { b } else { a };
+                    sorted[lo + 1] = if swap { a } else { b };
+                }
+            }
+        }
+
+        // 1-based positions of the middle pair; they coincide when cnt is odd.
+        let lower_pos = (cnt + 1) / 2;
+        let upper_pos = cnt / 2 + 1;
+        let mut lower: u64 = 0;
+        let mut upper: u64 = 0;
+        for i in 0..10 {
+            let pos = (i + 1) as u8;
+            if pos == lower_pos { lower = sorted[i]; }
+            if pos == upper_pos { upper = sorted[i]; }
+        }
+
+        let result = if cnt > 0 { lower + (upper - lower) / 2 } else { 0 };
         input_ctxt.owner.from_arcis(result)
     }
 }
@@ -357,8 +498,13 @@ mod use_cases {
         pub approved: u8,
     }
 
+    /// The applicant gets the score and decision; `lender` only the decision.
+    /// The decision is also revealed for on-chain consumers.
     #[instruction]
-    pub fn credit_score(input_ctxt: Enc<Shared, CreditScoreInputs>) -> Enc<Shared, CreditScoreOutput> {
+    pub fn credit_score(
+        input_ctxt: Enc<Shared, CreditScoreInputs>,
+        lender: Shared,
+    ) -> (Enc<Shared, CreditScoreOutput>, Enc<Shared, u8>, u8) {
         let input = input_ctxt.to_arcis();
         let dti_ratio = if input.income > 0 {
             ((input.debt * 100) / input.income) as u16
@@ -383,7 +529,11 @@ mod use_cases {
         let approved = if score >= 700 && dti_ratio < 43 { 1u8 } else { 0u8 };
         
         let output = CreditScoreOutput { score, approved };
-        input_ctxt.owner.from_arcis(output)
+        (
+            input_ctxt.owner.from_arcis(output),
+            lender.from_arcis(approved),
+            approved.reveal(),
+        )
     }
 
     pub struct HealthRiskInputs {
@@ -399,8 +549,12 @@ mod use_cases {
         pub risk_category: u8,
     }
 
+    /// The patient gets the score and category; `insurer` only the category.
     #[instruction]
-    pub fn health_risk(input_ctxt: Enc<Shared, HealthRiskInputs>) -> Enc<Shared, HealthRiskOutput> {
+    pub fn health_risk(
+        input_ctxt: Enc<Shared, HealthRiskInputs>,
+        insurer: Shared,
+    ) -> (Enc<Shared, HealthRiskOutput>, Enc<Shared, u8>) {
         let input = input_ctxt.to_arcis();
         let mut risk: u8 = 0;
         if input.age > 65 {
@@ -442,17 +596,37 @@ mod use_cases {
             risk_score: risk,
             risk_category: category,
         };
-        input_ctxt.owner.from_arcis(output)
+        (input_ctxt.owner.from_arcis(output), insurer.from_arcis(category))
     }
 
     pub struct VoteInputs {
-        pub vote: u8,
+        pub votes: [u8; 10],
+        pub count: u8,
     }
 
+    /// Counts the non-zero votes among the first `count` ballots. The count
+    /// goes to the submitter and `tallier` and is revealed; the ballots are
+    /// not.
     #[instruction]
-    pub fn vote_tally(input_ctxt: Enc<Shared, VoteInputs>) -> Enc<Shared, u8> {
+    pub fn vote_tally(
+        input_ctxt: Enc<Shared, VoteInputs>,
+        tallier: Shared,
+    ) -> (Enc<Shared, u8>, Enc<Shared, u8>, u8) {
         let input = input_ctxt.to_arcis();
-        input_ctxt.owner.from_arcis(input.vote)
+        let cnt = if input.count > 10 { 10 } else { input.count };
+
+        let mut yes: u8 = 0;
+        for i in 0..10 {
+            if (i as u8) < cnt && input.votes[i] != 0 {
+                yes += 1;
+            }
+        }
+
+        (
+            input_ctxt.owner.from_arcis(yes),
+            tallier.from_arcis(yes),
+            yes.reveal(),
+        )
     }
 
     pub struct ThresholdInputs {
@@ -460,11 +634,16 @@ mod use_cases {
         pub threshold: u64,
     }
 
+    /// Both the submitter and `verifier` learn whether the value clears the
+    /// threshold, and nothing else.
     #[instruction]
-    pub fn meets_threshold(input_ctxt: Enc<Shared, ThresholdInputs>) -> Enc<Shared, u8> {
+    pub fn meets_threshold(
+        input_ctxt: Enc<Shared, ThresholdInputs>,
+        verifier: Shared,
+    ) -> (Enc<Shared, u8>, Enc<Shared, u8>) {
         let input = input_ctxt.to_arcis();
         let result = if input.value >= input.threshold { 1u8 } else { 0u8 };
-        input_ctxt.owner.from_arcis(result)
+        (input_ctxt.owner.from_arcis(result), verifier.from_arcis(result))
     }
 
     pub struct WeightedAverageInputs {
@@ -472,8 +651,13 @@ mod use_cases {
         pub weights: [u8; 5],
     }
 
+    /// Both the submitter and `recipient` get the average, but not the
+    /// values or weights behind it.
     #[instruction]
-    pub fn weighted_average(input_ctxt: Enc<Shared, WeightedAverageInputs>) -> Enc<Shared, u64> {
+    pub fn weighted_average(
+        input_ctxt: Enc<Shared, WeightedAverageInputs>,
+        recipient: Shared,
+    ) -> (Enc<Shared, u64>, Enc<Shared, u64>) {
         let input = input_ctxt.to_arcis();
         
         let mut weighted_sum: u64 = 0;
@@ -490,6 +674,216 @@ mod use_cases {
             0
         };
         
+        (input_ctxt.owner.from_arcis(result), recipient.from_arcis(result))
+    }
+}
+
+// Q32.32 fixed point: a value x travels as the i64 round(x * 2^32), so money
+// and rates keep 32 fractional bits instead of truncating to whole units.
+// Products and quotients truncate toward zero; fixed_round rounds half away
+// from zero.
+#[encrypted]
+mod fixed_point {
+    use arcis_imports::*;
+
+    pub struct FixedInputs {
+        pub a: i64,
+        pub b: i64,
+    }
+
+    #[instruction]
+    pub fn fixed_add(input_ctxt: Enc<Shared, FixedInputs>) -> Enc<Shared, i64> {
+        let input = input_ctxt.to_arcis();
+        let result = input.a + input.b;
+        input_ctxt.owner.from_arcis(result)
+    }
+
+    #[instruction]
+    pub fn fixed_sub(input_ctxt: Enc<Shared, FixedInputs>) -> Enc<Shared, i64> {
+        let input = input_ctxt.to_arcis();
+        let result = input.a - input.b;
+        input_ctxt.owner.from_arcis(result)
+    }
+
+    #[instruction]
+    pub fn fixed_mul(input_ctxt: Enc<Shared, FixedInputs>) -> Enc<Shared, i64> {
+        let input = input_ctxt.to_arcis();
+        let result = ((input.a as i128 * input.b as i128) / 4294967296) as i64;
+        input_ctxt.owner.from_arcis(result)
+    }
+
+    #[instruction]
+    pub fn fixed_div(input_ctxt: Enc<Shared, FixedInputs>) -> Enc<Shared, i64> {
+        let input = input_ctxt.to_arcis();
+        let result = if input.b != 0 {
+            ((input.a as i128 * 4294967296) / input.b as i128) as i64
+        } else {
+            0
+        };
         input_ctxt.owner.from_arcis(result)
     }
+
+    pub struct RoundInputs {
+        pub value: i64,
+    }
+
+    // The nearest whole number, still as Q32.32; rounding up to 2^31 wraps.
+    #[instruction]
+    pub fn fixed_round(input_ctxt: Enc<Shared, RoundInputs>) -> Enc<Shared, i64> {
+        let input = input_ctxt.to_arcis();
+        let half: i128 = if input.value < 0 { -2147483648 } else { 2147483648 };
+        let result = ((input.value as i128 + half) / 4294967296 * 4294967296) as i64;
+        input_ctxt.owner.from_arcis(result)
+    }
+
+    pub struct PercentInputs {
+        pub value: i64,
+        pub percent: i64,
+    }
+
+    // value * percent / 100, both Q32.32; e.g. interest on a balance.
+    #[instruction]
+    pub fn fixed_percent(input_ctxt: Enc<Shared, PercentInputs>) -> Enc<Shared, i64> {
+        let input = input_ctxt.to_arcis();
+        let result = ((input.value as i128 * input.percent as i128) / 429496729600) as i64;
+        input_ctxt.owner.from_arcis(result)
+    }
+}
+
+// Pipeline blocks chained without a round trip to the client: `seal` moves a
+// client value under the MXE key, `pipeline_step` combines two MXE-owned
+// intermediates into a third, and `unseal` hands the final value to a
+// recipient. Intermediates never leave the cluster in a form anyone can read.
+#[encrypted]
+mod pipeline {
+    use arcis_imports::*;
+
+    pub struct SealInputs {
+        pub value: u64,
+    }
+
+    #[instruction]
+    pub fn seal(input_ctxt: Enc<Shared, SealInputs>) -> Enc<Mxe, u64> {
+        let input = input_ctxt.to_arcis();
+        Mxe::get().from_arcis(input.value)
+    }
+
+    // op: 0 add, 1 subtract (floored at 0), 2 multiply, 3 divide (0 when
+    // dividing by 0), 4 min, 5 max, 6 greater_than, 7 less_than, 8 equal.
+    // Comparisons yield 1 or 0; unknown ops yield 0.
+    #[instruction]
+    pub fn pipeline_step(op: u8, lhs_ctxt: Enc<Mxe, u64>, rhs_ctxt: Enc<Mxe, u64>) -> Enc<Mxe, u64> {
+        let a = lhs_ctxt.to_arcis();
+        let b = rhs_ctxt.to_arcis();
+
+        let result = if op == 0 {
+            a + b
+        } else if op == 1 {
+            if a > b { a - b } else { 0 }
+        } else if op == 2 {
+            a * b
+        } else if op == 3 {
+            if b != 0 { a / b } else { 0 }
+        } else if op == 4 {
+            if a < b { a } else { b }
+        } else if op == 5 {
+            if a > b { a } else { b }
+        } else if op == 6 {
+            if a > b { 1 } else { 0 }
+        } else if op == 7 {
+            if a < b { 1 } else { 0 }
+        } else if op == 8 {
+            if a == b { 1 } else { 0 }
+        } else {
+            0
+        };
+
+        lhs_ctxt.owner.from_arcis(result)
+    }
+
+    pub struct Registers {
+        pub r: [u64; 8],
+    }
+
+    // Runs a straight-line program of 16 four-byte instructions
+    // [op, dst, a, b] over eight encrypted registers in one computation and
+    // returns the final register file. Register indices past 7 read as 0 and
+    // are never written.
+    // op: 0 nop, 1 add, 2 subtract (floored at 0), 3 multiply, 4 divide (0
+    // when dividing by 0), 5 min, 6 max, 7 greater_than, 8 less_than,
+    // 9 equal, 10 and, 11 or, 12 not (of a), 13 select (dst = a != 0 ? b :
+    // dst), 14 copy (dst = a). Comparisons and logic yield 1 or 0; unknown
+    // ops behave like nop.
+    #[instruction]
+    pub fn pipeline_eval(program: [u8; 64], input_ctxt: Enc<Shared, Registers>) -> Enc<Shared, Registers> {
+        let mut regs = input_ctxt.to_arcis().r;
+
+        for pc in 0..16 {
+            let op = program[4 * pc];
+            let dst = program[4 * pc + 1];
+            let a_idx = program[4 * pc + 2];
+            let b_idx = program[4 * pc + 3];
+
+            let mut a = 0u64;
+            let mut b = 0u64;
+            let mut d = 0u64;
+            for r in 0..8 {
+                if a_idx == r as u8 {
+                    a = regs[r];
+                }
+                if b_idx == r as u8 {
+                    b = regs[r];
+                }
+                if dst == r as u8 {
+                    d = regs[r];
+                }
+            }
+
+            let value = if op == 1 {
+                a + b
+            } else if op == 2 {
+                if a > b { a - b } else { 0 }
+            } else if op == 3 {
+                a * b
+            } else if op == 4 {
+                if b != 0 { a / b } else { 0 }
+            } else if op == 5 {
+                if a < b { a } else { b }
+            } else if op == 6 {
+                if a > b { a } else { b }
+            } else if op == 7 {
+                if a > b { 1 } else { 0 }
+            } else if op == 8 {
+                if a < b { 1 } else { 0 }
+            } else if op == 9 {
+                if a == b { 1 } else { 0 }
+            } else if op == 10 {
+                if a != 0 && b != 0 { 1 } else { 0 }
+            } else if op == 11 {
+                if a != 0 || b != 0 { 1 } else { 0 }
+            } else if op == 12 {
+                if a == 0 { 1 } else { 0 }
+            } else if op == 13 {
+                if a != 0 { b } else { d }
+            } else if op == 14 {
+                a
+            } else {
+                d
+            };
+
+            for r in 0..8 {
+                if dst == r as u8 {
+                    regs[r] = value;
+                }
+            }
+        }
+
+        input_ctxt.owner.from_arcis(Registers { r: regs })
+    }
+
+    #[instruction]
+    pub fn unseal(recipient: Shared, value_ctxt: Enc<Mxe, u64>) -> Enc<Shared, u64> {
+        let value = value_ctxt.to_arcis();
+        recipient.from_arcis(value)
+    }
 }
@@ -0,0 +1,556 @@
+//! Plain-Rust twins of the circuits in this crate, one function per
+//! `#[instruction]` with the same name and module. They take the decrypted
+//! inputs in `circuit!` argument order and return the decrypted outputs in
+//! event field order, so local MPC runs and simulators can be checked
+//! against them.
+//!
+//! Arcis integers wrap on overflow, so the references use wrapping
+//! arithmetic wherever a circuit does not check for it.
+
+pub mod math {
+    pub fn add(a: u64, b: u64) -> u64 {
+        a.wrapping_add(b)
+    }
+
+    /// Floored at 0 instead of going negative.
+    pub fn subtract(a: u64, b: u64) -> u64 {
+        a.saturating_sub(b)
+    }
+
+    pub fn multiply(a: u64, b: u64) -> u64 {
+        a.wrapping_mul(b)
+    }
+
+    /// 0 when `b` is 0.
+    pub fn divide(a: u64, b: u64) -> u64 {
+        a.checked_div(b).unwrap_or(0)
+    }
+
+    /// 0 when `b` is 0.
+    pub fn modulo(a: u64, b: u64) -> u64 {
+        a.checked_rem(b).unwrap_or(0)
+    }
+
+    /// The exponent is capped at 10.
+    pub fn power(base: u64, exponent: u8) -> u64 {
+        (0..exponent.min(10)).fold(1u64, |acc, _| acc.wrapping_mul(base))
+    }
+
+    pub fn abs_diff(a: u64, b: u64) -> u64 {
+        a.abs_diff(b)
+    }
+
+    /// `(result, overflow)`; result saturates at `u64::MAX` on overflow.
+    pub fn checked_add(a: u64, b: u64) -> (u64, u8) {
+        checked(a.checked_add(b))
+    }
+
+    /// `(result, overflow)`; result saturates at `u64::MAX` on overflow.
+    pub fn checked_multiply(a: u64, b: u64) -> (u64, u8) {
+        checked(a.checked_mul(b))
+    }
+
+    /// `(result, overflow)`; result saturates at `u64::MAX` on overflow.
+    pub fn checked_power(base: u64, exponent: u8) -> (u64, u8) {
+        checked(base.checked_pow(exponent as u32))
+    }
+
+    fn checked(result: Option<u64>) -> (u64, u8) {
+        match result {
+            Some(result) => (result, 0),
+            None => (u64::MAX, 1),
+        }
+    }
+
+    pub fn add_i64(a: i64, b: i64) -> i64 {
+        a.wrapping_add(b)
+    }
+
+    pub fn subtract_i64(a: i64, b: i64) -> i64 {
+        a.wrapping_sub(b)
+    }
+
+    pub fn multiply_i64(a: i64, b: i64) -> i64 {
+        a.wrapping_mul(b)
+    }
+
+    /// Truncates toward zero; 0 when `b` is 0.
+    pub fn divide_i64(a: i64, b: i64) -> i64 {
+        if b == 0 {
+            0
+        } else {
+            a.wrapping_div(b)
+        }
+    }
+
+    pub fn abs_diff_i64(a: i64, b: i64) -> u64 {
+        a.abs_diff(b)
+    }
+}
+
+pub mod comparison {
+    pub fn greater_than(a: u64, b: u64) -> u8 {
+        (a > b) as u8
+    }
+
+    pub fn less_than(a: u64, b: u64) -> u8 {
+        (a < b) as u8
+    }
+
+    pub fn equal(a: u64, b: u64) -> u8 {
+        (a == b) as u8
+    }
+
+    pub fn greater_equal(a: u64, b: u64) -> u8 {
+        (a >= b) as u8
+    }
+
+    pub fn less_equal(a: u64, b: u64) -> u8 {
+        (a <= b) as u8
+    }
+
+    /// Inclusive on both ends; 0 whenever `min > max`.
+    pub fn in_range(value: u64, min: u64, max: u64) -> u8 {
+        (value >= min && value <= max) as u8
+    }
+
+    /// -1, 0 or 1 as `a` is less than, equal to or greater than `b`.
+    pub fn compare_i64(a: i64, b: i64) -> i8 {
+        a.cmp(&b) as i8
+    }
+}
+
+pub mod logical {
+    pub fn and(a: u8, b: u8) -> u8 {
+        (a != 0 && b != 0) as u8
+    }
+
+    pub fn or(a: u8, b: u8) -> u8 {
+        (a != 0 || b != 0) as u8
+    }
+
+    pub fn xor(a: u8, b: u8) -> u8 {
+        ((a != 0) != (b != 0)) as u8
+    }
+
+    pub fn not(a: u8) -> u8 {
+        (a == 0) as u8
+    }
+
+    pub fn if_else(condition: u8, true_value: u64, false_value: u64) -> u64 {
+        if condition != 0 {
+            true_value
+        } else {
+            false_value
+        }
+    }
+}
+
+/// `count` above 10 is treated as 10, and every function returns 0 when it
+/// is 0.
+pub mod statistics {
+    fn counted(values: &[u64; 10], count: u8) -> &[u64] {
+        &values[..count.min(10) as usize]
+    }
+
+    pub fn average(values: [u64; 10], count: u8) -> u64 {
+        let values = counted(&values, count);
+        if values.is_empty() {
+            return 0;
+        }
+        sum_of(values) / values.len() as u64
+    }
+
+    pub fn sum(values: [u64; 10], count: u8) -> u64 {
+        sum_of(counted(&values, count))
+    }
+
+    fn sum_of(values: &[u64]) -> u64 {
+        values.iter().fold(0u64, |acc, v| acc.wrapping_add(*v))
+    }
+
+    pub fn min(values: [u64; 10], count: u8) -> u64 {
+        counted(&values, count).iter().copied().min().unwrap_or(0)
+    }
+
+    pub fn max(values: [u64; 10], count: u8) -> u64 {
+        counted(&values, count).iter().copied().max().unwrap_or(0)
+    }
+
+    /// The middle value, or the floored mean of the middle pair when the
+    /// count is even.
+    pub fn median(values: [u64; 10], count: u8) -> u64 {
+        let mut values = counted(&values, count).to_vec();
+        if values.is_empty() {
+            return 0;
+        }
+        values.sort_unstable();
+        let lower = values[(values.len() - 1) / 2];
+        let upper = values[values.len() / 2];
+        lower + (upper - lower) / 2
+    }
+}
+
+pub mod use_cases {
+    /// `(score, approved)`. The score is clamped to 300..=850 and approval
+    /// needs a score of at least 700 with a debt-to-income ratio under 43%.
+    /// Zero income counts as a 100% ratio. The lender receives `approved`
+    /// alone.
+    pub fn credit_score(
+        income: u64,
+        debt: u64,
+        credit_history: u8,
+        missed_payments: u8,
+    ) -> (u16, u8) {
+        let dti_ratio = debt
+            .wrapping_mul(100)
+            .checked_div(income)
+            .map_or(100, |ratio| ratio as u16);
+
+        let mut score: u16 = 650;
+        if dti_ratio < 20 {
+            score += 100;
+        } else if dti_ratio < 35 {
+            score += 50;
+        } else if dti_ratio > 50 {
+            score -= 100;
+        }
+
+        score += credit_history as u16 * 5;
+        score = score.saturating_sub(missed_payments as u16 * 30);
+        let score = score.clamp(300, 850);
+        let approved = (score >= 700 && dti_ratio < 43) as u8;
+        (score, approved)
+    }
+
+    /// `(risk_score, risk_category)`; the score is at most 100 and the
+    /// category is 0 to 3 in steps of 25. The insurer receives the category
+    /// alone.
+    pub fn health_risk(
+        age: u8,
+        bmi: u8,
+        smoker: u8,
+        exercise_hours: u8,
+        family_history: u8,
+    ) -> (u8, u8) {
+        let mut risk: u8 = match age {
+            66.. => 30,
+            51..=65 => 20,
+            36..=50 => 10,
+            _ => 0,
+        };
+        if !(18..=30).contains(&bmi) {
+            risk += 20;
+        } else if bmi > 25 {
+            risk += 10;
+        }
+        if smoker != 0 {
+            risk += 25;
+        }
+        if exercise_hours >= 5 {
+            risk = risk.saturating_sub(10);
+        } else if exercise_hours < 2 {
+            risk += 10;
+        }
+        if family_history != 0 {
+            risk += 15;
+        }
+
+        let risk = risk.min(100);
+        (risk, (risk / 25).min(3))
+    }
+
+    /// Non-zero votes among the first `count` (at most 10) ballots.
+    pub fn vote_tally(votes: [u8; 10], count: u8) -> u8 {
+        votes
+            .iter()
+            .take(count.min(10) as usize)
+            .filter(|vote| **vote != 0)
+            .count() as u8
+    }
+
+    pub fn meets_threshold(value: u64, threshold: u64) -> u8 {
+        (value >= threshold) as u8
+    }
+
+    /// 0 when every weight is 0.
+    pub fn weighted_average(values: [u64; 5], weights: [u8; 5]) -> u64 {
+        let (weighted_sum, total_weight) = values
+            .iter()
+            .zip(weights)
+            .fold((0u64, 0u64), |(sum, total), (v, w)| {
+                (sum.wrapping_add(v.wrapping_mul(w as u64)), total + w as u64)
+            });
+        weighted_sum.checked_div(total_weight).unwrap_or(0)
+    }
+}
+
+/// Q32.32 values: `x` travels as the `i64` `round(x * 2^32)`.
+pub mod fixed_point {
+    pub const ONE: i64 = 1 << 32;
+
+    pub fn fixed_add(a: i64, b: i64) -> i64 {
+        a.wrapping_add(b)
+    }
+
+    pub fn fixed_sub(a: i64, b: i64) -> i64 {
+        a.wrapping_sub(b)
+    }
+
+    /// Truncates toward zero.
+    pub fn fixed_mul(a: i64, b: i64) -> i64 {
+        (a as i128 * b as i128 / ONE as i128) as i64
+    }
+
+    /// Truncates toward zero; 0 when `b` is 0.
+    pub fn fixed_div(a: i64, b: i64) -> i64 {
+        if b == 0 {
+            return 0;
+        }
+        (a as i128 * ONE as i128 / b as i128) as i64
+    }
+
+    /// The nearest whole number as Q32.32, halves away from zero; wraps
+    /// like the circuit when that number is 2^31.
+    pub fn fixed_round(value: i64) -> i64 {
+        let half = if value < 0 {
+            -(ONE as i128) / 2
+        } else {
+            ONE as i128 / 2
+        };
+        ((value as i128 + half) / ONE as i128 * ONE as i128) as i64
+    }
+
+    /// `value * percent / 100`, truncated toward zero.
+    pub fn fixed_percent(value: i64, percent: i64) -> i64 {
+        (value as i128 * percent as i128 / (100 * ONE as i128)) as i64
+    }
+}
+
+pub mod pipeline {
+    pub fn seal(value: u64) -> u64 {
+        value
+    }
+
+    pub fn unseal(value: u64) -> u64 {
+        value
+    }
+
+    /// See the op table on the circuit; unknown ops yield 0.
+    pub fn pipeline_step(op: u8, a: u64, b: u64) -> u64 {
+        match op {
+            0 => a.wrapping_add(b),
+            1 => a.saturating_sub(b),
+            2 => a.wrapping_mul(b),
+            3 => a.checked_div(b).unwrap_or(0),
+            4 => a.min(b),
+            5 => a.max(b),
+            6 => (a > b) as u64,
+            7 => (a < b) as u64,
+            8 => (a == b) as u64,
+            _ => 0,
+        }
+    }
+
+    /// See the op table on the circuit; unknown ops leave `dst` unchanged.
+    pub fn pipeline_eval(program: [u8; 64], registers: [u64; 8]) -> [u64; 8] {
+        let mut regs = registers;
+        let read = |regs: &[u64; 8], i: u8| regs.get(i as usize).copied().unwrap_or(0);
+
+        for ins in program.chunks_exact(4) {
+            let (op, dst) = (ins[0], ins[1]);
+            let (a, b, d) = (read(&regs, ins[2]), read(&regs, ins[3]), read(&regs, dst));
+            let value = match op {
+                1 => a.wrapping_add(b),
+                2 => a.saturating_sub(b),
+                3 => a.wrapping_mul(b),
+                4 => a.checked_div(b).unwrap_or(0),
+                5 => a.min(b),
+                6 => a.max(b),
+                7 => (a > b) as u64,
+                8 => (a < b) as u64,
+                9 => (a == b) as u64,
+                10 => (a != 0 && b != 0) as u64,
+                11 => (a != 0 || b != 0) as u64,
+                12 => (a == 0) as u64,
+                13 => {
+                    if a != 0 {
+                        b
+                    } else {
+                        d
+                    }
+                }
+                14 => a,
+                _ => d,
+            };
+            if let Some(slot) = regs.get_mut(dst as usize) {
+                *slot = value;
+            }
+        }
+        regs
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn division_by_zero_yields_zero(a: u64, s: i64) {
+            prop_assert_eq!(math::divide(a, 0), 0);
+            prop_assert_eq!(math::modulo(a, 0), 0);
+            prop_assert_eq!(math::divide_i64(s, 0), 0);
+            prop_assert_eq!(fixed_point::fixed_div(s, 0), 0);
+            prop_assert_eq!(pipeline::pipeline_step(3, a, 0), 0);
+        }
+
+        #[test]
+        fn subtract_clamps_at_zero(a: u64, b: u64) {
+            let result = math::subtract(a, b);
+            prop_assert_eq!(result as i128, (a as i128 - b as i128).max(0));
+        }
+
+        #[test]
+        fn unchecked_math_wraps(a: u64, b: u64) {
+            prop_assert_eq!(math::add(a, b), a.wrapping_add(b));
+            prop_assert_eq!(math::multiply(a, b), a.wrapping_mul(b));
+        }
+
+        #[test]
+        fn checked_math_flags_overflow(a: u64, b: u64, exponent: u8) {
+            let sum = a as u128 + b as u128;
+            let (result, overflow) = math::checked_add(a, b);
+            prop_assert_eq!(overflow == 1, sum > u64::MAX as u128);
+            prop_assert_eq!(result as u128, sum.min(u64::MAX as u128));
+
+            let product = a as u128 * b as u128;
+            let (result, overflow) = math::checked_multiply(a, b);
+            prop_assert_eq!(overflow == 1, product > u64::MAX as u128);
+            prop_assert_eq!(result as u128, product.min(u64::MAX as u128));
+
+            let base = a % 16;
+            let (result, overflow) = math::checked_power(base, exponent);
+            match (base as u128).checked_pow(exponent as u32).filter(|p| *p <= u64::MAX as u128) {
+                Some(p) => prop_assert_eq!((result as u128, overflow), (p, 0)),
+                None => prop_assert_eq!((result, overflow), (u64::MAX, 1)),
+            }
+        }
+
+        #[test]
+        fn power_caps_exponent_at_ten(base: u64, exponent in 10u8..) {
+            prop_assert_eq!(math::power(base, exponent), math::power(base, 10));
+        }
+
+        #[test]
+        fn signed_math_goes_negative(a in -1_000_000i64..1_000_000, b in -1_000_000i64..1_000_000) {
+            prop_assert_eq!(math::subtract_i64(a, b), a - b);
+            prop_assert_eq!(math::abs_diff_i64(a, b), (a - b).unsigned_abs());
+            prop_assert_eq!(comparison::compare_i64(a, b), (a - b).signum() as i8);
+        }
+
+        #[test]
+        fn abs_diff_i64_spans_the_full_range(a: i64, b: i64) {
+            prop_assert_eq!(math::abs_diff_i64(a, b) as i128, (a as i128 - b as i128).abs());
+        }
+
+        #[test]
+        fn count_above_ten_means_ten(values: [u64; 10], count in 10u8..) {
+            prop_assert_eq!(statistics::sum(values, count), statistics::sum(values, 10));
+            prop_assert_eq!(statistics::average(values, count), statistics::average(values, 10));
+            prop_assert_eq!(statistics::min(values, count), statistics::min(values, 10));
+            prop_assert_eq!(statistics::max(values, count), statistics::max(values, 10));
+            prop_assert_eq!(statistics::median(values, count), statistics::median(values, 10));
+        }
+
+        #[test]
+        fn statistics_ignore_slots_past_count(values: [u64; 10], padding: [u64; 10], count in 1u8..10) {
+            let mut padded = values;
+            padded[count as usize..].copy_from_slice(&padding[count as usize..]);
+            prop_assert_eq!(statistics::min(values, count), statistics::min(padded, count));
+            prop_assert_eq!(statistics::max(values, count), statistics::max(padded, count));
+            prop_assert_eq!(statistics::median(values, count), statistics::median(padded, count));
+        }
+
+        #[test]
+        fn median_sits_between_min_and_max(values: [u64; 10], count in 1u8..=10) {
+            let median = statistics::median(values, count);
+            prop_assert!(statistics::min(values, count) <= median);
+            prop_assert!(median <= statistics::max(values, count));
+        }
+
+        #[test]
+        fn credit_score_stays_clamped(income: u64, debt: u64, history: u8, missed: u8) {
+            let (score, approved) = use_cases::credit_score(income, debt, history, missed);
+            prop_assert!((300..=850).contains(&score));
+            prop_assert!(approved == 0 || score >= 700);
+        }
+
+        #[test]
+        fn credit_score_never_approves_without_income(debt: u64, history: u8, missed: u8) {
+            prop_assert_eq!(use_cases::credit_score(0, debt, history, missed).1, 0);
+        }
+
+        #[test]
+        fn health_risk_category_tracks_score(age: u8, bmi: u8, smoker: u8, exercise: u8, family: u8) {
+            let (risk, category) = use_cases::health_risk(age, bmi, smoker, exercise, family);
+            prop_assert!(risk <= 100);
+            let expected = match risk {
+                0..=24 => 0,
+                25..=49 => 1,
+                50..=74 => 2,
+                _ => 3,
+            };
+            prop_assert_eq!(category, expected);
+        }
+
+        #[test]
+        fn vote_tally_counts_only_counted_ballots(votes: [u8; 10], count: u8) {
+            let tally = use_cases::vote_tally(votes, count);
+            prop_assert!(tally <= count.min(10));
+            prop_assert_eq!(use_cases::vote_tally([1; 10], count), count.min(10));
+        }
+
+        #[test]
+        fn weighted_average_of_equal_weights_is_the_mean(values in prop::array::uniform5(0u64..1 << 50), weight in 1u8..) {
+            let mean = values.iter().sum::<u64>() / 5;
+            prop_assert_eq!(use_cases::weighted_average(values, [weight; 5]), mean);
+        }
+
+        #[test]
+        fn fixed_point_round_trips_whole_numbers(a in -1_000_000i64..1_000_000, b in 1i64..1_000) {
+            let (fa, fb) = (a * fixed_point::ONE, b * fixed_point::ONE);
+            prop_assert_eq!(fixed_point::fixed_mul(fa, fb), a * b * fixed_point::ONE);
+            prop_assert_eq!(fixed_point::fixed_round(fixed_point::fixed_div(fa, fb)), (a as f64 / b as f64).round() as i64 * fixed_point::ONE);
+            prop_assert_eq!(fixed_point::fixed_percent(fa, 100 * fixed_point::ONE), fa);
+        }
+
+        #[test]
+        fn pipeline_eval_matches_pipeline_step(op in 0u8..9, a: u64, b: u64) {
+            let mut program = [0u8; 64];
+            program[..4].copy_from_slice(&[op + 1, 2, 0, 1]);
+            let registers = pipeline::pipeline_eval(program, [a, b, 0, 0, 0, 0, 0, 0]);
+            prop_assert_eq!(registers[2], pipeline::pipeline_step(op, a, b));
+        }
+    }
+
+    #[test]
+    fn median_of_even_count_floors_the_middle_pair() {
+        let values = [4, 1, 3, 2, 0, 0, 0, 0, 0, 0];
+        assert_eq!(statistics::median(values, 4), 2);
+        assert_eq!(
+            statistics::median([u64::MAX, u64::MAX - 1, 0, 0, 0, 0, 0, 0, 0, 0], 2),
+            u64::MAX - 1
+        );
+    }
+
+    #[test]
+    fn fixed_round_rounds_halves_away_from_zero() {
+        let half = fixed_point::ONE / 2;
+        assert_eq!(fixed_point::fixed_round(2 * fixed_point::ONE + half), 3 * fixed_point::ONE);
+        assert_eq!(fixed_point::fixed_round(-2 * fixed_point::ONE - half), -3 * fixed_point::ONE);
+        assert_eq!(fixed_point::fixed_round(-half + 1), 0);
+        assert_eq!(fixed_point::fixed_round(fixed_point::ONE + half - 1), fixed_point::ONE);
+    }
+}
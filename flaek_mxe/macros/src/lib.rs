@@ -0,0 +1,750 @@
+//! `#[circuits]` expands the `circuit!(...)` lines of the flaek_mxe program
+//! module into the per-circuit instructions, callbacks, account structs and
+//! events, so every circuit is wired up the same way.
+//!
+//! It must sit above `#[arcium_program]` so the generated functions exist by
+//! the time the program macro collects its instructions.
+
+mod spec;
+
+use proc_macro::TokenStream;
+use proc_macro2::{Span, TokenStream as TokenStream2};
+use quote::{format_ident, quote};
+use spec::{ArgType, CircuitSpec, Output};
+use syn::{parse_macro_input, Error, File, Ident, Item, ItemMod, LitStr, Result};
+
+#[proc_macro_attribute]
+pub fn circuits(attr: TokenStream, item: TokenStream) -> TokenStream {
+    if !attr.is_empty() {
+        return Error::new(Span::call_site(), "#[circuits] takes no arguments")
+            .to_compile_error()
+            .into();
+    }
+    let module = parse_macro_input!(item as ItemMod);
+    expand(module)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+fn expand(mut module: ItemMod) -> Result<TokenStream2> {
+    let Some((_, items)) = module.content.as_mut() else {
+        return Err(Error::new_spanned(
+            &module,
+            "#[circuits] needs an inline module",
+        ));
+    };
+
+    let mut content = Vec::new();
+    let mut outer = Vec::new();
+    let mut names = Vec::new();
+    let mut init_arms = Vec::new();
+    for item in std::mem::take(items) {
+        match item {
+            Item::Macro(m) if m.mac.path.is_ident("circuit") => {
+                let circuit = Circuit::new(m.mac.parse_body()?);
+                content.extend(syn::parse2::<File>(circuit.instructions()?)?.items);
+                outer.push(circuit.items());
+                names.push(circuit.name_lit.clone());
+                init_arms.push(circuit.init_arm());
+            }
+            other => content.push(other),
+        }
+    }
+    *items = content;
+
+    Ok(quote! {
+        #module
+        #(#outer)*
+
+        /// Every circuit declared with `circuit!`, in declaration order.
+        pub const CIRCUITS: &[&str] = &[#(#names),*];
+
+        /// The `init_<circuit>_comp_def` instruction, for tooling that walks
+        /// [`CIRCUITS`] instead of naming each circuit.
+        #[cfg(not(target_os = "solana"))]
+        pub fn init_comp_def_instruction(
+            circuit: &str,
+            payer: Pubkey,
+            base_url: Option<String>,
+        ) -> Option<::anchor_lang::solana_program::instruction::Instruction> {
+            use ::anchor_lang::{InstructionData, ToAccountMetas};
+
+            let (accounts, data) = match circuit {
+                #(#init_arms)*
+                _ => return None,
+            };
+            Some(::anchor_lang::solana_program::instruction::Instruction {
+                program_id: ID,
+                accounts,
+                data,
+            })
+        }
+    })
+}
+
+/// Every identifier a circuit's generated code refers to, derived from its
+/// snake_case name.
+struct Circuit {
+    spec: CircuitSpec,
+    name_lit: LitStr,
+    offset: Ident,
+    init_fn: Ident,
+    callback_fn: Ident,
+    queue_accs: Ident,
+    callback_accs: Ident,
+    init_accs: Ident,
+    output: Ident,
+    event: Ident,
+}
+
+impl Circuit {
+    fn new(spec: CircuitSpec) -> Self {
+        let name = spec.name.to_string();
+        let pascal: String = name
+            .split('_')
+            .map(|word| {
+                let mut chars = word.chars();
+                chars
+                    .next()
+                    .map(|c| c.to_ascii_uppercase().to_string() + chars.as_str())
+                    .unwrap_or_default()
+            })
+            .collect();
+        let ident = |s: String| Ident::new(&s, Span::call_site());
+
+        Circuit {
+            name_lit: LitStr::new(&name, Span::call_site()),
+            offset: ident(format!("COMP_DEF_OFFSET_{}", name.to_uppercase())),
+            init_fn: ident(format!("init_{name}_comp_def")),
+            callback_fn: ident(format!("{name}_callback")),
+            queue_accs: ident(pascal.clone()),
+            callback_accs: ident(format!("{pascal}Callback")),
+            init_accs: ident(format!("Init{pascal}CompDef")),
+            output: ident(format!("{pascal}Output")),
+            event: ident(format!("{pascal}Event")),
+            spec,
+        }
+    }
+
+    /// The init, queue and callback handlers that live in the program module.
+    fn instructions(&self) -> Result<TokenStream2> {
+        let Circuit {
+            spec,
+            name_lit,
+            offset,
+            init_fn,
+            callback_fn,
+            queue_accs,
+            callback_accs,
+            init_accs,
+            output,
+            event,
+        } = self;
+        let name = &spec.name;
+
+        // Arguments are pushed in circuit order; the caller's key and nonce go
+        // in front of the first encrypted argument.
+        let mut params = Vec::new();
+        let mut pushes = Vec::new();
+        let mut shared_input = false;
+        for arg in &spec.args {
+            let arg_name = &arg.name;
+            if arg.ty.is_encrypted() && !shared_input {
+                shared_input = true;
+                pushes.push(quote!(args.extend([
+                    Argument::ArcisPubkey(pub_key),
+                    Argument::PlaintextU128(nonce),
+                ]);));
+            }
+            match &arg.ty {
+                ArgType::Scalar(ty) => {
+                    let variant = ArgType::encrypted_variant(ty)?;
+                    params.push(quote!(#arg_name: [u8; 32]));
+                    pushes.push(quote!(args.push(Argument::#variant(#arg_name));));
+                }
+                ArgType::Array(ty, len) => {
+                    let variant = ArgType::encrypted_variant(ty)?;
+                    params.push(quote!(#arg_name: [[u8; 32]; #len]));
+                    pushes
+                        .push(quote!(args.extend(#arg_name.into_iter().map(Argument::#variant));));
+                }
+                ArgType::Plaintext(ty) => {
+                    let variant = ArgType::plaintext_variant(ty)?;
+                    params.push(quote!(#arg_name: #ty));
+                    pushes.push(quote!(args.push(Argument::#variant(#arg_name));));
+                }
+                ArgType::PlaintextArray(ty, len) => {
+                    let variant = ArgType::plaintext_variant(ty)?;
+                    params.push(quote!(#arg_name: [#ty; #len]));
+                    pushes
+                        .push(quote!(args.extend(#arg_name.into_iter().map(Argument::#variant));));
+                }
+                ArgType::Shared => {
+                    let key = Ident::new(&format!("{arg_name}_pub_key"), Span::call_site());
+                    let nonce = Ident::new(&format!("{arg_name}_nonce"), Span::call_site());
+                    params.push(quote!(#key: [u8; 32]));
+                    params.push(quote!(#nonce: u128));
+                    pushes.push(quote!(args.extend([
+                        Argument::ArcisPubkey(#key),
+                        Argument::PlaintextU128(#nonce),
+                    ]);));
+                }
+                ArgType::Intermediate => {
+                    pushes.push(quote!(args.extend(intermediate_args(&ctx.accounts.#arg_name));));
+                }
+            }
+        }
+        if shared_input {
+            params.push(quote!(pub_key: [u8; 32]));
+            params.push(quote!(nonce: u128));
+        }
+
+        let (open_output, callback_accounts, fail_output, store_output, event_fields) =
+            match &spec.output {
+                Output::Fields(outputs) => {
+                    let fields = outputs
+                        .iter()
+                        .enumerate()
+                        .map(|(i, field)| quote!(#field: o.ciphertexts[#i],));
+                    (
+                        quote!(),
+                        quote!(),
+                        quote!(),
+                        quote!(),
+                        quote!(#(#fields)* nonce: o.nonce.to_le_bytes(),),
+                    )
+                }
+                Output::Array(field, _) => (
+                    quote!(),
+                    quote!(),
+                    quote!(),
+                    quote!(),
+                    quote!(#field: o.ciphertexts, nonce: o.nonce.to_le_bytes(),),
+                ),
+                Output::Intermediate => (
+                    quote! {
+                        open_intermediate(
+                            &mut ctx.accounts.intermediate,
+                            ctx.bumps.intermediate,
+                            computation_offset,
+                            ctx.accounts.payer.key(),
+                        );
+                        let intermediate_account = CallbackAccount {
+                            pubkey: ctx.accounts.intermediate.key(),
+                            is_writable: true,
+                        };
+                    },
+                    quote!(intermediate_account),
+                    quote!(ctx.accounts.intermediate.status = ComputationStatus::Failed;),
+                    quote! {
+                        store_intermediate(
+                            &mut ctx.accounts.intermediate,
+                            o.ciphertexts[0],
+                            o.nonce,
+                        );
+                    },
+                    quote!(intermediate: ctx.accounts.intermediate.key(),),
+                ),
+            };
+        // The client's output comes first, then the recipient's, then each
+        // revealed value. With more than one, the circuit returns a tuple,
+        // which Arcium wraps in one more struct.
+        let mut bindings = vec![format_ident!("o")];
+        let mut extra_event_fields = Vec::new();
+        let mut shared_output = quote!(None);
+        if let Some(recipient_output) = &spec.recipient_output {
+            let (fields, key, nonce) = recipient_output.event_fields();
+            let fields = fields
+                .iter()
+                .enumerate()
+                .map(|(i, field)| quote!(#field: r.ciphertexts[#i],));
+            bindings.push(format_ident!("r"));
+            extra_event_fields.push(quote! {
+                #(#fields)*
+                #key: r.encryption_key,
+                #nonce: r.nonce.to_le_bytes(),
+            });
+            shared_output = quote! {
+                Some(SharedOutput {
+                    ciphertexts: r.ciphertexts.to_vec(),
+                    encryption_key: r.encryption_key,
+                    nonce: r.nonce.to_le_bytes(),
+                })
+            };
+        }
+        let revealed: Vec<Ident> = spec.revealed.iter().map(|r| r.event_field()).collect();
+        bindings.extend(revealed.iter().cloned());
+        extra_event_fields.push(quote!(#(#revealed,)*));
+        let (outputs, success) = if bindings.len() == 1 {
+            (quote!(o), quote!(#output { field_0: o }))
+        } else {
+            let output_struct = format_ident!("{output}Struct0");
+            let fields = (0..bindings.len()).map(|i| format_ident!("field_{i}"));
+            (
+                quote!((#(#bindings),*)),
+                quote!(#output {
+                    field_0: #output_struct {
+                        #(#fields: #bindings,)*
+                    },
+                }),
+            )
+        };
+        Ok(quote! {
+            pub fn #init_fn(
+                ctx: Context<#init_accs>,
+                base_url: Option<String>,
+            ) -> Result<()> {
+                let source = register_circuit(
+                    &mut ctx.accounts.circuit_registry,
+                    ctx.bumps.circuit_registry,
+                    #name_lit,
+                    #offset,
+                    base_url,
+                )?;
+                let authority = ctx.accounts.config.authority;
+                init_comp_def_zero(ctx.accounts, true, 0, source, Some(authority))?;
+                Ok(())
+            }
+
+            pub fn #name(
+                ctx: Context<#queue_accs>,
+                computation_offset: u64,
+                #(#params,)*
+            ) -> Result<()> {
+                ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
+                let credits_debited = debit_credits(
+                    &ctx.accounts.config,
+                    #offset,
+                    ctx.accounts.credits.as_deref_mut(),
+                )?;
+                open_computation_result(
+                    &mut ctx.accounts.computation_result,
+                    ctx.bumps.computation_result,
+                    computation_offset,
+                    ctx.accounts.payer.key(),
+                    #offset,
+                    credits_debited,
+                );
+                charge_fee(
+                    &ctx.accounts.config,
+                    #offset,
+                    FeeAccounts {
+                        payer: &ctx.accounts.payer,
+                        treasury: &ctx.accounts.treasury,
+                        payer_fee_account: ctx.accounts.payer_fee_account.as_deref(),
+                        treasury_fee_account: ctx.accounts.treasury_fee_account.as_deref(),
+                        token_program: ctx.accounts.token_program.as_ref(),
+                        system_program: &ctx.accounts.system_program,
+                    },
+                )?;
+                let result_account = CallbackAccount {
+                    pubkey: ctx.accounts.computation_result.key(),
+                    is_writable: true,
+                };
+                let credits_account = credits_callback_account(ctx.accounts.credits.as_deref());
+                #open_output
+                let mut args = Vec::new();
+                #(#pushes)*
+                open_attestation(
+                    &mut ctx.accounts.attestation,
+                    ctx.bumps.attestation,
+                    computation_offset,
+                    &ctx.accounts.circuit_registry,
+                    ctx.accounts.cluster_account.key(),
+                    &args,
+                )?;
+                let attestation_account = CallbackAccount {
+                    pubkey: ctx.accounts.attestation.key(),
+                    is_writable: true,
+                };
+
+                queue_computation(
+                    ctx.accounts,
+                    computation_offset,
+                    args,
+                    None,
+                    vec![#callback_accs::callback_ix(&[
+                        result_account,
+                        credits_account,
+                        attestation_account,
+                        #callback_accounts
+                    ])],
+                )?;
+
+                Ok(())
+            }
+
+            #[arcium_callback(encrypted_ix = #name_lit)]
+            pub fn #callback_fn(
+                ctx: Context<#callback_accs>,
+                output: ComputationOutputs<#output>,
+            ) -> Result<()> {
+                let #outputs = match output {
+                    ComputationOutputs::Success(#success) => #outputs,
+                    _ => {
+                        #fail_output
+                        return fail_computation(
+                            &mut ctx.accounts.computation_result,
+                            ctx.accounts.credits.as_deref_mut(),
+                            FailureReason::Aborted,
+                        )
+                    }
+                };
+                let recipient_output: Option<SharedOutput> = #shared_output;
+                let revealed_values = [#(#revealed as u64),*];
+                store_computation_result(
+                    &mut ctx.accounts.computation_result,
+                    &o.ciphertexts,
+                    o.nonce,
+                    recipient_output.as_ref(),
+                    &revealed_values,
+                )?;
+                attest(
+                    &mut ctx.accounts.attestation,
+                    &o.ciphertexts,
+                    o.nonce,
+                    recipient_output.as_ref(),
+                    &revealed_values,
+                )?;
+                release_credits(
+                    &mut ctx.accounts.computation_result,
+                    ctx.accounts.credits.as_deref_mut(),
+                    false,
+                )?;
+                #store_output
+
+                let result = &ctx.accounts.computation_result;
+                emit!(#event {
+                    computation_offset: result.computation_offset,
+                    payer: result.payer,
+                    circuit: result.comp_def_offset,
+                    #event_fields
+                    #(#extra_event_fields)*
+                });
+                Ok(())
+            }
+        })
+    }
+
+    /// This circuit's case in `init_comp_def_instruction`.
+    fn init_arm(&self) -> TokenStream2 {
+        let Circuit {
+            name_lit,
+            offset,
+            init_accs,
+            ..
+        } = self;
+        quote! {
+            #name_lit => (
+                accounts::#init_accs {
+                    payer,
+                    mxe_account: derive_mxe_pda!(),
+                    comp_def_account: derive_comp_def_pda!(#offset),
+                    circuit_registry: Pubkey::find_program_address(
+                        &[CIRCUIT_REGISTRY_SEED, &#offset.to_le_bytes()],
+                        &ID,
+                    )
+                    .0,
+                    config: Pubkey::find_program_address(&[CONFIG_SEED], &ID).0,
+                    arcium_program: ARCIUM_PROG_ID,
+                    system_program: ::anchor_lang::system_program::ID,
+                }
+                .to_account_metas(None),
+                instruction::#init_accs { base_url }.data(),
+            ),
+        }
+    }
+
+    /// The offset constant, account structs and event, emitted next to the
+    /// program module.
+    fn items(&self) -> TokenStream2 {
+        let Circuit {
+            spec,
+            name_lit,
+            offset,
+            queue_accs,
+            callback_accs,
+            init_accs,
+            event,
+            ..
+        } = self;
+
+        // MXE-owned inputs must be finished and belong to the caller, or
+        // anyone could unseal someone else's intermediate to themselves.
+        let input_accounts = spec
+            .args
+            .iter()
+            .filter(|arg| matches!(arg.ty, ArgType::Intermediate))
+            .map(|arg| {
+                let arg_name = &arg.name;
+                quote! {
+                    #[account(
+                        has_one = payer @ ErrorCode::IntermediateNotOwned,
+                        constraint = #arg_name.status == ComputationStatus::Completed
+                            @ ErrorCode::IntermediateNotReady,
+                    )]
+                    pub #arg_name: Box<Account<'info, Intermediate>>,
+                }
+            });
+
+        let recipient_event_fields = spec.recipient_output.as_ref().map(|recipient_output| {
+            let (fields, key, nonce) = recipient_output.event_fields();
+            let key_doc = format!(
+                "The x25519 key the `{}_*` fields are encrypted to.",
+                recipient_output.recipient
+            );
+            quote! {
+                #(pub #fields: [u8; 32],)*
+                #[doc = #key_doc]
+                pub #key: [u8; 32],
+                pub #nonce: [u8; 16],
+            }
+        });
+        let revealed_event_fields = spec.revealed.iter().map(|revealed| {
+            let field = revealed.event_field();
+            let ty = &revealed.ty;
+            quote!(pub #field: #ty,)
+        });
+
+        let (queue_output, callback_output, event_fields) = match &spec.output {
+            Output::Fields(outputs) => (
+                quote!(),
+                quote!(),
+                quote! {
+                    #(pub #outputs: [u8; 32],)*
+                    pub nonce: [u8; 16],
+                },
+            ),
+            Output::Array(field, len) => (
+                quote!(),
+                quote!(),
+                quote! {
+                    pub #field: [[u8; 32]; #len],
+                    pub nonce: [u8; 16],
+                },
+            ),
+            Output::Intermediate => (
+                quote! {
+                    #[account(
+                        init,
+                        payer = payer,
+                        space = 8 + Intermediate::INIT_SPACE,
+                        seeds = [INTERMEDIATE_SEED, &computation_offset.to_le_bytes()],
+                        bump,
+                    )]
+                    pub intermediate: Box<Account<'info, Intermediate>>,
+                },
+                quote! {
+                    #[account(
+                        mut,
+                        seeds = [
+                            INTERMEDIATE_SEED,
+                            &intermediate.computation_offset.to_le_bytes(),
+                        ],
+                        bump = intermediate.bump,
+                    )]
+                    pub intermediate: Box<Account<'info, Intermediate>>,
+                },
+                quote! {
+                    /// The `Intermediate` account now holding the MXE-owned output.
+                    pub intermediate: Pubkey,
+                },
+            ),
+        };
+
+        quote! {
+            const #offset: u32 = comp_def_offset(#name_lit);
+
+            #[queue_computation_accounts(#name_lit, payer)]
+            #[derive(Accounts)]
+            #[instruction(computation_offset: u64)]
+            pub struct #queue_accs<'info> {
+                #[account(mut)]
+                pub payer: Signer<'info>,
+                #[account(
+                    init_if_needed,
+                    space = 9,
+                    payer = payer,
+                    seeds = [&SIGN_PDA_SEED],
+                    bump,
+                    address = derive_sign_pda!(),
+                )]
+                pub sign_pda_account: Account<'info, SignerAccount>,
+                #[account(
+                    address = derive_mxe_pda!()
+                )]
+                pub mxe_account: Box<Account<'info, MXEAccount>>,
+                #[account(
+                    mut,
+                    address = derive_mempool_pda!()
+                )]
+                /// CHECK: mempool_account, checked by the arcium program.
+                pub mempool_account: UncheckedAccount<'info>,
+                #[account(
+                    mut,
+                    address = derive_execpool_pda!()
+                )]
+                /// CHECK: executing_pool, checked by the arcium program.
+                pub executing_pool: UncheckedAccount<'info>,
+                #[account(
+                    mut,
+                    address = derive_comp_pda!(computation_offset)
+                )]
+                /// CHECK: computation_account, checked by the arcium program.
+                pub computation_account: UncheckedAccount<'info>,
+                #[account(
+                    address = derive_comp_def_pda!(#offset)
+                )]
+                pub comp_def_account: Box<Account<'info, ComputationDefinitionAccount>>,
+                /// Pins the circuit hash the attestation records.
+                #[account(
+                    seeds = [CIRCUIT_REGISTRY_SEED, &#offset.to_le_bytes()],
+                    bump = circuit_registry.bump,
+                )]
+                pub circuit_registry: Box<Account<'info, CircuitRegistry>>,
+                #[account(
+                    mut,
+                    address = derive_cluster_pda!(mxe_account)
+                )]
+                pub cluster_account: Box<Account<'info, Cluster>>,
+                #[account(
+                    mut,
+                    address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS,
+                )]
+                pub pool_account: Box<Account<'info, FeePool>>,
+                #[account(
+                    address = ARCIUM_CLOCK_ACCOUNT_ADDRESS
+                )]
+                pub clock_account: Box<Account<'info, ClockAccount>>,
+                #[account(
+                    init,
+                    payer = payer,
+                    space = 8 + ComputationResult::INIT_SPACE,
+                    seeds = [COMPUTATION_RESULT_SEED, &computation_offset.to_le_bytes()],
+                    bump,
+                )]
+                pub computation_result: Box<Account<'info, ComputationResult>>,
+                #[account(
+                    seeds = [CONFIG_SEED],
+                    bump = config.bump,
+                    constraint = config.admits(
+                        &payer.key(),
+                        co_signer.as_ref().map(|signer| signer.key).as_deref(),
+                    ) @ ErrorCode::Unauthorized,
+                )]
+                pub config: Box<Account<'info, Config>>,
+                /// Required when the config gates payers behind a co-signer.
+                pub co_signer: Option<Signer<'info>>,
+                #[account(mut, seeds = [TREASURY_SEED], bump)]
+                pub treasury: SystemAccount<'info>,
+                /// Required when fees are charged in an SPL token.
+                #[account(mut)]
+                pub payer_fee_account: Option<Box<Account<'info, TokenAccount>>>,
+                #[account(mut)]
+                pub treasury_fee_account: Option<Box<Account<'info, TokenAccount>>>,
+                pub token_program: Option<Program<'info, Token>>,
+                /// Required when the circuit has a credit cost.
+                #[account(
+                    mut,
+                    seeds = [CREDITS_SEED, payer.key().as_ref()],
+                    bump = credits.bump,
+                )]
+                pub credits: Option<Box<Account<'info, TenantCredits>>>,
+                #[account(
+                    init,
+                    payer = payer,
+                    space = 8 + Attestation::INIT_SPACE,
+                    seeds = [ATTESTATION_SEED, &computation_offset.to_le_bytes()],
+                    bump,
+                )]
+                pub attestation: Box<Account<'info, Attestation>>,
+                #(#input_accounts)*
+                #queue_output
+                pub system_program: Program<'info, System>,
+                pub arcium_program: Program<'info, Arcium>,
+            }
+
+            #[callback_accounts(#name_lit)]
+            #[derive(Accounts)]
+            pub struct #callback_accs<'info> {
+                pub arcium_program: Program<'info, Arcium>,
+                #[account(
+                    address = derive_comp_def_pda!(#offset)
+                )]
+                pub comp_def_account: Box<Account<'info, ComputationDefinitionAccount>>,
+                #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
+                /// CHECK: instructions_sysvar, checked by the account constraint
+                pub instructions_sysvar: AccountInfo<'info>,
+                #[account(
+                    mut,
+                    seeds = [
+                        COMPUTATION_RESULT_SEED,
+                        &computation_result.computation_offset.to_le_bytes(),
+                    ],
+                    bump = computation_result.bump,
+                )]
+                pub computation_result: Box<Account<'info, ComputationResult>>,
+                /// The payer's credits, whose pending debit the callback
+                /// releases.
+                #[account(
+                    mut,
+                    seeds = [CREDITS_SEED, computation_result.payer.as_ref()],
+                    bump = credits.bump,
+                )]
+                pub credits: Option<Box<Account<'info, TenantCredits>>>,
+                #[account(
+                    mut,
+                    seeds = [
+                        ATTESTATION_SEED,
+                        &attestation.computation_offset.to_le_bytes(),
+                    ],
+                    bump = attestation.bump,
+                )]
+                pub attestation: Box<Account<'info, Attestation>>,
+                #callback_output
+            }
+
+            #[init_computation_definition_accounts(#name_lit, payer)]
+            #[derive(Accounts)]
+            pub struct #init_accs<'info> {
+                #[account(mut)]
+                pub payer: Signer<'info>,
+                #[account(
+                    mut,
+                    address = derive_mxe_pda!()
+                )]
+                pub mxe_account: Box<Account<'info, MXEAccount>>,
+                #[account(mut)]
+                /// CHECK: comp_def_account, checked by arcium program.
+                pub comp_def_account: UncheckedAccount<'info>,
+                #[account(
+                    init,
+                    payer = payer,
+                    space = 8 + CircuitRegistry::INIT_SPACE,
+                    seeds = [CIRCUIT_REGISTRY_SEED, &#offset.to_le_bytes()],
+                    bump,
+                )]
+                pub circuit_registry: Account<'info, CircuitRegistry>,
+                #[account(
+                    seeds = [CONFIG_SEED],
+                    bump = config.bump,
+                    constraint = config.authority == payer.key() @ ErrorCode::NotAuthority,
+                )]
+                pub config: Account<'info, Config>,
+                pub arcium_program: Program<'info, Arcium>,
+                pub system_program: Program<'info, System>,
+            }
+
+            #[event]
+            pub struct #event {
+                pub computation_offset: u64,
+                pub payer: Pubkey,
+                /// `comp_def_offset` of the circuit that produced this output.
+                pub circuit: u32,
+                #event_fields
+                #recipient_event_fields
+                #(#revealed_event_fields)*
+            }
+        }
+    }
+}
@@ -0,0 +1,269 @@
+use proc_macro2::Span;
+use quote::format_ident;
+use syn::{
+    bracketed, parenthesized,
+    parse::{Parse, ParseStream},
+    punctuated::Punctuated,
+    Error, Ident, LitInt, Result, Token,
+};
+
+/// One `circuit!(name(arg: ty, ...) -> outputs)` line. Arguments are listed
+/// in the order the circuit takes them.
+pub struct CircuitSpec {
+    pub name: Ident,
+    pub args: Vec<CircuitArg>,
+    pub output: Output,
+    pub recipient_output: Option<RecipientOutput>,
+    /// Outputs the circuit `.reveal()`s, e.g. `+ reveal(approved: u8)`.
+    pub revealed: Vec<RevealedOutput>,
+}
+
+pub enum Output {
+    /// Ciphertexts for the client, one event field each, e.g. `result` or
+    /// `(score, approved)`.
+    Fields(Vec<Ident>),
+    /// One event field holding every ciphertext, e.g. `registers[8]`.
+    Array(Ident, LitInt),
+    /// A single MXE-owned value, written to a fresh `Intermediate` account.
+    Intermediate,
+}
+
+/// Output fields also encrypted to a `Shared` argument, e.g.
+/// `+ lender(approved)` after the caller's fields.
+pub struct RecipientOutput {
+    pub recipient: Ident,
+    pub fields: Vec<Ident>,
+}
+
+impl RecipientOutput {
+    /// The event fields carrying this output: one per field, prefixed with
+    /// the recipient's name, then its key and nonce.
+    pub fn event_fields(&self) -> (Vec<Ident>, Ident, Ident) {
+        let recipient = &self.recipient;
+        let fields = self
+            .fields
+            .iter()
+            .map(|field| format_ident!("{recipient}_{field}"))
+            .collect();
+        (
+            fields,
+            format_ident!("{recipient}_pub_key"),
+            format_ident!("{recipient}_nonce"),
+        )
+    }
+}
+
+/// A plaintext output, emitted as `revealed_<name>` and stored in the
+/// result account. The account widens every value to u64, so only `bool`
+/// and unsigned integers up to `u64` can be revealed; a signed value would
+/// come back sign-wrapped.
+pub struct RevealedOutput {
+    pub name: Ident,
+    pub ty: Ident,
+}
+
+impl RevealedOutput {
+    pub fn event_field(&self) -> Ident {
+        format_ident!("revealed_{}", self.name)
+    }
+}
+
+impl Parse for RevealedOutput {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let name = input.parse()?;
+        input.parse::<Token![:]>()?;
+        let ty: Ident = input.parse()?;
+        if !matches!(
+            ty.to_string().as_str(),
+            "bool" | "u8" | "u16" | "u32" | "u64"
+        ) {
+            return Err(Error::new(
+                ty.span(),
+                format!("cannot reveal `{ty}`: revealed values are stored as u64, so use `bool` or an unsigned integer up to `u64`"),
+            ));
+        }
+        Ok(RevealedOutput { name, ty })
+    }
+}
+
+pub struct CircuitArg {
+    pub name: Ident,
+    pub ty: ArgType,
+}
+
+pub enum ArgType {
+    /// One ciphertext under the caller's key, e.g. `a: u64`.
+    Scalar(Ident),
+    /// One ciphertext per element, e.g. `values: [u64; 10]`.
+    Array(Ident, LitInt),
+    /// A value the circuit sees in the clear, e.g. `op: plaintext u8`.
+    Plaintext(Ident),
+    /// Plaintext values passed one per element, e.g.
+    /// `program: plaintext [u8; 64]`.
+    PlaintextArray(Ident, LitInt),
+    /// A client key the circuit encrypts to, e.g. `recipient: Shared`.
+    Shared,
+    /// An MXE-owned value read from an `Intermediate` account, e.g.
+    /// `lhs: Intermediate`.
+    Intermediate,
+}
+
+impl ArgType {
+    /// Whether this argument is part of the caller's `Enc<Shared, _>` input.
+    pub fn is_encrypted(&self) -> bool {
+        matches!(self, ArgType::Scalar(_) | ArgType::Array(..))
+    }
+
+    /// The `Argument` variant carrying one encrypted element of this type.
+    pub fn encrypted_variant(ty: &Ident) -> Result<Ident> {
+        variant(ty, "Encrypted")
+    }
+
+    /// The `Argument` variant carrying one plaintext value of this type.
+    pub fn plaintext_variant(ty: &Ident) -> Result<Ident> {
+        variant(ty, "Plaintext")
+    }
+}
+
+fn variant(ty: &Ident, prefix: &str) -> Result<Ident> {
+    let suffix = match ty.to_string().as_str() {
+        "bool" => "Bool",
+        "u8" => "U8",
+        "u16" => "U16",
+        "u32" => "U32",
+        "u64" => "U64",
+        "u128" => "U128",
+        "i8" => "I8",
+        "i16" => "I16",
+        "i32" => "I32",
+        "i64" => "I64",
+        "i128" => "I128",
+        other => {
+            return Err(Error::new(
+                ty.span(),
+                format!("unsupported circuit argument type `{other}`"),
+            ))
+        }
+    };
+    Ok(Ident::new(&format!("{prefix}{suffix}"), Span::call_site()))
+}
+
+impl Parse for CircuitSpec {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let name: Ident = input.parse()?;
+
+        let content;
+        parenthesized!(content in input);
+        let args: Vec<CircuitArg> = Punctuated::<CircuitArg, Token![,]>::parse_terminated(&content)?
+            .into_iter()
+            .collect();
+
+        input.parse::<Token![->]>()?;
+        let output = if input.peek(syn::token::Paren) {
+            let content;
+            parenthesized!(content in input);
+            Output::Fields(
+                Punctuated::<Ident, Token![,]>::parse_terminated(&content)?
+                    .into_iter()
+                    .collect(),
+            )
+        } else {
+            let field: Ident = input.parse()?;
+            if field == "Intermediate" {
+                Output::Intermediate
+            } else if input.peek(syn::token::Bracket) {
+                let content;
+                bracketed!(content in input);
+                Output::Array(field, content.parse()?)
+            } else {
+                Output::Fields(vec![field])
+            }
+        };
+
+        let mut recipient_output: Option<RecipientOutput> = None;
+        let mut revealed = Vec::new();
+        while input.peek(Token![+]) {
+            input.parse::<Token![+]>()?;
+            let recipient: Ident = input.parse()?;
+            if !matches!(output, Output::Fields(_)) {
+                return Err(Error::new(
+                    recipient.span(),
+                    "only circuits with client outputs can add a recipient or reveal",
+                ));
+            }
+            let content;
+            parenthesized!(content in input);
+            if recipient == "reveal" {
+                revealed.extend(Punctuated::<RevealedOutput, Token![,]>::parse_terminated(
+                    &content,
+                )?);
+                continue;
+            }
+            let is_shared_arg = args
+                .iter()
+                .any(|arg| arg.name == recipient && matches!(arg.ty, ArgType::Shared));
+            if !is_shared_arg {
+                return Err(Error::new(
+                    recipient.span(),
+                    format!("`{recipient}` is not a `Shared` argument of this circuit"),
+                ));
+            }
+            if let Some(previous) = &recipient_output {
+                return Err(Error::new(
+                    recipient.span(),
+                    format!(
+                        "`{}` already receives this circuit's output; a circuit can have only one recipient",
+                        previous.recipient
+                    ),
+                ));
+            }
+            let fields = Punctuated::<Ident, Token![,]>::parse_terminated(&content)?
+                .into_iter()
+                .collect();
+            recipient_output = Some(RecipientOutput { recipient, fields });
+        }
+
+        Ok(CircuitSpec {
+            name,
+            args,
+            output,
+            recipient_output,
+            revealed,
+        })
+    }
+}
+
+impl Parse for CircuitArg {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let name = input.parse()?;
+        input.parse::<Token![:]>()?;
+        let ty = if input.peek(syn::token::Bracket) {
+            let (ty, len) = parse_array(input)?;
+            ArgType::Array(ty, len)
+        } else {
+            let ty: Ident = input.parse()?;
+            if ty == "plaintext" && input.peek(syn::token::Bracket) {
+                let (ty, len) = parse_array(input)?;
+                ArgType::PlaintextArray(ty, len)
+            } else if ty == "plaintext" {
+                ArgType::Plaintext(input.parse()?)
+            } else if ty == "Shared" {
+                ArgType::Shared
+            } else if ty == "Intermediate" {
+                ArgType::Intermediate
+            } else {
+                ArgType::Scalar(ty)
+            }
+        };
+        Ok(CircuitArg { name, ty })
+    }
+}
+
+/// `[ty; len]`
+fn parse_array(input: ParseStream) -> Result<(Ident, LitInt)> {
+    let content;
+    bracketed!(content in input);
+    let ty = content.parse()?;
+    content.parse::<Token![;]>()?;
+    Ok((ty, content.parse()?))
+}
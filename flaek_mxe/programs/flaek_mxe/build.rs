@@ -0,0 +1,143 @@
+use sha2::{Digest, Sha256};
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+};
+
+// Generates OUT_DIR/circuit_hashes.rs: the artifact file name and SHA-256 of
+// every `#[instruction]` in encrypted-ixs. The program publishes circuits
+// under that same file name, so the pinned hash and source URL agree.
+// Also fails the build when encrypted-ixs and the program's `circuit!` lines
+// disagree on which circuits exist.
+fn main() {
+    let manifest_dir = PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap());
+    let workspace_dir = manifest_dir.join("..").join("..");
+    let build_dir = workspace_dir.join("build");
+    let circuits_src = workspace_dir
+        .join("encrypted-ixs")
+        .join("src")
+        .join("lib.rs");
+    let program_src = manifest_dir.join("src").join("lib.rs");
+    let net = env::var("CIRCUITS_NET")
+        .unwrap_or_else(|_| "devnet".to_string())
+        .to_lowercase();
+
+    println!("cargo:rerun-if-changed={}", circuits_src.display());
+    println!("cargo:rerun-if-changed={}", program_src.display());
+    println!("cargo:rerun-if-changed={}", build_dir.display());
+    println!("cargo:rerun-if-env-changed=CIRCUITS_NET");
+
+    let src = fs::read_to_string(&circuits_src).expect("encrypted-ixs/src/lib.rs not found");
+    let program = fs::read_to_string(&program_src).expect("src/lib.rs not found");
+    let circuits = instruction_names(&src);
+    check_circuit_set(&circuits, &circuit_specs(&program));
+
+    let mut table = String::new();
+    table.push_str("pub const PLACEHOLDER_HASH: [u8; 32] = [0; 32];\n\n");
+    table.push_str("pub const CIRCUIT_ARTIFACTS: &[(&str, &str, [u8; 32])] = &[\n");
+    for circuit in circuits {
+        let (file, hash) = match resolve_artifact(&build_dir, &circuit, &net) {
+            Some(path) => {
+                println!("cargo:rerun-if-changed={}", path.display());
+                let file = path.file_name().unwrap().to_string_lossy().into_owned();
+                (file, Sha256::digest(fs::read(&path).unwrap()).into())
+            }
+            None => {
+                println!(
+                    "cargo:warning=no build/{circuit}.arcis, {circuit} keeps a placeholder hash"
+                );
+                (format!("{circuit}.arcis"), [0u8; 32])
+            }
+        };
+        let bytes: Vec<String> = hash.iter().map(|b| b.to_string()).collect();
+        table.push_str(&format!(
+            "    (\"{}\", \"{}\", [{}]),\n",
+            circuit,
+            file,
+            bytes.join(", ")
+        ));
+    }
+    table.push_str("];\n");
+
+    let out = PathBuf::from(env::var("OUT_DIR").unwrap()).join("circuit_hashes.rs");
+    fs::write(out, table).unwrap();
+}
+
+/// Names of the functions marked `#[instruction]` in the circuits crate, at
+/// any depth of inline modules.
+fn instruction_names(src: &str) -> Vec<String> {
+    let file = syn::parse_file(src).expect("encrypted-ixs/src/lib.rs does not parse");
+    let mut names = Vec::new();
+    collect_instructions(&file.items, &mut names);
+    names
+}
+
+fn collect_instructions(items: &[syn::Item], names: &mut Vec<String>) {
+    for item in items {
+        match item {
+            syn::Item::Fn(f) if f.attrs.iter().any(|a| a.path().is_ident("instruction")) => {
+                names.push(f.sig.ident.to_string());
+            }
+            syn::Item::Mod(m) => {
+                if let Some((_, items)) = &m.content {
+                    collect_instructions(items, names);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Names of the circuits declared with `circuit!(...)` in the program's
+/// `#[circuits]` module, each of which gets a `COMP_DEF_OFFSET_*` constant and
+/// its instructions.
+fn circuit_specs(src: &str) -> Vec<String> {
+    let file = syn::parse_file(src).expect("src/lib.rs does not parse");
+    file.items
+        .iter()
+        .filter_map(|item| match item {
+            syn::Item::Mod(m) if m.attrs.iter().any(|a| a.path().is_ident("circuits")) => {
+                m.content.as_ref().map(|(_, items)| items)
+            }
+            _ => None,
+        })
+        .flatten()
+        .filter_map(|item| match item {
+            syn::Item::Macro(m) if m.mac.path.is_ident("circuit") => {
+                match m.mac.tokens.clone().into_iter().next() {
+                    Some(proc_macro2::TokenTree::Ident(name)) => Some(name.to_string()),
+                    _ => panic!("circuit! must start with the circuit's name"),
+                }
+            }
+            _ => None,
+        })
+        .collect()
+}
+
+fn check_circuit_set(circuits: &[String], specs: &[String]) {
+    let missing: Vec<&str> = circuits
+        .iter()
+        .filter(|c| !specs.contains(c))
+        .map(String::as_str)
+        .collect();
+    let unknown: Vec<&str> = specs
+        .iter()
+        .filter(|s| !circuits.contains(s))
+        .map(String::as_str)
+        .collect();
+    if !missing.is_empty() || !unknown.is_empty() {
+        panic!(
+            "encrypted-ixs and programs/flaek_mxe disagree on the circuit set\n  \
+             no circuit! line for: {missing:?}\n  \
+             circuit! line without an #[instruction]: {unknown:?}"
+        );
+    }
+}
+
+/// Prefers `<circuit>_<net>.arcis`, falling back to `<circuit>.arcis`.
+fn resolve_artifact(build_dir: &Path, circuit: &str, net: &str) -> Option<PathBuf> {
+    [format!("{circuit}_{net}.arcis"), format!("{circuit}.arcis")]
+        .into_iter()
+        .map(|name| build_dir.join(name))
+        .find(|path| path.exists())
+}
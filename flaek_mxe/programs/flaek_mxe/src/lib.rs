@@ -1,40 +1,46 @@
-use anchor_lang::prelude::*;
+use anchor_lang::{
+    prelude::*,
+    solana_program::hash::hashv,
+    system_program::{self, Transfer},
+};
+use anchor_spl::token::{self, Token, TokenAccount};
 use arcium_anchor::prelude::*;
 use arcium_anchor::traits::InitCompDefAccs;
 use arcium_client::idl::arcium::{
     cpi::{accounts::InitComputationDefinition, init_computation_definition},
-    types::{CircuitSource, ComputationDefinitionMeta, ComputationSignature, OffChainCircuitSource},
+    types::{
+        CallbackAccount, CircuitSource, ComputationDefinitionMeta, ComputationSignature,
+        OffChainCircuitSource,
+    },
 };
-
-// Circuit offsets
-const COMP_DEF_OFFSET_ADD: u32 = comp_def_offset("add");
-const COMP_DEF_OFFSET_SUBTRACT: u32 = comp_def_offset("subtract");
-const COMP_DEF_OFFSET_MULTIPLY: u32 = comp_def_offset("multiply");
-const COMP_DEF_OFFSET_DIVIDE: u32 = comp_def_offset("divide");
-const COMP_DEF_OFFSET_MODULO: u32 = comp_def_offset("modulo");
-const COMP_DEF_OFFSET_POWER: u32 = comp_def_offset("power");
-const COMP_DEF_OFFSET_ABS_DIFF: u32 = comp_def_offset("abs_diff");
-const COMP_DEF_OFFSET_GREATER_THAN: u32 = comp_def_offset("greater_than");
-const COMP_DEF_OFFSET_LESS_THAN: u32 = comp_def_offset("less_than");
-const COMP_DEF_OFFSET_EQUAL: u32 = comp_def_offset("equal");
-const COMP_DEF_OFFSET_GREATER_EQUAL: u32 = comp_def_offset("greater_equal");
-const COMP_DEF_OFFSET_LESS_EQUAL: u32 = comp_def_offset("less_equal");
-const COMP_DEF_OFFSET_IN_RANGE: u32 = comp_def_offset("in_range");
-const COMP_DEF_OFFSET_AND: u32 = comp_def_offset("and");
-const COMP_DEF_OFFSET_OR: u32 = comp_def_offset("or");
-const COMP_DEF_OFFSET_NOT: u32 = comp_def_offset("not");
-const COMP_DEF_OFFSET_XOR: u32 = comp_def_offset("xor");
-const COMP_DEF_OFFSET_IF_ELSE: u32 = comp_def_offset("if_else");
-const COMP_DEF_OFFSET_AVERAGE: u32 = comp_def_offset("average");
-const COMP_DEF_OFFSET_SUM: u32 = comp_def_offset("sum");
-const COMP_DEF_OFFSET_MIN: u32 = comp_def_offset("min");
-const COMP_DEF_OFFSET_MAX: u32 = comp_def_offset("max");
-const COMP_DEF_OFFSET_MEDIAN: u32 = comp_def_offset("median");
-const COMP_DEF_OFFSET_CREDIT_SCORE: u32 = comp_def_offset("credit_score");
-const COMP_DEF_OFFSET_HEALTH_RISK: u32 = comp_def_offset("health_risk");
-const COMP_DEF_OFFSET_VOTE_TALLY: u32 = comp_def_offset("vote_tally");
-const COMP_DEF_OFFSET_MEETS_THRESHOLD: u32 = comp_def_offset("meets_threshold");
-const COMP_DEF_OFFSET_WEIGHTED_AVERAGE: u32 = comp_def_offset("weighted_average");
+use flaek_mxe_macros::circuits;
+
+/// File name and SHA-256 of every compiled circuit, generated by build.rs
+/// from build/*.arcis.
+pub mod circuit_hashes {
+    include!(concat!(env!("OUT_DIR"), "/circuit_hashes.rs"));
+}
+
+/// The artifact build.rs pinned for `circuit`: its file name in build/ and
+/// its hash, possibly the placeholder.
+fn pinned_artifact(circuit: &str) -> (&'static str, [u8; 32]) {
+    circuit_hashes::CIRCUIT_ARTIFACTS
+        .iter()
+        .find(|(name, _, _)| *name == circuit)
+        .map(|(_, file, hash)| (*file, *hash))
+        .unwrap_or(("", circuit_hashes::PLACEHOLDER_HASH))
+}
+
+/// Looks up the pinned artifact for `circuit`, refusing the all-zero
+/// placeholder that build.rs writes when the `.arcis` file was missing.
+fn circuit_artifact(circuit: &str) -> Result<(&'static str, [u8; 32])> {
+    let (file, hash) = pinned_artifact(circuit);
+    require!(
+        hash != circuit_hashes::PLACEHOLDER_HASH,
+        ErrorCode::CircuitHashMissing
+    );
+    Ok((file, hash))
+}
 
 fn init_comp_def_zero<'info, T>(
     accs: &T,
@@ -78,4164 +84,1049 @@ where
     Ok(())
 }
 
-declare_id!("9VBDqM7RFkrE2Wth8vLAW7CNsxo36hSbjtFHG54D1BKP");
-
-#[arcium_program]
-pub mod flaek_mxe {
-    use super::*;
-
-    pub fn init_sign_pda(ctx: Context<InitSignPda>) -> Result<()> {
-        ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
-        Ok(())
-    }
-
-    pub fn init_add_comp_def(ctx: Context<InitAddCompDef>) -> Result<()> {
-        init_comp_def_zero(ctx.accounts, true, 0, Some(CircuitSource::OffChain(OffChainCircuitSource {
-            source: "https://brown-immense-amphibian-214.mypinata.cloud/ipfs/bafybeibt3652jfgchqftay6cfytbiibxqujxqm3vmvy4d2kgfzubboo3qq/devnet/add_devnet.arcis".to_string(),
-            hash: [0; 32],
-        })), None)?;
-        Ok(())
-    }
-
-    pub fn add(
-        ctx: Context<Add>,
-        computation_offset: u64,
-        ciphertext_0: [u8; 32],
-        ciphertext_1: [u8; 32],
-        pub_key: [u8; 32],
-        nonce: u128,
-    ) -> Result<()> {
-        ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
-        let args = vec![
-            Argument::ArcisPubkey(pub_key),
-            Argument::PlaintextU128(nonce),
-            Argument::EncryptedU64(ciphertext_0),
-            Argument::EncryptedU64(ciphertext_1),
-        ];
-
-        queue_computation(
-            ctx.accounts,
-            computation_offset,
-            args,
-            None,
-            vec![AddCallback::callback_ix(&[])],
-        )?;
-
-        Ok(())
-    }
-
-    #[arcium_callback(encrypted_ix = "add")]
-    pub fn add_callback(
-        ctx: Context<AddCallback>,
-        output: ComputationOutputs<AddOutput>,
-    ) -> Result<()> {
-        let o = match output {
-            ComputationOutputs::Success(AddOutput { field_0 }) => field_0,
-            _ => return Err(ErrorCode::AbortedComputation.into()),
-        };
-
-        emit!(AddEvent {
-            result: o.ciphertexts[0],
-            nonce: o.nonce.to_le_bytes(),
-        });
-        Ok(())
+pub const CONFIG_SEED: &[u8] = b"config";
+
+/// Collects queue fees: lamports in the PDA itself, SPL tokens in token
+/// accounts it owns. `init_config` funds it with the rent-exempt minimum so
+/// SOL fees smaller than that can land, and withdrawals never dip below it.
+pub const TREASURY_SEED: &[u8] = b"treasury";
+
+/// The queue accounts [`charge_fee`] pays from and into.
+struct FeeAccounts<'a, 'info> {
+    payer: &'a Signer<'info>,
+    treasury: &'a SystemAccount<'info>,
+    payer_fee_account: Option<&'a Account<'info, TokenAccount>>,
+    treasury_fee_account: Option<&'a Account<'info, TokenAccount>>,
+    token_program: Option<&'a Program<'info, Token>>,
+    system_program: &'a Program<'info, System>,
+}
+
+/// Moves the configured fee for `comp_def_offset`, if any, from the payer
+/// into the treasury, in lamports or in `config.fee_mint`.
+fn charge_fee(config: &Config, comp_def_offset: u32, accounts: FeeAccounts) -> Result<()> {
+    let amount = config.fee(comp_def_offset);
+    if amount == 0 {
+        return Ok(());
+    }
+    let Some(mint) = config.fee_mint else {
+        return system_program::transfer(
+            CpiContext::new(
+                accounts.system_program.to_account_info(),
+                Transfer {
+                    from: accounts.payer.to_account_info(),
+                    to: accounts.treasury.to_account_info(),
+                },
+            ),
+            amount,
+        );
+    };
+    let (Some(from), Some(to), Some(token_program)) = (
+        accounts.payer_fee_account,
+        accounts.treasury_fee_account,
+        accounts.token_program,
+    ) else {
+        return err!(ErrorCode::MissingFeeAccounts);
+    };
+    require_keys_eq!(from.mint, mint, ErrorCode::InvalidFeeAccount);
+    require_keys_eq!(to.mint, mint, ErrorCode::InvalidFeeAccount);
+    require_keys_eq!(to.owner, accounts.treasury.key(), ErrorCode::InvalidFeeAccount);
+    token::transfer(
+        CpiContext::new(
+            token_program.to_account_info(),
+            token::Transfer {
+                from: from.to_account_info(),
+                to: to.to_account_info(),
+                authority: accounts.payer.to_account_info(),
+            },
+        ),
+        amount,
+    )
+}
+
+pub const CREDITS_SEED: &[u8] = b"credits";
+
+/// Debits the configured credit cost of `comp_def_offset` from the payer's
+/// credits and holds it pending until the callback lands. Returns it, for
+/// the callback to release.
+fn debit_credits(
+    config: &Config,
+    comp_def_offset: u32,
+    credits: Option<&mut Account<TenantCredits>>,
+) -> Result<u64> {
+    let cost = price(&config.credit_costs, comp_def_offset);
+    if cost == 0 {
+        return Ok(0);
+    }
+    let credits = credits.ok_or(ErrorCode::InsufficientCredits)?;
+    credits.balance = credits
+        .balance
+        .checked_sub(cost)
+        .ok_or(ErrorCode::InsufficientCredits)?;
+    credits.pending = credits
+        .pending
+        .checked_add(cost)
+        .ok_or(ErrorCode::CreditsOverflow)?;
+    Ok(cost)
+}
+
+/// Releases the credits `result` holds pending once its callback lands:
+/// spent on success, back into the balance when `refund`.
+fn release_credits(
+    result: &mut ComputationResult,
+    credits: Option<&mut Account<TenantCredits>>,
+    refund: bool,
+) -> Result<()> {
+    let Some(credits) = credits else {
+        return Ok(());
+    };
+    credits.pending = credits
+        .pending
+        .checked_sub(result.credits_debited)
+        .ok_or(ErrorCode::CreditsOverflow)?;
+    if refund {
+        credits.balance = credits
+            .balance
+            .checked_add(result.credits_debited)
+            .ok_or(ErrorCode::CreditsOverflow)?;
+        result.credits_debited = 0;
     }
+    Ok(())
+}
 
-    pub fn init_subtract_comp_def(ctx: Context<InitSubtractCompDef>) -> Result<()> {
-        init_comp_def_zero(ctx.accounts, true, 0, Some(CircuitSource::OffChain(OffChainCircuitSource {
-            source: "https://brown-immense-amphibian-214.mypinata.cloud/ipfs/bafybeibt3652jfgchqftay6cfytbiibxqujxqm3vmvy4d2kgfzubboo3qq/devnet/subtract_devnet.arcis".to_string(),
-            hash: [0; 32],
-        })), None)?;
-        Ok(())
+/// The credits account a callback refunds, or the program id standing in
+/// for an absent optional account.
+fn credits_callback_account(credits: Option<&Account<TenantCredits>>) -> CallbackAccount {
+    match credits {
+        Some(credits) => CallbackAccount {
+            pubkey: credits.key(),
+            is_writable: true,
+        },
+        None => CallbackAccount {
+            pubkey: ID,
+            is_writable: false,
+        },
     }
+}
 
-    pub fn subtract(
-        ctx: Context<Subtract>,
-        computation_offset: u64,
-        ciphertext_0: [u8; 32],
-        ciphertext_1: [u8; 32],
-        pub_key: [u8; 32],
-        nonce: u128,
-    ) -> Result<()> {
-        ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
-        let args = vec![
-            Argument::ArcisPubkey(pub_key),
-            Argument::PlaintextU128(nonce),
-            Argument::EncryptedU64(ciphertext_0),
-            Argument::EncryptedU64(ciphertext_1),
-        ];
-
-        queue_computation(
-            ctx.accounts,
-            computation_offset,
-            args,
-            None,
-            vec![SubtractCallback::callback_ix(&[])],
-        )?;
+pub const CIRCUIT_REGISTRY_SEED: &[u8] = b"circuit_registry";
 
-        Ok(())
-    }
+/// Records where `circuit` is served from and returns the matching source
+/// override. `None` leaves the circuit to be uploaded on-chain and finalized.
+/// `base_url` must serve the artifacts under their build/ file names.
+fn register_circuit(
+    registry: &mut Account<CircuitRegistry>,
+    bump: u8,
+    circuit: &str,
+    comp_def_offset: u32,
+    base_url: Option<String>,
+) -> Result<Option<CircuitSource>> {
+    let (file, hash) = circuit_artifact(circuit)?;
+    let source = match base_url {
+        Some(base_url) => format!("{}/{}", base_url.trim_end_matches('/'), file),
+        None => String::new(),
+    };
+    require!(
+        source.len() <= CircuitRegistry::MAX_SOURCE_LEN,
+        ErrorCode::CircuitSourceTooLong
+    );
 
-    #[arcium_callback(encrypted_ix = "subtract")]
-    pub fn subtract_callback(
-        ctx: Context<SubtractCallback>,
-        output: ComputationOutputs<SubtractOutput>,
-    ) -> Result<()> {
-        let o = match output {
-            ComputationOutputs::Success(SubtractOutput { field_0 }) => field_0,
-            _ => return Err(ErrorCode::AbortedComputation.into()),
-        };
+    registry.comp_def_offset = comp_def_offset;
+    registry.circuit = circuit.to_string();
+    registry.source = source.clone();
+    registry.hash = hash;
+    registry.bump = bump;
+
+    if source.is_empty() {
+        return Ok(None);
+    }
+    Ok(Some(CircuitSource::OffChain(OffChainCircuitSource { source, hash })))
+}
+
+pub const COMPUTATION_RESULT_SEED: &[u8] = b"computation_result";
+
+/// Stamps a newly created result account at queue time; the callback fills in
+/// the output later.
+fn open_computation_result(
+    result: &mut ComputationResult,
+    bump: u8,
+    computation_offset: u64,
+    payer: Pubkey,
+    comp_def_offset: u32,
+    credits_debited: u64,
+) {
+    result.computation_offset = computation_offset;
+    result.payer = payer;
+    result.comp_def_offset = comp_def_offset;
+    result.credits_debited = credits_debited;
+    result.status = ComputationStatus::Queued;
+    result.bump = bump;
+}
+
+/// Persists a callback's output so it survives a missed event log. Fails
+/// with `TooManyOutputs` if it does not fit the account.
+pub fn store_computation_result(
+    result: &mut ComputationResult,
+    ciphertexts: &[[u8; 32]],
+    nonce: u128,
+    recipient: Option<&SharedOutput>,
+    revealed: &[u64],
+) -> Result<()> {
+    require!(
+        ciphertexts.len() <= ComputationResult::MAX_CIPHERTEXTS
+            && recipient.map_or(0, |recipient| recipient.ciphertexts.len())
+                <= ComputationResult::MAX_CIPHERTEXTS
+            && revealed.len() <= ComputationResult::MAX_REVEALED,
+        ErrorCode::TooManyOutputs
+    );
+    result.status = ComputationStatus::Completed;
+    result.ciphertexts = ciphertexts.to_vec();
+    result.recipient = recipient.cloned();
+    result.revealed = revealed.to_vec();
+    result.nonce = nonce.to_le_bytes();
+    result.slot = Clock::get()?.slot;
+    Ok(())
+}
 
-        emit!(SubtractEvent {
-            result: o.ciphertexts[0],
-            nonce: o.nonce.to_le_bytes(),
-        });
-        Ok(())
-    }
+/// Records an aborted computation, refunds the credits it cost and tells
+/// listeners, so jobs fail fast instead of waiting out a timeout on an
+/// erroring callback.
+fn fail_computation(
+    result: &mut ComputationResult,
+    credits: Option<&mut Account<TenantCredits>>,
+    reason: FailureReason,
+) -> Result<()> {
+    result.status = ComputationStatus::Failed;
+    result.slot = Clock::get()?.slot;
+    release_credits(result, credits, true)?;
+
+    emit!(ComputationFailedEvent {
+        computation_offset: result.computation_offset,
+        payer: result.payer,
+        circuit: result.comp_def_offset,
+        reason,
+    });
+    Ok(())
+}
 
-    pub fn init_multiply_comp_def(ctx: Context<InitMultiplyCompDef>) -> Result<()> {
-        init_comp_def_zero(ctx.accounts, true, 0, Some(CircuitSource::OffChain(OffChainCircuitSource {
-            source: "https://brown-immense-amphibian-214.mypinata.cloud/ipfs/bafybeibt3652jfgchqftay6cfytbiibxqujxqm3vmvy4d2kgfzubboo3qq/devnet/multiply_devnet.arcis".to_string(),
-            hash: [0; 32],
-        })), None)?;
-        Ok(())
-    }
+pub const INTERMEDIATE_SEED: &[u8] = b"intermediate";
+
+/// Stamps the account a pipeline step will write its MXE-owned output to.
+fn open_intermediate(
+    intermediate: &mut Intermediate,
+    bump: u8,
+    computation_offset: u64,
+    payer: Pubkey,
+) {
+    intermediate.computation_offset = computation_offset;
+    intermediate.payer = payer;
+    intermediate.status = ComputationStatus::Queued;
+    intermediate.bump = bump;
+}
+
+fn store_intermediate(intermediate: &mut Intermediate, ciphertext: [u8; 32], nonce: u128) {
+    intermediate.ciphertext = ciphertext;
+    intermediate.nonce = nonce.to_le_bytes();
+    intermediate.status = ComputationStatus::Completed;
+}
+
+/// Passes an intermediate to a circuit as `Enc<Mxe, _>`: the nonce by value
+/// and the ciphertext read by the cluster straight from the account.
+fn intermediate_args(intermediate: &Account<Intermediate>) -> [Argument; 2] {
+    [
+        Argument::PlaintextU128(u128::from_le_bytes(intermediate.nonce)),
+        Argument::Account(
+            intermediate.key(),
+            Intermediate::CIPHERTEXT_OFFSET,
+            Intermediate::CIPHERTEXT_LEN,
+        ),
+    ]
+}
+
+pub const ATTESTATION_SEED: &[u8] = b"attestation";
+
+/// SHA-256 of the arguments a computation was queued with, each
+/// borsh-encoded and concatenated: its input ciphertexts, plus the keys,
+/// nonces and account references that go with them.
+pub fn inputs_hash(args: &[Argument]) -> Result<[u8; 32]> {
+    let mut data = Vec::new();
+    for arg in args {
+        arg.serialize(&mut data)?;
+    }
+    Ok(hashv(&[&data]).to_bytes())
+}
+
+/// SHA-256 of everything a callback hands back, concatenated: the payer's
+/// output ciphertexts and their nonce, then the recipient's ciphertexts,
+/// key and nonce if the circuit has one, then each revealed value as a
+/// little-endian u64.
+pub fn outputs_hash(
+    ciphertexts: &[[u8; 32]],
+    nonce: u128,
+    recipient: Option<&SharedOutput>,
+    revealed: &[u64],
+) -> [u8; 32] {
+    let mut parts: Vec<&[u8]> = ciphertexts.iter().map(|c| c.as_slice()).collect();
+    let nonce = nonce.to_le_bytes();
+    parts.push(&nonce);
+    if let Some(recipient) = recipient {
+        parts.extend(recipient.ciphertexts.iter().map(|c| c.as_slice()));
+        parts.push(&recipient.encryption_key);
+        parts.push(&recipient.nonce);
+    }
+    let revealed: Vec<[u8; 8]> = revealed.iter().map(|value| value.to_le_bytes()).collect();
+    parts.extend(revealed.iter().map(|value| value.as_slice()));
+    hashv(&parts).to_bytes()
+}
+
+/// Stamps what is known about a computation when it is queued, pinning the
+/// circuit hash its registry entry holds; the callback completes the
+/// attestation with its outputs.
+fn open_attestation(
+    attestation: &mut Attestation,
+    bump: u8,
+    computation_offset: u64,
+    registry: &CircuitRegistry,
+    cluster: Pubkey,
+    args: &[Argument],
+) -> Result<()> {
+    require!(
+        registry.hash != circuit_hashes::PLACEHOLDER_HASH,
+        ErrorCode::CircuitHashMissing
+    );
+    attestation.computation_offset = computation_offset;
+    attestation.comp_def_offset = registry.comp_def_offset;
+    attestation.circuit_hash = registry.hash;
+    attestation.cluster = cluster;
+    attestation.inputs_hash = inputs_hash(args)?;
+    attestation.bump = bump;
+    Ok(())
+}
 
-    pub fn multiply(
-        ctx: Context<Multiply>,
-        computation_offset: u64,
-        ciphertext_0: [u8; 32],
-        ciphertext_1: [u8; 32],
-        pub_key: [u8; 32],
-        nonce: u128,
-    ) -> Result<()> {
-        ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
-        let args = vec![
-            Argument::ArcisPubkey(pub_key),
-            Argument::PlaintextU128(nonce),
-            Argument::EncryptedU64(ciphertext_0),
-            Argument::EncryptedU64(ciphertext_1),
-        ];
+fn attest(
+    attestation: &mut Attestation,
+    ciphertexts: &[[u8; 32]],
+    nonce: u128,
+    recipient: Option<&SharedOutput>,
+    revealed: &[u64],
+) -> Result<()> {
+    attestation.outputs_hash = outputs_hash(ciphertexts, nonce, recipient, revealed);
+    attestation.slot = Clock::get()?.slot;
+    Ok(())
+}
 
-        queue_computation(
-            ctx.accounts,
-            computation_offset,
-            args,
-            None,
-            vec![MultiplyCallback::callback_ix(&[])],
-        )?;
+declare_id!("9VBDqM7RFkrE2Wth8vLAW7CNsxo36hSbjtFHG54D1BKP");
 
-        Ok(())
-    }
+// Each `circuit!` line expands to its init/queue/callback handlers, account
+// structs, event and COMP_DEF_OFFSET_* constant; see flaek-mxe-macros.
+#[circuits]
+#[arcium_program]
+pub mod flaek_mxe {
+    use super::*;
 
-    #[arcium_callback(encrypted_ix = "multiply")]
-    pub fn multiply_callback(
-        ctx: Context<MultiplyCallback>,
-        output: ComputationOutputs<MultiplyOutput>,
+    /// Makes the program's upgrade authority its admin. Runs once, so the
+    /// deployer cannot be front-run to it.
+    pub fn init_config(ctx: Context<InitConfig>) -> Result<()> {
+        let rent = Rent::get()?.minimum_balance(0);
+        let shortfall = rent.saturating_sub(ctx.accounts.treasury.lamports());
+        if shortfall > 0 {
+            system_program::transfer(
+                CpiContext::new(
+                    ctx.accounts.system_program.to_account_info(),
+                    Transfer {
+                        from: ctx.accounts.authority.to_account_info(),
+                        to: ctx.accounts.treasury.to_account_info(),
+                    },
+                ),
+                shortfall,
+            )?;
+        }
+        ctx.accounts.config.authority = ctx.accounts.authority.key();
+        ctx.accounts.config.gate = Gate::Open;
+        ctx.accounts.config.bump = ctx.bumps.config;
+        Ok(())
+    }
+
+    pub fn transfer_authority(
+        ctx: Context<TransferAuthority>,
+        new_authority: Pubkey,
     ) -> Result<()> {
-        let o = match output {
-            ComputationOutputs::Success(MultiplyOutput { field_0 }) => field_0,
-            _ => return Err(ErrorCode::AbortedComputation.into()),
-        };
-
-        emit!(MultiplyEvent {
-            result: o.ciphertexts[0],
-            nonce: o.nonce.to_le_bytes(),
-        });
+        ctx.accounts.config.authority = new_authority;
         Ok(())
     }
 
-    pub fn init_divide_comp_def(ctx: Context<InitDivideCompDef>) -> Result<()> {
-        init_comp_def_zero(ctx.accounts, true, 0, Some(CircuitSource::OffChain(OffChainCircuitSource {
-            source: "https://brown-immense-amphibian-214.mypinata.cloud/ipfs/bafybeibt3652jfgchqftay6cfytbiibxqujxqm3vmvy4d2kgfzubboo3qq/devnet/divide_devnet.arcis".to_string(),
-            hash: [0; 32],
-        })), None)?;
+    /// Chooses who may queue computations; see [`Gate`].
+    pub fn set_gate(ctx: Context<UpdateConfig>, gate: Gate) -> Result<()> {
+        ctx.accounts.config.gate = gate;
         Ok(())
     }
 
-    pub fn divide(
-        ctx: Context<Divide>,
-        computation_offset: u64,
-        ciphertext_0: [u8; 32],
-        ciphertext_1: [u8; 32],
-        pub_key: [u8; 32],
-        nonce: u128,
-    ) -> Result<()> {
-        ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
-        let args = vec![
-            Argument::ArcisPubkey(pub_key),
-            Argument::PlaintextU128(nonce),
-            Argument::EncryptedU64(ciphertext_0),
-            Argument::EncryptedU64(ciphertext_1),
-        ];
-
-        queue_computation(
-            ctx.accounts,
-            computation_offset,
-            args,
-            None,
-            vec![DivideCallback::callback_ix(&[])],
-        )?;
-
+    pub fn allow_payer(ctx: Context<UpdateConfig>, payer: Pubkey) -> Result<()> {
+        let allowed = &mut ctx.accounts.config.allowed_payers;
+        if !allowed.contains(&payer) {
+            require!(
+                allowed.len() < Config::MAX_ALLOWED_PAYERS,
+                ErrorCode::AllowListFull
+            );
+            allowed.push(payer);
+        }
         Ok(())
     }
 
-    #[arcium_callback(encrypted_ix = "divide")]
-    pub fn divide_callback(
-        ctx: Context<DivideCallback>,
-        output: ComputationOutputs<DivideOutput>,
-    ) -> Result<()> {
-        let o = match output {
-            ComputationOutputs::Success(DivideOutput { field_0 }) => field_0,
-            _ => return Err(ErrorCode::AbortedComputation.into()),
-        };
-
-        emit!(DivideEvent {
-            result: o.ciphertexts[0],
-            nonce: o.nonce.to_le_bytes(),
-        });
+    pub fn disallow_payer(ctx: Context<UpdateConfig>, payer: Pubkey) -> Result<()> {
+        ctx.accounts.config.allowed_payers.retain(|p| *p != payer);
         Ok(())
     }
 
-    pub fn init_modulo_comp_def(ctx: Context<InitModuloCompDef>) -> Result<()> {
-        init_comp_def_zero(ctx.accounts, true, 0, Some(CircuitSource::OffChain(OffChainCircuitSource {
-            source: "https://brown-immense-amphibian-214.mypinata.cloud/ipfs/bafybeibt3652jfgchqftay6cfytbiibxqujxqm3vmvy4d2kgfzubboo3qq/devnet/modulo_devnet.arcis".to_string(),
-            hash: [0; 32],
-        })), None)?;
-        Ok(())
+    /// Sets what queuing the circuit at `comp_def_offset` costs, in units
+    /// of the fee currency; zero makes it free.
+    pub fn set_fee(ctx: Context<UpdateConfig>, comp_def_offset: u32, amount: u64) -> Result<()> {
+        set_price(&mut ctx.accounts.config.fees, comp_def_offset, amount)
     }
 
-    pub fn modulo(
-        ctx: Context<Modulo>,
-        computation_offset: u64,
-        ciphertext_0: [u8; 32],
-        ciphertext_1: [u8; 32],
-        pub_key: [u8; 32],
-        nonce: u128,
+    /// Sets how many credits queuing the circuit at `comp_def_offset`
+    /// debits; zero lets payers without credits queue it.
+    pub fn set_credit_cost(
+        ctx: Context<UpdateConfig>,
+        comp_def_offset: u32,
+        amount: u64,
     ) -> Result<()> {
-        ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
-        let args = vec![
-            Argument::ArcisPubkey(pub_key),
-            Argument::PlaintextU128(nonce),
-            Argument::EncryptedU64(ciphertext_0),
-            Argument::EncryptedU64(ciphertext_1),
-        ];
-
-        queue_computation(
-            ctx.accounts,
-            computation_offset,
-            args,
-            None,
-            vec![ModuloCallback::callback_ix(&[])],
+        set_price(&mut ctx.accounts.config.credit_costs, comp_def_offset, amount)
+    }
+
+    /// Tops up the signer's credits by `amount` lamports.
+    pub fn deposit_credits(ctx: Context<DepositCredits>, amount: u64) -> Result<()> {
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.tenant.to_account_info(),
+                    to: ctx.accounts.credits.to_account_info(),
+                },
+            ),
+            amount,
         )?;
-
-        Ok(())
-    }
-
-    #[arcium_callback(encrypted_ix = "modulo")]
-    pub fn modulo_callback(
-        ctx: Context<ModuloCallback>,
-        output: ComputationOutputs<ModuloOutput>,
+        let credits = &mut ctx.accounts.credits;
+        credits.tenant = ctx.accounts.tenant.key();
+        credits.balance = credits
+            .balance
+            .checked_add(amount)
+            .ok_or(ErrorCode::CreditsOverflow)?;
+        credits.bump = ctx.bumps.credits;
+        Ok(())
+    }
+
+    /// Refunds `amount` of the signer's unspent credits in lamports.
+    pub fn withdraw_credits(ctx: Context<WithdrawCredits>, amount: u64) -> Result<()> {
+        let credits = &mut ctx.accounts.credits;
+        credits.balance = credits
+            .balance
+            .checked_sub(amount)
+            .ok_or(ErrorCode::InsufficientCredits)?;
+        **credits.to_account_info().try_borrow_mut_lamports()? -= amount;
+        **ctx.accounts.tenant.try_borrow_mut_lamports()? += amount;
+        Ok(())
+    }
+
+    /// Moves the lamports of spent credits into the treasury, leaving the
+    /// balance, credits still pending on a callback and rent behind.
+    pub fn sweep_credits(ctx: Context<SweepCredits>) -> Result<()> {
+        let credits = ctx.accounts.credits.to_account_info();
+        let reserved = Rent::get()?.minimum_balance(credits.data_len())
+            + ctx.accounts.credits.balance
+            + ctx.accounts.credits.pending;
+        let spent = credits.lamports().saturating_sub(reserved);
+        **credits.try_borrow_mut_lamports()? -= spent;
+        **ctx.accounts.treasury.try_borrow_mut_lamports()? += spent;
+        Ok(())
+    }
+
+    /// Charges fees in `mint`, or in lamports when `None`.
+    pub fn set_fee_mint(ctx: Context<UpdateConfig>, mint: Option<Pubkey>) -> Result<()> {
+        ctx.accounts.config.fee_mint = mint;
+        Ok(())
+    }
+
+    /// Moves `amount` out of the treasury: SPL tokens when both token
+    /// accounts are given, lamports to `recipient` otherwise, as long as the
+    /// treasury keeps its rent-exempt minimum.
+    pub fn withdraw_treasury(ctx: Context<WithdrawTreasury>, amount: u64) -> Result<()> {
+        let seeds: &[&[u8]] = &[TREASURY_SEED, &[ctx.bumps.treasury]];
+        let accounts = &ctx.accounts;
+        match (
+            &accounts.treasury_fee_account,
+            &accounts.recipient_fee_account,
+            &accounts.token_program,
+        ) {
+            (Some(from), Some(to), Some(token_program)) => token::transfer(
+                CpiContext::new_with_signer(
+                    token_program.to_account_info(),
+                    token::Transfer {
+                        from: from.to_account_info(),
+                        to: to.to_account_info(),
+                        authority: accounts.treasury.to_account_info(),
+                    },
+                    &[seeds],
+                ),
+                amount,
+            ),
+            (None, None, _) => {
+                let available = accounts
+                    .treasury
+                    .lamports()
+                    .saturating_sub(Rent::get()?.minimum_balance(0));
+                require!(amount <= available, ErrorCode::TreasuryBelowRent);
+                system_program::transfer(
+                    CpiContext::new_with_signer(
+                        accounts.system_program.to_account_info(),
+                        Transfer {
+                            from: accounts.treasury.to_account_info(),
+                            to: accounts.recipient.to_account_info(),
+                        },
+                        &[seeds],
+                    ),
+                    amount,
+                )
+            }
+            _ => err!(ErrorCode::MissingFeeAccounts),
+        }
+    }
+
+    /// Resyncs a circuit's registry entry with its comp def, creating it if
+    /// the comp def predates the registry. Off-chain sources carry their own
+    /// hash; on-chain ones keep the hash pinned when they were registered.
+    pub fn update_circuit_registry(
+        ctx: Context<UpdateCircuitRegistry>,
+        circuit: String,
     ) -> Result<()> {
-        let o = match output {
-            ComputationOutputs::Success(ModuloOutput { field_0 }) => field_0,
-            _ => return Err(ErrorCode::AbortedComputation.into()),
-        };
-
-        emit!(ModuloEvent {
-            result: o.ciphertexts[0],
-            nonce: o.nonce.to_le_bytes(),
-        });
-        Ok(())
-    }
-
-    pub fn init_abs_diff_comp_def(ctx: Context<InitAbsDiffCompDef>) -> Result<()> {
-        init_comp_def_zero(ctx.accounts, true, 0, Some(CircuitSource::OffChain(OffChainCircuitSource {
-            source: "https://brown-immense-amphibian-214.mypinata.cloud/ipfs/bafybeibt3652jfgchqftay6cfytbiibxqujxqm3vmvy4d2kgfzubboo3qq/devnet/abs_diff_devnet.arcis".to_string(),
-            hash: [0; 32],
-        })), None)?;
+        let registry = &mut ctx.accounts.circuit_registry;
+        match &ctx.accounts.comp_def_account.circuit_source {
+            CircuitSource::OffChain(OffChainCircuitSource { source, hash }) => {
+                require!(
+                    source.len() <= CircuitRegistry::MAX_SOURCE_LEN,
+                    ErrorCode::CircuitSourceTooLong
+                );
+                registry.source = source.clone();
+                registry.hash = *hash;
+            }
+            _ => {
+                require!(
+                    registry.hash != circuit_hashes::PLACEHOLDER_HASH,
+                    ErrorCode::CircuitHashMissing
+                );
+                registry.source = String::new();
+            }
+        }
+        registry.comp_def_offset = comp_def_offset(&circuit);
+        registry.circuit = circuit;
+        registry.bump = ctx.bumps.circuit_registry;
         Ok(())
     }
 
-    pub fn abs_diff(
-        ctx: Context<AbsDiff>,
-        computation_offset: u64,
-        ciphertext_0: [u8; 32],
-        ciphertext_1: [u8; 32],
-        pub_key: [u8; 32],
-        nonce: u128,
-    ) -> Result<()> {
+    pub fn init_sign_pda(ctx: Context<InitSignPda>) -> Result<()> {
         ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
-        let args = vec![
-            Argument::ArcisPubkey(pub_key),
-            Argument::PlaintextU128(nonce),
-            Argument::EncryptedU64(ciphertext_0),
-            Argument::EncryptedU64(ciphertext_1),
-        ];
-
-        queue_computation(
-            ctx.accounts,
-            computation_offset,
-            args,
-            None,
-            vec![AbsDiffCallback::callback_ix(&[])],
-        )?;
-
-        Ok(())
-    }
-
-    #[arcium_callback(encrypted_ix = "abs_diff")]
-    pub fn abs_diff_callback(
-        ctx: Context<AbsDiffCallback>,
-        output: ComputationOutputs<AbsDiffOutput>,
-    ) -> Result<()> {
-        let o = match output {
-            ComputationOutputs::Success(AbsDiffOutput { field_0 }) => field_0,
-            _ => return Err(ErrorCode::AbortedComputation.into()),
-        };
-
-        emit!(AbsDiffEvent {
-            result: o.ciphertexts[0],
-            nonce: o.nonce.to_le_bytes(),
-        });
         Ok(())
     }
 
-    pub fn init_power_comp_def(ctx: Context<InitPowerCompDef>) -> Result<()> {
-        init_comp_def_zero(ctx.accounts, true, 0, Some(CircuitSource::OffChain(OffChainCircuitSource {
-            source: "https://brown-immense-amphibian-214.mypinata.cloud/ipfs/bafybeibt3652jfgchqftay6cfytbiibxqujxqm3vmvy4d2kgfzubboo3qq/devnet/power_devnet.arcis".to_string(),
-            hash: [0; 32],
-        })), None)?;
-        Ok(())
+    /// Whether the computation at `computation_offset` completed under the
+    /// circuit with `circuit_hash` and produced the outputs hashing to
+    /// `outputs_hash`; see [`outputs_hash`].
+    pub fn verify_attestation(
+        ctx: Context<VerifyAttestation>,
+        _computation_offset: u64,
+        circuit_hash: [u8; 32],
+        outputs_hash: [u8; 32],
+    ) -> Result<bool> {
+        let attestation = &ctx.accounts.attestation;
+        Ok(attestation.slot != 0
+            && attestation.circuit_hash == circuit_hash
+            && attestation.outputs_hash == outputs_hash)
     }
 
-    pub fn power(
-        ctx: Context<Power>,
-        computation_offset: u64,
-        ciphertext_0: [u8; 32],
-        ciphertext_1: [u8; 32],
-        pub_key: [u8; 32],
-        nonce: u128,
+    pub fn close_computation_result(
+        _ctx: Context<CloseComputationResult>,
+        _computation_offset: u64,
     ) -> Result<()> {
-        ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
-        let args = vec![
-            Argument::ArcisPubkey(pub_key),
-            Argument::PlaintextU128(nonce),
-            Argument::EncryptedU64(ciphertext_0),
-            Argument::EncryptedU8(ciphertext_1),
-        ];
-
-        queue_computation(
-            ctx.accounts,
-            computation_offset,
-            args,
-            None,
-            vec![PowerCallback::callback_ix(&[])],
-        )?;
-
         Ok(())
     }
 
-    #[arcium_callback(encrypted_ix = "power")]
-    pub fn power_callback(
-        ctx: Context<PowerCallback>,
-        output: ComputationOutputs<PowerOutput>,
+    pub fn close_intermediate(
+        _ctx: Context<CloseIntermediate>,
+        _computation_offset: u64,
     ) -> Result<()> {
-        let o = match output {
-            ComputationOutputs::Success(PowerOutput { field_0 }) => field_0,
-            _ => return Err(ErrorCode::AbortedComputation.into()),
-        };
-
-        emit!(PowerEvent {
-            result: o.ciphertexts[0],
-            nonce: o.nonce.to_le_bytes(),
-        });
         Ok(())
     }
 
-    pub fn init_greater_than_comp_def(ctx: Context<InitGreaterThanCompDef>) -> Result<()> {
-        init_comp_def_zero(ctx.accounts, true, 0, Some(CircuitSource::OffChain(OffChainCircuitSource {
-            source: "https://brown-immense-amphibian-214.mypinata.cloud/ipfs/bafybeibt3652jfgchqftay6cfytbiibxqujxqm3vmvy4d2kgfzubboo3qq/devnet/greater_than_devnet.arcis".to_string(),
-            hash: [0; 32],
-        })), None)?;
-        Ok(())
-    }
-
-    pub fn greater_than(
-        ctx: Context<GreaterThan>,
-        computation_offset: u64,
-        ciphertext_0: [u8; 32],
-        ciphertext_1: [u8; 32],
-        pub_key: [u8; 32],
-        nonce: u128,
-    ) -> Result<()> {
-        ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
-        let args = vec![
-            Argument::ArcisPubkey(pub_key),
-            Argument::PlaintextU128(nonce),
-            Argument::EncryptedU64(ciphertext_0),
-            Argument::EncryptedU64(ciphertext_1),
-        ];
+    // math
+    circuit!(add(a: u64, b: u64) -> result);
+    circuit!(subtract(a: u64, b: u64) -> result);
+    circuit!(multiply(a: u64, b: u64) -> result);
+    circuit!(divide(a: u64, b: u64) -> result);
+    circuit!(modulo(a: u64, b: u64) -> result);
+    circuit!(abs_diff(a: u64, b: u64) -> result);
+    circuit!(power(base: u64, exponent: u8) -> result);
+    circuit!(checked_add(a: u64, b: u64) -> (result, overflow));
+    circuit!(checked_multiply(a: u64, b: u64) -> (result, overflow));
+    circuit!(checked_power(base: u64, exponent: u8) -> (result, overflow));
+    circuit!(add_i64(a: i64, b: i64) -> result);
+    circuit!(subtract_i64(a: i64, b: i64) -> result);
+    circuit!(multiply_i64(a: i64, b: i64) -> result);
+    circuit!(divide_i64(a: i64, b: i64) -> result);
+    circuit!(abs_diff_i64(a: i64, b: i64) -> result);
+
+    // comparison
+    circuit!(greater_than(a: u64, b: u64) -> result);
+    circuit!(less_than(a: u64, b: u64) -> result);
+    circuit!(equal(a: u64, b: u64) -> result);
+    circuit!(greater_equal(a: u64, b: u64) -> result);
+    circuit!(less_equal(a: u64, b: u64) -> result);
+    circuit!(meets_threshold(value: u64, threshold: u64, verifier: Shared) -> result + verifier(result));
+    circuit!(in_range(value: u64, min: u64, max: u64) -> result);
+    circuit!(compare_i64(a: i64, b: i64) -> result);
+
+    // logical
+    circuit!(and(a: u8, b: u8) -> result);
+    circuit!(or(a: u8, b: u8) -> result);
+    circuit!(xor(a: u8, b: u8) -> result);
+    circuit!(not(a: u8) -> result);
+    circuit!(if_else(condition: u8, true_value: u64, false_value: u64) -> result);
+    circuit!(vote_tally(votes: [u8; 10], count: u8, tallier: Shared) -> result + tallier(result) + reveal(result: u8));
+
+    // statistics
+    circuit!(average(values: [u64; 10], count: u8) -> result);
+    circuit!(sum(values: [u64; 10], count: u8) -> result);
+    circuit!(min(values: [u64; 10], count: u8) -> result);
+    circuit!(max(values: [u64; 10], count: u8) -> result);
+    circuit!(median(values: [u64; 10], count: u8) -> result);
+    circuit!(weighted_average(values: [u64; 5], weights: [u8; 5], recipient: Shared) -> result + recipient(result));
+
+    // use cases
+    circuit!(credit_score(income: u64, debt: u64, credit_history: u8, missed_payments: u8, lender: Shared) -> (score, approved) + lender(approved) + reveal(approved: u8));
+    circuit!(health_risk(age: u8, bmi: u8, smoker: u8, exercise_hours: u8, family_history: u8, insurer: Shared) -> (risk_score, risk_category) + insurer(risk_category));
+
+    // fixed point (Q32.32)
+    circuit!(fixed_add(a: i64, b: i64) -> result);
+    circuit!(fixed_sub(a: i64, b: i64) -> result);
+    circuit!(fixed_mul(a: i64, b: i64) -> result);
+    circuit!(fixed_div(a: i64, b: i64) -> result);
+    circuit!(fixed_round(value: i64) -> result);
+    circuit!(fixed_percent(value: i64, percent: i64) -> result);
+
+    // pipeline
+    circuit!(seal(value: u64) -> Intermediate);
+    circuit!(pipeline_step(op: plaintext u8, lhs: Intermediate, rhs: Intermediate) -> Intermediate);
+    circuit!(unseal(recipient: Shared, value: Intermediate) -> result);
+    circuit!(pipeline_eval(program: plaintext [u8; 64], registers: [u64; 8]) -> registers[8]);
+}
+
+#[derive(Accounts)]
+pub struct InitConfig<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + Config::INIT_SPACE,
+        seeds = [CONFIG_SEED],
+        bump,
+    )]
+    pub config: Account<'info, Config>,
+    #[account(mut, seeds = [TREASURY_SEED], bump)]
+    pub treasury: SystemAccount<'info>,
+    #[account(constraint = program.programdata_address()? == Some(program_data.key()))]
+    pub program: Program<'info, crate::program::FlaekMxe>,
+    #[account(
+        constraint = program_data.upgrade_authority_address == Some(authority.key())
+            @ ErrorCode::NotAuthority,
+    )]
+    pub program_data: Account<'info, ProgramData>,
+    pub system_program: Program<'info, System>,
+}
 
-        queue_computation(
-            ctx.accounts,
-            computation_offset,
-            args,
-            None,
-            vec![GreaterThanCallback::callback_ix(&[])],
-        )?;
+#[derive(Accounts)]
+pub struct UpdateConfig<'info> {
+    pub authority: Signer<'info>,
+    #[account(
+        mut,
+        has_one = authority @ ErrorCode::NotAuthority,
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, Config>,
+}
 
-        Ok(())
-    }
+#[derive(Accounts)]
+pub struct WithdrawTreasury<'info> {
+    pub authority: Signer<'info>,
+    #[account(
+        has_one = authority @ ErrorCode::NotAuthority,
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, Config>,
+    #[account(mut, seeds = [TREASURY_SEED], bump)]
+    pub treasury: SystemAccount<'info>,
+    /// CHECK: only receives lamports.
+    #[account(mut)]
+    pub recipient: UncheckedAccount<'info>,
+    #[account(mut, token::authority = treasury)]
+    pub treasury_fee_account: Option<Account<'info, TokenAccount>>,
+    #[account(mut)]
+    pub recipient_fee_account: Option<Account<'info, TokenAccount>>,
+    pub token_program: Option<Program<'info, Token>>,
+    pub system_program: Program<'info, System>,
+}
 
-    #[arcium_callback(encrypted_ix = "greater_than")]
-    pub fn greater_than_callback(
-        ctx: Context<GreaterThanCallback>,
-        output: ComputationOutputs<GreaterThanOutput>,
-    ) -> Result<()> {
-        let o = match output {
-            ComputationOutputs::Success(GreaterThanOutput { field_0 }) => field_0,
-            _ => return Err(ErrorCode::AbortedComputation.into()),
-        };
+#[derive(Accounts)]
+#[instruction(circuit: String)]
+pub struct UpdateCircuitRegistry<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(
+        has_one = authority @ ErrorCode::NotAuthority,
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, Config>,
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + CircuitRegistry::INIT_SPACE,
+        seeds = [CIRCUIT_REGISTRY_SEED, &comp_def_offset(&circuit).to_le_bytes()],
+        bump,
+    )]
+    pub circuit_registry: Account<'info, CircuitRegistry>,
+    #[account(address = derive_comp_def_pda!(comp_def_offset(&circuit)))]
+    pub comp_def_account: Box<Account<'info, ComputationDefinitionAccount>>,
+    pub system_program: Program<'info, System>,
+}
 
-        emit!(GreaterThanEvent {
-            result: o.ciphertexts[0],
-            nonce: o.nonce.to_le_bytes(),
-        });
-        Ok(())
-    }
+#[derive(Accounts)]
+#[instruction(computation_offset: u64)]
+pub struct VerifyAttestation<'info> {
+    #[account(
+        seeds = [ATTESTATION_SEED, &computation_offset.to_le_bytes()],
+        bump = attestation.bump,
+    )]
+    pub attestation: Account<'info, Attestation>,
+}
 
-    pub fn init_less_than_comp_def(ctx: Context<InitLessThanCompDef>) -> Result<()> {
-        init_comp_def_zero(ctx.accounts, true, 0, Some(CircuitSource::OffChain(OffChainCircuitSource {
-            source: "https://brown-immense-amphibian-214.mypinata.cloud/ipfs/bafybeibt3652jfgchqftay6cfytbiibxqujxqm3vmvy4d2kgfzubboo3qq/devnet/less_than_devnet.arcis".to_string(),
-            hash: [0; 32],
-        })), None)?;
-        Ok(())
-    }
+#[derive(Accounts)]
+pub struct DepositCredits<'info> {
+    #[account(mut)]
+    pub tenant: Signer<'info>,
+    #[account(
+        init_if_needed,
+        payer = tenant,
+        space = 8 + TenantCredits::INIT_SPACE,
+        seeds = [CREDITS_SEED, tenant.key().as_ref()],
+        bump,
+    )]
+    pub credits: Account<'info, TenantCredits>,
+    pub system_program: Program<'info, System>,
+}
 
-    pub fn less_than(
-        ctx: Context<LessThan>,
-        computation_offset: u64,
-        ciphertext_0: [u8; 32],
-        ciphertext_1: [u8; 32],
-        pub_key: [u8; 32],
-        nonce: u128,
-    ) -> Result<()> {
-        ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
-        let args = vec![
-            Argument::ArcisPubkey(pub_key),
-            Argument::PlaintextU128(nonce),
-            Argument::EncryptedU64(ciphertext_0),
-            Argument::EncryptedU64(ciphertext_1),
-        ];
+#[derive(Accounts)]
+pub struct WithdrawCredits<'info> {
+    #[account(mut)]
+    pub tenant: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [CREDITS_SEED, tenant.key().as_ref()],
+        bump = credits.bump,
+    )]
+    pub credits: Account<'info, TenantCredits>,
+}
 
-        queue_computation(
-            ctx.accounts,
-            computation_offset,
-            args,
-            None,
-            vec![LessThanCallback::callback_ix(&[])],
-        )?;
+#[derive(Accounts)]
+pub struct SweepCredits<'info> {
+    pub authority: Signer<'info>,
+    #[account(
+        has_one = authority @ ErrorCode::NotAuthority,
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, Config>,
+    #[account(
+        mut,
+        seeds = [CREDITS_SEED, credits.tenant.as_ref()],
+        bump = credits.bump,
+    )]
+    pub credits: Account<'info, TenantCredits>,
+    #[account(mut, seeds = [TREASURY_SEED], bump)]
+    pub treasury: SystemAccount<'info>,
+}
 
-        Ok(())
-    }
+#[derive(Accounts)]
+pub struct TransferAuthority<'info> {
+    pub authority: Signer<'info>,
+    #[account(
+        mut,
+        has_one = authority @ ErrorCode::NotAuthority,
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, Config>,
+}
 
-    #[arcium_callback(encrypted_ix = "less_than")]
-    pub fn less_than_callback(
-        ctx: Context<LessThanCallback>,
-        output: ComputationOutputs<LessThanOutput>,
-    ) -> Result<()> {
-        let o = match output {
-            ComputationOutputs::Success(LessThanOutput { field_0 }) => field_0,
-            _ => return Err(ErrorCode::AbortedComputation.into()),
-        };
+#[derive(Accounts)]
+pub struct InitSignPda<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        init_if_needed,
+        space = 9,
+        payer = payer,
+        seeds = [&SIGN_PDA_SEED],
+        bump,
+        address = derive_sign_pda!(),
+    )]
+    pub sign_pda_account: Account<'info, SignerAccount>,
+    pub system_program: Program<'info, System>,
+}
 
-        emit!(LessThanEvent {
-            result: o.ciphertexts[0],
-            nonce: o.nonce.to_le_bytes(),
-        });
-        Ok(())
-    }
+#[derive(Accounts)]
+#[instruction(computation_offset: u64)]
+pub struct CloseComputationResult<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        mut,
+        close = payer,
+        has_one = payer,
+        seeds = [COMPUTATION_RESULT_SEED, &computation_offset.to_le_bytes()],
+        bump = computation_result.bump,
+        constraint = computation_result.status != ComputationStatus::Queued @ ErrorCode::ComputationPending,
+    )]
+    pub computation_result: Account<'info, ComputationResult>,
+}
 
-    pub fn init_equal_comp_def(ctx: Context<InitEqualCompDef>) -> Result<()> {
-        init_comp_def_zero(ctx.accounts, true, 0, Some(CircuitSource::OffChain(OffChainCircuitSource {
-            source: "https://brown-immense-amphibian-214.mypinata.cloud/ipfs/bafybeibt3652jfgchqftay6cfytbiibxqujxqm3vmvy4d2kgfzubboo3qq/devnet/equal_devnet.arcis".to_string(),
-            hash: [0; 32],
-        })), None)?;
-        Ok(())
-    }
+#[derive(Accounts)]
+#[instruction(computation_offset: u64)]
+pub struct CloseIntermediate<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        mut,
+        close = payer,
+        has_one = payer,
+        seeds = [INTERMEDIATE_SEED, &computation_offset.to_le_bytes()],
+        bump = intermediate.bump,
+        constraint = intermediate.status != ComputationStatus::Queued @ ErrorCode::ComputationPending,
+    )]
+    pub intermediate: Account<'info, Intermediate>,
+}
 
-    pub fn equal(
-        ctx: Context<Equal>,
-        computation_offset: u64,
-        ciphertext_0: [u8; 32],
-        ciphertext_1: [u8; 32],
-        pub_key: [u8; 32],
-        nonce: u128,
-    ) -> Result<()> {
-        ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
-        let args = vec![
-            Argument::ArcisPubkey(pub_key),
-            Argument::PlaintextU128(nonce),
-            Argument::EncryptedU64(ciphertext_0),
-            Argument::EncryptedU64(ciphertext_1),
-        ];
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub enum ComputationStatus {
+    Queued,
+    Completed,
+    Failed,
+}
 
-        queue_computation(
-            ctx.accounts,
-            computation_offset,
-            args,
-            None,
-            vec![EqualCallback::callback_ix(&[])],
-        )?;
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum FailureReason {
+    /// The cluster returned no output for the computation.
+    Aborted,
+}
 
-        Ok(())
-    }
+/// Ciphertexts a circuit encrypted to a `Shared` key, with that key and the
+/// nonce they decrypt under.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug, InitSpace)]
+pub struct SharedOutput {
+    #[max_len(8)]
+    pub ciphertexts: Vec<[u8; 32]>,
+    pub encryption_key: [u8; 32],
+    pub nonce: [u8; 16],
+}
 
-    #[arcium_callback(encrypted_ix = "equal")]
-    pub fn equal_callback(
-        ctx: Context<EqualCallback>,
-        output: ComputationOutputs<EqualOutput>,
-    ) -> Result<()> {
-        let o = match output {
-            ComputationOutputs::Success(EqualOutput { field_0 }) => field_0,
-            _ => return Err(ErrorCode::AbortedComputation.into()),
-        };
+/// Output of one queued computation, written by its callback so a worker can
+/// poll for it instead of relying on catching the event.
+#[account]
+#[derive(InitSpace)]
+pub struct ComputationResult {
+    pub computation_offset: u64,
+    pub payer: Pubkey,
+    pub comp_def_offset: u32,
+    pub status: ComputationStatus,
+    #[max_len(8)]
+    pub ciphertexts: Vec<[u8; 32]>,
+    pub nonce: [u8; 16],
+    /// The output encrypted to the circuit's `Shared` recipient, if any.
+    pub recipient: Option<SharedOutput>,
+    /// Outputs the circuit revealed in the clear, in circuit order and
+    /// widened to u64, for other programs to read without decrypting.
+    #[max_len(4)]
+    pub revealed: Vec<u64>,
+    /// Slot the callback landed in; zero while queued.
+    pub slot: u64,
+    /// Credits the queue instruction debited; refunded on failure.
+    pub credits_debited: u64,
+    pub bump: u8,
+}
+
+impl ComputationResult {
+    pub const MAX_CIPHERTEXTS: usize = 8;
+    pub const MAX_REVEALED: usize = 4;
+}
+
+/// Which circuit, on which cluster, turned which inputs into which outputs.
+/// Opened at queue time and completed by a successful callback; unlike the
+/// `ComputationResult`, it is never closed.
+#[account]
+#[derive(InitSpace)]
+pub struct Attestation {
+    pub computation_offset: u64,
+    pub comp_def_offset: u32,
+    /// SHA-256 of the `.arcis` circuit, as pinned in its `CircuitRegistry`.
+    pub circuit_hash: [u8; 32],
+    pub cluster: Pubkey,
+    /// See [`inputs_hash`].
+    pub inputs_hash: [u8; 32],
+    /// See [`outputs_hash`]; zero until the callback lands.
+    pub outputs_hash: [u8; 32],
+    /// Slot the callback landed in; zero while queued or if it failed.
+    pub slot: u64,
+    pub bump: u8,
+}
 
-        emit!(EqualEvent {
-            result: o.ciphertexts[0],
-            nonce: o.nonce.to_le_bytes(),
+#[event]
+pub struct ComputationFailedEvent {
+    pub computation_offset: u64,
+    pub payer: Pubkey,
+    /// `comp_def_offset` of the circuit that failed.
+    pub circuit: u32,
+    pub reason: FailureReason,
+}
+
+/// A value encrypted to the MXE, produced by one pipeline step and consumed
+/// by later ones without any client seeing it. `unseal` hands it back out.
+#[account]
+#[derive(InitSpace)]
+pub struct Intermediate {
+    /// Kept first so circuits can read it in place; see `CIPHERTEXT_OFFSET`.
+    pub ciphertext: [u8; 32],
+    pub nonce: [u8; 16],
+    pub computation_offset: u64,
+    pub payer: Pubkey,
+    pub status: ComputationStatus,
+    pub bump: u8,
+}
+
+impl Intermediate {
+    /// Position of `ciphertext` in the account data, past the discriminator.
+    pub const CIPHERTEXT_OFFSET: u32 = 8;
+    pub const CIPHERTEXT_LEN: u32 = 32;
+}
+
+/// Program-wide settings. `authority` alone may initialize computation
+/// definitions and is their finalize authority.
+#[account]
+#[derive(InitSpace)]
+pub struct Config {
+    pub authority: Pubkey,
+    pub gate: Gate,
+    /// Payers admitted under [`Gate::AllowList`].
+    #[max_len(32)]
+    pub allowed_payers: Vec<Pubkey>,
+    /// Currency of `fees`; lamports when `None`.
+    pub fee_mint: Option<Pubkey>,
+    /// Circuits that cost something to queue.
+    #[max_len(64)]
+    pub fees: Vec<CircuitFee>,
+    /// Circuits that debit the payer's `TenantCredits` when queued.
+    #[max_len(64)]
+    pub credit_costs: Vec<CircuitFee>,
+    pub bump: u8,
+}
+
+impl Config {
+    pub const MAX_ALLOWED_PAYERS: usize = 32;
+    pub const MAX_FEES: usize = 64;
+
+    /// What queuing the circuit at `comp_def_offset` costs.
+    pub fn fee(&self, comp_def_offset: u32) -> u64 {
+        price(&self.fees, comp_def_offset)
+    }
+
+    /// Whether `payer` may queue a computation, given the co-signer that
+    /// signed alongside it, if any.
+    pub fn admits(&self, payer: &Pubkey, co_signer: Option<&Pubkey>) -> bool {
+        match &self.gate {
+            Gate::Open => true,
+            Gate::AllowList => self.allowed_payers.contains(payer),
+            Gate::CoSigner(key) => co_signer == Some(key),
+        }
+    }
+}
+
+/// A per-circuit price, in fees or in credits.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub struct CircuitFee {
+    pub comp_def_offset: u32,
+    pub amount: u64,
+}
+
+fn price(table: &[CircuitFee], comp_def_offset: u32) -> u64 {
+    table
+        .iter()
+        .find(|fee| fee.comp_def_offset == comp_def_offset)
+        .map_or(0, |fee| fee.amount)
+}
+
+fn set_price(table: &mut Vec<CircuitFee>, comp_def_offset: u32, amount: u64) -> Result<()> {
+    table.retain(|fee| fee.comp_def_offset != comp_def_offset);
+    if amount > 0 {
+        require!(table.len() < Config::MAX_FEES, ErrorCode::FeeTableFull);
+        table.push(CircuitFee {
+            comp_def_offset,
+            amount,
         });
-        Ok(())
-    }
-
-    pub fn init_greater_equal_comp_def(ctx: Context<InitGreaterEqualCompDef>) -> Result<()> {
-        init_comp_def_zero(ctx.accounts, true, 0, Some(CircuitSource::OffChain(OffChainCircuitSource {
-            source: "https://brown-immense-amphibian-214.mypinata.cloud/ipfs/bafybeibt3652jfgchqftay6cfytbiibxqujxqm3vmvy4d2kgfzubboo3qq/devnet/greater_equal_devnet.arcis".to_string(),
-            hash: [0; 32],
-        })), None)?;
-        Ok(())
-    }
-
-    pub fn greater_equal(
-        ctx: Context<GreaterEqual>,
-        computation_offset: u64,
-        ciphertext_0: [u8; 32],
-        ciphertext_1: [u8; 32],
-        pub_key: [u8; 32],
-        nonce: u128,
-    ) -> Result<()> {
-        ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
-        let args = vec![
-            Argument::ArcisPubkey(pub_key),
-            Argument::PlaintextU128(nonce),
-            Argument::EncryptedU64(ciphertext_0),
-            Argument::EncryptedU64(ciphertext_1),
-        ];
-
-        queue_computation(
-            ctx.accounts,
-            computation_offset,
-            args,
-            None,
-            vec![GreaterEqualCallback::callback_ix(&[])],
-        )?;
-
-        Ok(())
     }
+    Ok(())
+}
 
-    #[arcium_callback(encrypted_ix = "greater_equal")]
-    pub fn greater_equal_callback(
-        ctx: Context<GreaterEqualCallback>,
-        output: ComputationOutputs<GreaterEqualOutput>,
-    ) -> Result<()> {
-        let o = match output {
-            ComputationOutputs::Success(GreaterEqualOutput { field_0 }) => field_0,
-            _ => return Err(ErrorCode::AbortedComputation.into()),
-        };
-
-        emit!(GreaterEqualEvent {
-            result: o.ciphertexts[0],
-            nonce: o.nonce.to_le_bytes(),
-        });
-        Ok(())
-    }
-
-    pub fn init_less_equal_comp_def(ctx: Context<InitLessEqualCompDef>) -> Result<()> {
-        init_comp_def_zero(ctx.accounts, true, 0, Some(CircuitSource::OffChain(OffChainCircuitSource {
-            source: "https://brown-immense-amphibian-214.mypinata.cloud/ipfs/bafybeibt3652jfgchqftay6cfytbiibxqujxqm3vmvy4d2kgfzubboo3qq/devnet/less_equal_devnet.arcis".to_string(),
-            hash: [0; 32],
-        })), None)?;
-        Ok(())
-    }
-
-    pub fn less_equal(
-        ctx: Context<LessEqual>,
-        computation_offset: u64,
-        ciphertext_0: [u8; 32],
-        ciphertext_1: [u8; 32],
-        pub_key: [u8; 32],
-        nonce: u128,
-    ) -> Result<()> {
-        ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
-        let args = vec![
-            Argument::ArcisPubkey(pub_key),
-            Argument::PlaintextU128(nonce),
-            Argument::EncryptedU64(ciphertext_0),
-            Argument::EncryptedU64(ciphertext_1),
-        ];
-
-        queue_computation(
-            ctx.accounts,
-            computation_offset,
-            args,
-            None,
-            vec![LessEqualCallback::callback_ix(&[])],
-        )?;
-
-        Ok(())
-    }
-
-    #[arcium_callback(encrypted_ix = "less_equal")]
-    pub fn less_equal_callback(
-        ctx: Context<LessEqualCallback>,
-        output: ComputationOutputs<LessEqualOutput>,
-    ) -> Result<()> {
-        let o = match output {
-            ComputationOutputs::Success(LessEqualOutput { field_0 }) => field_0,
-            _ => return Err(ErrorCode::AbortedComputation.into()),
-        };
-
-        emit!(LessEqualEvent {
-            result: o.ciphertexts[0],
-            nonce: o.nonce.to_le_bytes(),
-        });
-        Ok(())
-    }
-
-    pub fn init_meets_threshold_comp_def(ctx: Context<InitMeetsThresholdCompDef>) -> Result<()> {
-        init_comp_def_zero(ctx.accounts, true, 0, Some(CircuitSource::OffChain(OffChainCircuitSource {
-            source: "https://brown-immense-amphibian-214.mypinata.cloud/ipfs/bafybeibt3652jfgchqftay6cfytbiibxqujxqm3vmvy4d2kgfzubboo3qq/devnet/meets_threshold_devnet.arcis".to_string(),
-            hash: [0; 32],
-        })), None)?;
-        Ok(())
-    }
-
-    pub fn meets_threshold(
-        ctx: Context<MeetsThreshold>,
-        computation_offset: u64,
-        ciphertext_0: [u8; 32],
-        ciphertext_1: [u8; 32],
-        pub_key: [u8; 32],
-        nonce: u128,
-    ) -> Result<()> {
-        ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
-        let args = vec![
-            Argument::ArcisPubkey(pub_key),
-            Argument::PlaintextU128(nonce),
-            Argument::EncryptedU64(ciphertext_0),
-            Argument::EncryptedU64(ciphertext_1),
-        ];
-
-        queue_computation(
-            ctx.accounts,
-            computation_offset,
-            args,
-            None,
-            vec![MeetsThresholdCallback::callback_ix(&[])],
-        )?;
-
-        Ok(())
-    }
-
-    #[arcium_callback(encrypted_ix = "meets_threshold")]
-    pub fn meets_threshold_callback(
-        ctx: Context<MeetsThresholdCallback>,
-        output: ComputationOutputs<MeetsThresholdOutput>,
-    ) -> Result<()> {
-        let o = match output {
-            ComputationOutputs::Success(MeetsThresholdOutput { field_0 }) => field_0,
-            _ => return Err(ErrorCode::AbortedComputation.into()),
-        };
-
-        emit!(MeetsThresholdEvent {
-            result: o.ciphertexts[0],
-            nonce: o.nonce.to_le_bytes(),
-        });
-        Ok(())
-    }
-
-    pub fn init_in_range_comp_def(ctx: Context<InitInRangeCompDef>) -> Result<()> {
-        init_comp_def_zero(ctx.accounts, true, 0, Some(CircuitSource::OffChain(OffChainCircuitSource {
-            source: "https://brown-immense-amphibian-214.mypinata.cloud/ipfs/bafybeibt3652jfgchqftay6cfytbiibxqujxqm3vmvy4d2kgfzubboo3qq/devnet/in_range_devnet.arcis".to_string(),
-            hash: [0; 32],
-        })), None)?;
-        Ok(())
-    }
-
-    pub fn in_range(
-        ctx: Context<InRange>,
-        computation_offset: u64,
-        ciphertext_0: [u8; 32],
-        ciphertext_1: [u8; 32],
-        ciphertext_2: [u8; 32],
-        pub_key: [u8; 32],
-        nonce: u128,
-    ) -> Result<()> {
-        ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
-        let args = vec![
-            Argument::ArcisPubkey(pub_key),
-            Argument::PlaintextU128(nonce),
-            Argument::EncryptedU64(ciphertext_0),
-            Argument::EncryptedU64(ciphertext_1),
-            Argument::EncryptedU64(ciphertext_2),
-        ];
-
-        queue_computation(
-            ctx.accounts,
-            computation_offset,
-            args,
-            None,
-            vec![InRangeCallback::callback_ix(&[])],
-        )?;
-
-        Ok(())
-    }
-
-    #[arcium_callback(encrypted_ix = "in_range")]
-    pub fn in_range_callback(
-        ctx: Context<InRangeCallback>,
-        output: ComputationOutputs<InRangeOutput>,
-    ) -> Result<()> {
-        let o = match output {
-            ComputationOutputs::Success(InRangeOutput { field_0 }) => field_0,
-            _ => return Err(ErrorCode::AbortedComputation.into()),
-        };
-
-        emit!(InRangeEvent {
-            result: o.ciphertexts[0],
-            nonce: o.nonce.to_le_bytes(),
-        });
-        Ok(())
-    }
-
-    pub fn init_and_comp_def(ctx: Context<InitAndCompDef>) -> Result<()> {
-        init_comp_def_zero(ctx.accounts, true, 0, Some(CircuitSource::OffChain(OffChainCircuitSource {
-            source: "https://brown-immense-amphibian-214.mypinata.cloud/ipfs/bafybeibt3652jfgchqftay6cfytbiibxqujxqm3vmvy4d2kgfzubboo3qq/devnet/and_devnet.arcis".to_string(),
-            hash: [0; 32],
-        })), None)?;
-        Ok(())
-    }
-
-    pub fn and(
-        ctx: Context<And>,
-        computation_offset: u64,
-        ciphertext_0: [u8; 32],
-        ciphertext_1: [u8; 32],
-        pub_key: [u8; 32],
-        nonce: u128,
-    ) -> Result<()> {
-        ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
-        let args = vec![
-            Argument::ArcisPubkey(pub_key),
-            Argument::PlaintextU128(nonce),
-            Argument::EncryptedU8(ciphertext_0),
-            Argument::EncryptedU8(ciphertext_1),
-        ];
-
-        queue_computation(
-            ctx.accounts,
-            computation_offset,
-            args,
-            None,
-            vec![AndCallback::callback_ix(&[])],
-        )?;
-
-        Ok(())
-    }
-
-    #[arcium_callback(encrypted_ix = "and")]
-    pub fn and_callback(
-        ctx: Context<AndCallback>,
-        output: ComputationOutputs<AndOutput>,
-    ) -> Result<()> {
-        let o = match output {
-            ComputationOutputs::Success(AndOutput { field_0 }) => field_0,
-            _ => return Err(ErrorCode::AbortedComputation.into()),
-        };
-
-        emit!(AndEvent {
-            result: o.ciphertexts[0],
-            nonce: o.nonce.to_le_bytes(),
-        });
-        Ok(())
-    }
-
-    pub fn init_or_comp_def(ctx: Context<InitOrCompDef>) -> Result<()> {
-        init_comp_def_zero(ctx.accounts, true, 0, Some(CircuitSource::OffChain(OffChainCircuitSource {
-            source: "https://brown-immense-amphibian-214.mypinata.cloud/ipfs/bafybeibt3652jfgchqftay6cfytbiibxqujxqm3vmvy4d2kgfzubboo3qq/devnet/or_devnet.arcis".to_string(),
-            hash: [0; 32],
-        })), None)?;
-        Ok(())
-    }
-
-    pub fn or(
-        ctx: Context<Or>,
-        computation_offset: u64,
-        ciphertext_0: [u8; 32],
-        ciphertext_1: [u8; 32],
-        pub_key: [u8; 32],
-        nonce: u128,
-    ) -> Result<()> {
-        ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
-        let args = vec![
-            Argument::ArcisPubkey(pub_key),
-            Argument::PlaintextU128(nonce),
-            Argument::EncryptedU8(ciphertext_0),
-            Argument::EncryptedU8(ciphertext_1),
-        ];
-
-        queue_computation(
-            ctx.accounts,
-            computation_offset,
-            args,
-            None,
-            vec![OrCallback::callback_ix(&[])],
-        )?;
-
-        Ok(())
-    }
-
-    #[arcium_callback(encrypted_ix = "or")]
-    pub fn or_callback(
-        ctx: Context<OrCallback>,
-        output: ComputationOutputs<OrOutput>,
-    ) -> Result<()> {
-        let o = match output {
-            ComputationOutputs::Success(OrOutput { field_0 }) => field_0,
-            _ => return Err(ErrorCode::AbortedComputation.into()),
-        };
-
-        emit!(OrEvent {
-            result: o.ciphertexts[0],
-            nonce: o.nonce.to_le_bytes(),
-        });
-        Ok(())
-    }
-
-    pub fn init_xor_comp_def(ctx: Context<InitXorCompDef>) -> Result<()> {
-        init_comp_def_zero(ctx.accounts, true, 0, Some(CircuitSource::OffChain(OffChainCircuitSource {
-            source: "https://brown-immense-amphibian-214.mypinata.cloud/ipfs/bafybeibt3652jfgchqftay6cfytbiibxqujxqm3vmvy4d2kgfzubboo3qq/devnet/xor_devnet.arcis".to_string(),
-            hash: [0; 32],
-        })), None)?;
-        Ok(())
-    }
-
-    pub fn xor(
-        ctx: Context<Xor>,
-        computation_offset: u64,
-        ciphertext_0: [u8; 32],
-        ciphertext_1: [u8; 32],
-        pub_key: [u8; 32],
-        nonce: u128,
-    ) -> Result<()> {
-        ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
-        let args = vec![
-            Argument::ArcisPubkey(pub_key),
-            Argument::PlaintextU128(nonce),
-            Argument::EncryptedU8(ciphertext_0),
-            Argument::EncryptedU8(ciphertext_1),
-        ];
-
-        queue_computation(
-            ctx.accounts,
-            computation_offset,
-            args,
-            None,
-            vec![XorCallback::callback_ix(&[])],
-        )?;
-
-        Ok(())
-    }
-
-    #[arcium_callback(encrypted_ix = "xor")]
-    pub fn xor_callback(
-        ctx: Context<XorCallback>,
-        output: ComputationOutputs<XorOutput>,
-    ) -> Result<()> {
-        let o = match output {
-            ComputationOutputs::Success(XorOutput { field_0 }) => field_0,
-            _ => return Err(ErrorCode::AbortedComputation.into()),
-        };
-
-        emit!(XorEvent {
-            result: o.ciphertexts[0],
-            nonce: o.nonce.to_le_bytes(),
-        });
-        Ok(())
-    }
-
-    pub fn init_not_comp_def(ctx: Context<InitNotCompDef>) -> Result<()> {
-        init_comp_def_zero(ctx.accounts, true, 0, Some(CircuitSource::OffChain(OffChainCircuitSource {
-            source: "https://brown-immense-amphibian-214.mypinata.cloud/ipfs/bafybeibt3652jfgchqftay6cfytbiibxqujxqm3vmvy4d2kgfzubboo3qq/devnet/not_devnet.arcis".to_string(),
-            hash: [0; 32],
-        })), None)?;
-        Ok(())
-    }
-
-    pub fn not(
-        ctx: Context<Not>,
-        computation_offset: u64,
-        ciphertext_0: [u8; 32],
-        pub_key: [u8; 32],
-        nonce: u128,
-    ) -> Result<()> {
-        ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
-        let args = vec![
-            Argument::ArcisPubkey(pub_key),
-            Argument::PlaintextU128(nonce),
-            Argument::EncryptedU8(ciphertext_0),
-        ];
-
-        queue_computation(
-            ctx.accounts,
-            computation_offset,
-            args,
-            None,
-            vec![NotCallback::callback_ix(&[])],
-        )?;
-
-        Ok(())
-    }
-
-    #[arcium_callback(encrypted_ix = "not")]
-    pub fn not_callback(
-        ctx: Context<NotCallback>,
-        output: ComputationOutputs<NotOutput>,
-    ) -> Result<()> {
-        let o = match output {
-            ComputationOutputs::Success(NotOutput { field_0 }) => field_0,
-            _ => return Err(ErrorCode::AbortedComputation.into()),
-        };
-
-        emit!(NotEvent {
-            result: o.ciphertexts[0],
-            nonce: o.nonce.to_le_bytes(),
-        });
-        Ok(())
-    }
-
-    pub fn init_if_else_comp_def(ctx: Context<InitIfElseCompDef>) -> Result<()> {
-        init_comp_def_zero(ctx.accounts, true, 0, Some(CircuitSource::OffChain(OffChainCircuitSource {
-            source: "https://brown-immense-amphibian-214.mypinata.cloud/ipfs/bafybeibt3652jfgchqftay6cfytbiibxqujxqm3vmvy4d2kgfzubboo3qq/devnet/if_else_devnet.arcis".to_string(),
-            hash: [0; 32],
-        })), None)?;
-        Ok(())
-    }
-
-    pub fn if_else(
-        ctx: Context<IfElse>,
-        computation_offset: u64,
-        ciphertext_0: [u8; 32],
-        ciphertext_1: [u8; 32],
-        ciphertext_2: [u8; 32],
-        pub_key: [u8; 32],
-        nonce: u128,
-    ) -> Result<()> {
-        ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
-        let args = vec![
-            Argument::ArcisPubkey(pub_key),
-            Argument::PlaintextU128(nonce),
-            Argument::EncryptedU8(ciphertext_0),
-            Argument::EncryptedU64(ciphertext_1),
-            Argument::EncryptedU64(ciphertext_2),
-        ];
-
-        queue_computation(
-            ctx.accounts,
-            computation_offset,
-            args,
-            None,
-            vec![IfElseCallback::callback_ix(&[])],
-        )?;
-
-        Ok(())
-    }
-
-    #[arcium_callback(encrypted_ix = "if_else")]
-    pub fn if_else_callback(
-        ctx: Context<IfElseCallback>,
-        output: ComputationOutputs<IfElseOutput>,
-    ) -> Result<()> {
-        let o = match output {
-            ComputationOutputs::Success(IfElseOutput { field_0 }) => field_0,
-            _ => return Err(ErrorCode::AbortedComputation.into()),
-        };
-
-        emit!(IfElseEvent {
-            result: o.ciphertexts[0],
-            nonce: o.nonce.to_le_bytes(),
-        });
-        Ok(())
-    }
-
-    pub fn init_vote_tally_comp_def(ctx: Context<InitVoteTallyCompDef>) -> Result<()> {
-        init_comp_def_zero(ctx.accounts, true, 0, Some(CircuitSource::OffChain(OffChainCircuitSource {
-            source: "https://brown-immense-amphibian-214.mypinata.cloud/ipfs/bafybeibt3652jfgchqftay6cfytbiibxqujxqm3vmvy4d2kgfzubboo3qq/devnet/vote_tally_devnet.arcis".to_string(),
-            hash: [0; 32],
-        })), None)?;
-        Ok(())
-    }
-
-    pub fn vote_tally(
-        ctx: Context<VoteTally>,
-        computation_offset: u64,
-        ciphertext_0: [u8; 32],
-        pub_key: [u8; 32],
-        nonce: u128,
-    ) -> Result<()> {
-        ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
-        let args = vec![
-            Argument::ArcisPubkey(pub_key),
-            Argument::PlaintextU128(nonce),
-            Argument::EncryptedU8(ciphertext_0),
-        ];
-
-        queue_computation(
-            ctx.accounts,
-            computation_offset,
-            args,
-            None,
-            vec![VoteTallyCallback::callback_ix(&[])],
-        )?;
-
-        Ok(())
-    }
-
-    #[arcium_callback(encrypted_ix = "vote_tally")]
-    pub fn vote_tally_callback(
-        ctx: Context<VoteTallyCallback>,
-        output: ComputationOutputs<VoteTallyOutput>,
-    ) -> Result<()> {
-        let o = match output {
-            ComputationOutputs::Success(VoteTallyOutput { field_0 }) => field_0,
-            _ => return Err(ErrorCode::AbortedComputation.into()),
-        };
-
-        emit!(VoteTallyEvent {
-            result: o.ciphertexts[0],
-            nonce: o.nonce.to_le_bytes(),
-        });
-        Ok(())
-    }
-
-    pub fn init_average_comp_def(ctx: Context<InitAverageCompDef>) -> Result<()> {
-        init_comp_def_zero(ctx.accounts, true, 0, Some(CircuitSource::OffChain(OffChainCircuitSource {
-            source: "https://brown-immense-amphibian-214.mypinata.cloud/ipfs/bafybeibt3652jfgchqftay6cfytbiibxqujxqm3vmvy4d2kgfzubboo3qq/devnet/average_devnet.arcis".to_string(),
-            hash: [0; 32],
-        })), None)?;
-        Ok(())
-    }
-
-    pub fn average(
-        ctx: Context<Average>,
-        computation_offset: u64,
-        values: [u8; 32],
-        count: [u8; 32],
-        pub_key: [u8; 32],
-        nonce: u128,
-    ) -> Result<()> {
-        ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
-        let args = vec![
-            Argument::ArcisPubkey(pub_key),
-            Argument::PlaintextU128(nonce),
-            Argument::EncryptedU64(values),
-            Argument::EncryptedU8(count),
-        ];
-
-        queue_computation(
-            ctx.accounts,
-            computation_offset,
-            args,
-            None,
-            vec![AverageCallback::callback_ix(&[])],
-        )?;
-
-        Ok(())
-    }
-
-    #[arcium_callback(encrypted_ix = "average")]
-    pub fn average_callback(
-        ctx: Context<AverageCallback>,
-        output: ComputationOutputs<AverageOutput>,
-    ) -> Result<()> {
-        let o = match output {
-            ComputationOutputs::Success(AverageOutput { field_0 }) => field_0,
-            _ => return Err(ErrorCode::AbortedComputation.into()),
-        };
-
-        emit!(AverageEvent {
-            result: o.ciphertexts[0],
-            nonce: o.nonce.to_le_bytes(),
-        });
-        Ok(())
-    }
-
-    pub fn init_sum_comp_def(ctx: Context<InitSumCompDef>) -> Result<()> {
-        init_comp_def_zero(ctx.accounts, true, 0, Some(CircuitSource::OffChain(OffChainCircuitSource {
-            source: "https://brown-immense-amphibian-214.mypinata.cloud/ipfs/bafybeibt3652jfgchqftay6cfytbiibxqujxqm3vmvy4d2kgfzubboo3qq/devnet/sum_devnet.arcis".to_string(),
-            hash: [0; 32],
-        })), None)?;
-        Ok(())
-    }
-
-    pub fn sum(
-        ctx: Context<Sum>,
-        computation_offset: u64,
-        values: [u8; 32],
-        count: [u8; 32],
-        pub_key: [u8; 32],
-        nonce: u128,
-    ) -> Result<()> {
-        ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
-        let args = vec![
-            Argument::ArcisPubkey(pub_key),
-            Argument::PlaintextU128(nonce),
-            Argument::EncryptedU64(values),
-            Argument::EncryptedU8(count),
-        ];
-
-        queue_computation(
-            ctx.accounts,
-            computation_offset,
-            args,
-            None,
-            vec![SumCallback::callback_ix(&[])],
-        )?;
-
-        Ok(())
-    }
-
-    #[arcium_callback(encrypted_ix = "sum")]
-    pub fn sum_callback(
-        ctx: Context<SumCallback>,
-        output: ComputationOutputs<SumOutput>,
-    ) -> Result<()> {
-        let o = match output {
-            ComputationOutputs::Success(SumOutput { field_0 }) => field_0,
-            _ => return Err(ErrorCode::AbortedComputation.into()),
-        };
-
-        emit!(SumEvent {
-            result: o.ciphertexts[0],
-            nonce: o.nonce.to_le_bytes(),
-        });
-        Ok(())
-    }
-
-    pub fn init_min_comp_def(ctx: Context<InitMinCompDef>) -> Result<()> {
-        init_comp_def_zero(ctx.accounts, true, 0, Some(CircuitSource::OffChain(OffChainCircuitSource {
-            source: "https://brown-immense-amphibian-214.mypinata.cloud/ipfs/bafybeibt3652jfgchqftay6cfytbiibxqujxqm3vmvy4d2kgfzubboo3qq/devnet/min_devnet.arcis".to_string(),
-            hash: [0; 32],
-        })), None)?;
-        Ok(())
-    }
-
-    pub fn min(
-        ctx: Context<Min>,
-        computation_offset: u64,
-        values: [u8; 32],
-        count: [u8; 32],
-        pub_key: [u8; 32],
-        nonce: u128,
-    ) -> Result<()> {
-        ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
-        let args = vec![
-            Argument::ArcisPubkey(pub_key),
-            Argument::PlaintextU128(nonce),
-            Argument::EncryptedU64(values),
-            Argument::EncryptedU8(count),
-        ];
-
-        queue_computation(
-            ctx.accounts,
-            computation_offset,
-            args,
-            None,
-            vec![MinCallback::callback_ix(&[])],
-        )?;
-
-        Ok(())
-    }
-
-    #[arcium_callback(encrypted_ix = "min")]
-    pub fn min_callback(
-        ctx: Context<MinCallback>,
-        output: ComputationOutputs<MinOutput>,
-    ) -> Result<()> {
-        let o = match output {
-            ComputationOutputs::Success(MinOutput { field_0 }) => field_0,
-            _ => return Err(ErrorCode::AbortedComputation.into()),
-        };
-
-        emit!(MinEvent {
-            result: o.ciphertexts[0],
-            nonce: o.nonce.to_le_bytes(),
-        });
-        Ok(())
-    }
-
-    pub fn init_max_comp_def(ctx: Context<InitMaxCompDef>) -> Result<()> {
-        init_comp_def_zero(ctx.accounts, true, 0, Some(CircuitSource::OffChain(OffChainCircuitSource {
-            source: "https://brown-immense-amphibian-214.mypinata.cloud/ipfs/bafybeibt3652jfgchqftay6cfytbiibxqujxqm3vmvy4d2kgfzubboo3qq/devnet/max_devnet.arcis".to_string(),
-            hash: [0; 32],
-        })), None)?;
-        Ok(())
-    }
-
-    pub fn max(
-        ctx: Context<Max>,
-        computation_offset: u64,
-        values: [u8; 32],
-        count: [u8; 32],
-        pub_key: [u8; 32],
-        nonce: u128,
-    ) -> Result<()> {
-        ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
-        let args = vec![
-            Argument::ArcisPubkey(pub_key),
-            Argument::PlaintextU128(nonce),
-            Argument::EncryptedU64(values),
-            Argument::EncryptedU8(count),
-        ];
-
-        queue_computation(
-            ctx.accounts,
-            computation_offset,
-            args,
-            None,
-            vec![MaxCallback::callback_ix(&[])],
-        )?;
-
-        Ok(())
-    }
-
-    #[arcium_callback(encrypted_ix = "max")]
-    pub fn max_callback(
-        ctx: Context<MaxCallback>,
-        output: ComputationOutputs<MaxOutput>,
-    ) -> Result<()> {
-        let o = match output {
-            ComputationOutputs::Success(MaxOutput { field_0 }) => field_0,
-            _ => return Err(ErrorCode::AbortedComputation.into()),
-        };
-
-        emit!(MaxEvent {
-            result: o.ciphertexts[0],
-            nonce: o.nonce.to_le_bytes(),
-        });
-        Ok(())
-    }
-
-    pub fn init_median_comp_def(ctx: Context<InitMedianCompDef>) -> Result<()> {
-        init_comp_def_zero(ctx.accounts, true, 0, Some(CircuitSource::OffChain(OffChainCircuitSource {
-            source: "https://brown-immense-amphibian-214.mypinata.cloud/ipfs/bafybeibt3652jfgchqftay6cfytbiibxqujxqm3vmvy4d2kgfzubboo3qq/devnet/median_devnet.arcis".to_string(),
-            hash: [0; 32],
-        })), None)?;
-        Ok(())
-    }
-
-    pub fn median(
-        ctx: Context<Median>,
-        computation_offset: u64,
-        values: [u8; 32],
-        count: [u8; 32],
-        pub_key: [u8; 32],
-        nonce: u128,
-    ) -> Result<()> {
-        ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
-        let args = vec![
-            Argument::ArcisPubkey(pub_key),
-            Argument::PlaintextU128(nonce),
-            Argument::EncryptedU64(values),
-            Argument::EncryptedU8(count),
-        ];
-
-        queue_computation(
-            ctx.accounts,
-            computation_offset,
-            args,
-            None,
-            vec![MedianCallback::callback_ix(&[])],
-        )?;
-
-        Ok(())
-    }
-
-    #[arcium_callback(encrypted_ix = "median")]
-    pub fn median_callback(
-        ctx: Context<MedianCallback>,
-        output: ComputationOutputs<MedianOutput>,
-    ) -> Result<()> {
-        let o = match output {
-            ComputationOutputs::Success(MedianOutput { field_0 }) => field_0,
-            _ => return Err(ErrorCode::AbortedComputation.into()),
-        };
-
-        emit!(MedianEvent {
-            result: o.ciphertexts[0],
-            nonce: o.nonce.to_le_bytes(),
-        });
-        Ok(())
-    }
-
-    pub fn init_weighted_average_comp_def(ctx: Context<InitWeightedAverageCompDef>) -> Result<()> {
-        init_comp_def_zero(ctx.accounts, true, 0, Some(CircuitSource::OffChain(OffChainCircuitSource {
-            source: "https://brown-immense-amphibian-214.mypinata.cloud/ipfs/bafybeibt3652jfgchqftay6cfytbiibxqujxqm3vmvy4d2kgfzubboo3qq/devnet/weighted_average_devnet.arcis".to_string(),
-            hash: [0; 32],
-        })), None)?;
-        Ok(())
-    }
-
-    pub fn weighted_average(
-        ctx: Context<WeightedAverage>,
-        computation_offset: u64,
-        values: [u8; 32],
-        count: [u8; 32],
-        pub_key: [u8; 32],
-        nonce: u128,
-    ) -> Result<()> {
-        ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
-        let args = vec![
-            Argument::ArcisPubkey(pub_key),
-            Argument::PlaintextU128(nonce),
-            Argument::EncryptedU64(values),
-            Argument::EncryptedU8(count),
-        ];
-
-        queue_computation(
-            ctx.accounts,
-            computation_offset,
-            args,
-            None,
-            vec![WeightedAverageCallback::callback_ix(&[])],
-        )?;
-
-        Ok(())
-    }
-
-    #[arcium_callback(encrypted_ix = "weighted_average")]
-    pub fn weighted_average_callback(
-        ctx: Context<WeightedAverageCallback>,
-        output: ComputationOutputs<WeightedAverageOutput>,
-    ) -> Result<()> {
-        let o = match output {
-            ComputationOutputs::Success(WeightedAverageOutput { field_0 }) => field_0,
-            _ => return Err(ErrorCode::AbortedComputation.into()),
-        };
-
-        emit!(WeightedAverageEvent {
-            result: o.ciphertexts[0],
-            nonce: o.nonce.to_le_bytes(),
-        });
-        Ok(())
-    }
-
-    pub fn init_credit_score_comp_def(ctx: Context<InitCreditScoreCompDef>) -> Result<()> {
-        init_comp_def_zero(ctx.accounts, true, 0, Some(CircuitSource::OffChain(OffChainCircuitSource {
-            source: "https://brown-immense-amphibian-214.mypinata.cloud/ipfs/bafybeibt3652jfgchqftay6cfytbiibxqujxqm3vmvy4d2kgfzubboo3qq/devnet/credit_score_devnet.arcis".to_string(),
-            hash: [0; 32],
-        })), None)?;
-        Ok(())
-    }
-
-    pub fn credit_score(
-        ctx: Context<CreditScore>,
-        computation_offset: u64,
-        ciphertext_0: [u8; 32],
-        ciphertext_1: [u8; 32],
-        ciphertext_2: [u8; 32],
-        ciphertext_3: [u8; 32],
-        pub_key: [u8; 32],
-        nonce: u128,
-    ) -> Result<()> {
-        ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
-        let args = vec![
-            Argument::ArcisPubkey(pub_key),
-            Argument::PlaintextU128(nonce),
-            Argument::EncryptedU64(ciphertext_0),
-            Argument::EncryptedU64(ciphertext_1),
-            Argument::EncryptedU8(ciphertext_2),
-            Argument::EncryptedU8(ciphertext_3),
-        ];
-
-        queue_computation(
-            ctx.accounts,
-            computation_offset,
-            args,
-            None,
-            vec![CreditScoreCallback::callback_ix(&[])],
-        )?;
-
-        Ok(())
-    }
-
-    #[arcium_callback(encrypted_ix = "credit_score")]
-    pub fn credit_score_callback(
-        ctx: Context<CreditScoreCallback>,
-        output: ComputationOutputs<CreditScoreOutput>,
-    ) -> Result<()> {
-        let o = match output {
-            ComputationOutputs::Success(CreditScoreOutput { field_0 }) => field_0,
-            _ => return Err(ErrorCode::AbortedComputation.into()),
-        };
-
-        emit!(CreditScoreEvent {
-            score: o.ciphertexts[0],
-            approved: o.ciphertexts[1],
-            nonce: o.nonce.to_le_bytes(),
-        });
-        Ok(())
-    }
-
-    pub fn init_health_risk_comp_def(ctx: Context<InitHealthRiskCompDef>) -> Result<()> {
-        init_comp_def_zero(ctx.accounts, true, 0, Some(CircuitSource::OffChain(OffChainCircuitSource {
-            source: "https://brown-immense-amphibian-214.mypinata.cloud/ipfs/bafybeibt3652jfgchqftay6cfytbiibxqujxqm3vmvy4d2kgfzubboo3qq/devnet/health_risk_devnet.arcis".to_string(),
-            hash: [0; 32],
-        })), None)?;
-        Ok(())
-    }
-
-    pub fn health_risk(
-        ctx: Context<HealthRisk>,
-        computation_offset: u64,
-        ciphertext_0: [u8; 32],
-        ciphertext_1: [u8; 32],
-        ciphertext_2: [u8; 32],
-        ciphertext_3: [u8; 32],
-        ciphertext_4: [u8; 32],
-        pub_key: [u8; 32],
-        nonce: u128,
-    ) -> Result<()> {
-        ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
-        let args = vec![
-            Argument::ArcisPubkey(pub_key),
-            Argument::PlaintextU128(nonce),
-            Argument::EncryptedU8(ciphertext_0),
-            Argument::EncryptedU8(ciphertext_1),
-            Argument::EncryptedU8(ciphertext_2),
-            Argument::EncryptedU8(ciphertext_3),
-            Argument::EncryptedU8(ciphertext_4),
-        ];
-
-        queue_computation(
-            ctx.accounts,
-            computation_offset,
-            args,
-            None,
-            vec![HealthRiskCallback::callback_ix(&[])],
-        )?;
-
-        Ok(())
-    }
-
-    #[arcium_callback(encrypted_ix = "health_risk")]
-    pub fn health_risk_callback(
-        ctx: Context<HealthRiskCallback>,
-        output: ComputationOutputs<HealthRiskOutput>,
-    ) -> Result<()> {
-        let o = match output {
-            ComputationOutputs::Success(HealthRiskOutput { field_0 }) => field_0,
-            _ => return Err(ErrorCode::AbortedComputation.into()),
-        };
-
-        emit!(HealthRiskEvent {
-            risk_score: o.ciphertexts[0],
-            risk_category: o.ciphertexts[1],
-            nonce: o.nonce.to_le_bytes(),
-        });
-        Ok(())
-    }
-}
-
-
-#[derive(Accounts)]
-pub struct InitSignPda<'info> {
-    #[account(mut)]
-    pub payer: Signer<'info>,
-    #[account(
-        init_if_needed,
-        space = 9,
-        payer = payer,
-        seeds = [&SIGN_PDA_SEED],
-        bump,
-        address = derive_sign_pda!(),
-    )]
-    pub sign_pda_account: Account<'info, SignerAccount>,
-    pub system_program: Program<'info, System>,
-}
-
-
-#[queue_computation_accounts("add", payer)]
-#[derive(Accounts)]
-#[instruction(computation_offset: u64)]
-pub struct Add<'info> {
-    #[account(mut)]
-    pub payer: Signer<'info>,
-    #[account(
-        init_if_needed,
-        space = 9,
-        payer = payer,
-        seeds = [&SIGN_PDA_SEED],
-        bump,
-        address = derive_sign_pda!(),
-    )]
-    pub sign_pda_account: Account<'info, SignerAccount>,
-    #[account(
-        address = derive_mxe_pda!()
-    )]
-    pub mxe_account: Box<Account<'info, MXEAccount>>,
-    #[account(
-        mut,
-        address = derive_mempool_pda!()
-    )]
-    /// CHECK: mempool_account, checked by the arcium program.
-    pub mempool_account: UncheckedAccount<'info>,
-    #[account(
-        mut,
-        address = derive_execpool_pda!()
-    )]
-    /// CHECK: executing_pool, checked by the arcium program.
-    pub executing_pool: UncheckedAccount<'info>,
-    #[account(
-        mut,
-        address = derive_comp_pda!(computation_offset)
-    )]
-    /// CHECK: computation_account, checked by the arcium program.
-    pub computation_account: UncheckedAccount<'info>,
-    #[account(
-        address = derive_comp_def_pda!(COMP_DEF_OFFSET_ADD)
-    )]
-    pub comp_def_account: Box<Account<'info, ComputationDefinitionAccount>>,
-    #[account(
-        mut,
-        address = derive_cluster_pda!(mxe_account)
-    )]
-    pub cluster_account: Box<Account<'info, Cluster>>,
-    #[account(
-        mut,
-        address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS,
-    )]
-    pub pool_account: Box<Account<'info, FeePool>>,
-    #[account(
-        address = ARCIUM_CLOCK_ACCOUNT_ADDRESS
-    )]
-    pub clock_account: Box<Account<'info, ClockAccount>>,
-    pub system_program: Program<'info, System>,
-    pub arcium_program: Program<'info, Arcium>,
-}
-
-#[callback_accounts("add")]
-#[derive(Accounts)]
-pub struct AddCallback<'info> {
-    pub arcium_program: Program<'info, Arcium>,
-    #[account(
-        address = derive_comp_def_pda!(COMP_DEF_OFFSET_ADD)
-    )]
-    pub comp_def_account: Box<Account<'info, ComputationDefinitionAccount>>,
-    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
-    /// CHECK: instructions_sysvar, checked by the account constraint
-    pub instructions_sysvar: AccountInfo<'info>,
-}
-
-#[init_computation_definition_accounts("add", payer)]
-#[derive(Accounts)]
-pub struct InitAddCompDef<'info> {
-    #[account(mut)]
-    pub payer: Signer<'info>,
-    #[account(
-        mut,
-        address = derive_mxe_pda!()
-    )]
-    pub mxe_account: Box<Account<'info, MXEAccount>>,
-    #[account(mut)]
-    /// CHECK: comp_def_account, checked by arcium program.
-    pub comp_def_account: UncheckedAccount<'info>,
-    pub arcium_program: Program<'info, Arcium>,
-    pub system_program: Program<'info, System>,
-}
-
-#[event]
-pub struct AddEvent {
-    pub result: [u8; 32],
-    pub nonce: [u8; 16],
-}
-
-#[queue_computation_accounts("subtract", payer)]
-#[derive(Accounts)]
-#[instruction(computation_offset: u64)]
-pub struct Subtract<'info> {
-    #[account(mut)]
-    pub payer: Signer<'info>,
-    #[account(
-        init_if_needed,
-        space = 9,
-        payer = payer,
-        seeds = [&SIGN_PDA_SEED],
-        bump,
-        address = derive_sign_pda!(),
-    )]
-    pub sign_pda_account: Account<'info, SignerAccount>,
-    #[account(
-        address = derive_mxe_pda!()
-    )]
-    pub mxe_account: Box<Account<'info, MXEAccount>>,
-    #[account(
-        mut,
-        address = derive_mempool_pda!()
-    )]
-    /// CHECK: mempool_account, checked by the arcium program.
-    pub mempool_account: UncheckedAccount<'info>,
-    #[account(
-        mut,
-        address = derive_execpool_pda!()
-    )]
-    /// CHECK: executing_pool, checked by the arcium program.
-    pub executing_pool: UncheckedAccount<'info>,
-    #[account(
-        mut,
-        address = derive_comp_pda!(computation_offset)
-    )]
-    /// CHECK: computation_account, checked by the arcium program.
-    pub computation_account: UncheckedAccount<'info>,
-    #[account(
-        address = derive_comp_def_pda!(COMP_DEF_OFFSET_SUBTRACT)
-    )]
-    pub comp_def_account: Box<Account<'info, ComputationDefinitionAccount>>,
-    #[account(
-        mut,
-        address = derive_cluster_pda!(mxe_account)
-    )]
-    pub cluster_account: Box<Account<'info, Cluster>>,
-    #[account(
-        mut,
-        address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS,
-    )]
-    pub pool_account: Box<Account<'info, FeePool>>,
-    #[account(
-        address = ARCIUM_CLOCK_ACCOUNT_ADDRESS
-    )]
-    pub clock_account: Box<Account<'info, ClockAccount>>,
-    pub system_program: Program<'info, System>,
-    pub arcium_program: Program<'info, Arcium>,
-}
-
-#[callback_accounts("subtract")]
-#[derive(Accounts)]
-pub struct SubtractCallback<'info> {
-    pub arcium_program: Program<'info, Arcium>,
-    #[account(
-        address = derive_comp_def_pda!(COMP_DEF_OFFSET_SUBTRACT)
-    )]
-    pub comp_def_account: Box<Account<'info, ComputationDefinitionAccount>>,
-    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
-    /// CHECK: instructions_sysvar, checked by the account constraint
-    pub instructions_sysvar: AccountInfo<'info>,
-}
-
-#[init_computation_definition_accounts("subtract", payer)]
-#[derive(Accounts)]
-pub struct InitSubtractCompDef<'info> {
-    #[account(mut)]
-    pub payer: Signer<'info>,
-    #[account(
-        mut,
-        address = derive_mxe_pda!()
-    )]
-    pub mxe_account: Box<Account<'info, MXEAccount>>,
-    #[account(mut)]
-    /// CHECK: comp_def_account, checked by arcium program.
-    pub comp_def_account: UncheckedAccount<'info>,
-    pub arcium_program: Program<'info, Arcium>,
-    pub system_program: Program<'info, System>,
-}
-
-#[event]
-pub struct SubtractEvent {
-    pub result: [u8; 32],
-    pub nonce: [u8; 16],
-}
-
-#[queue_computation_accounts("multiply", payer)]
-#[derive(Accounts)]
-#[instruction(computation_offset: u64)]
-pub struct Multiply<'info> {
-    #[account(mut)]
-    pub payer: Signer<'info>,
-    #[account(
-        init_if_needed,
-        space = 9,
-        payer = payer,
-        seeds = [&SIGN_PDA_SEED],
-        bump,
-        address = derive_sign_pda!(),
-    )]
-    pub sign_pda_account: Account<'info, SignerAccount>,
-    #[account(
-        address = derive_mxe_pda!()
-    )]
-    pub mxe_account: Box<Account<'info, MXEAccount>>,
-    #[account(
-        mut,
-        address = derive_mempool_pda!()
-    )]
-    /// CHECK: mempool_account, checked by the arcium program.
-    pub mempool_account: UncheckedAccount<'info>,
-    #[account(
-        mut,
-        address = derive_execpool_pda!()
-    )]
-    /// CHECK: executing_pool, checked by the arcium program.
-    pub executing_pool: UncheckedAccount<'info>,
-    #[account(
-        mut,
-        address = derive_comp_pda!(computation_offset)
-    )]
-    /// CHECK: computation_account, checked by the arcium program.
-    pub computation_account: UncheckedAccount<'info>,
-    #[account(
-        address = derive_comp_def_pda!(COMP_DEF_OFFSET_MULTIPLY)
-    )]
-    pub comp_def_account: Box<Account<'info, ComputationDefinitionAccount>>,
-    #[account(
-        mut,
-        address = derive_cluster_pda!(mxe_account)
-    )]
-    pub cluster_account: Box<Account<'info, Cluster>>,
-    #[account(
-        mut,
-        address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS,
-    )]
-    pub pool_account: Box<Account<'info, FeePool>>,
-    #[account(
-        address = ARCIUM_CLOCK_ACCOUNT_ADDRESS
-    )]
-    pub clock_account: Box<Account<'info, ClockAccount>>,
-    pub system_program: Program<'info, System>,
-    pub arcium_program: Program<'info, Arcium>,
-}
-
-#[callback_accounts("multiply")]
-#[derive(Accounts)]
-pub struct MultiplyCallback<'info> {
-    pub arcium_program: Program<'info, Arcium>,
-    #[account(
-        address = derive_comp_def_pda!(COMP_DEF_OFFSET_MULTIPLY)
-    )]
-    pub comp_def_account: Box<Account<'info, ComputationDefinitionAccount>>,
-    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
-    /// CHECK: instructions_sysvar, checked by the account constraint
-    pub instructions_sysvar: AccountInfo<'info>,
-}
-
-#[init_computation_definition_accounts("multiply", payer)]
-#[derive(Accounts)]
-pub struct InitMultiplyCompDef<'info> {
-    #[account(mut)]
-    pub payer: Signer<'info>,
-    #[account(
-        mut,
-        address = derive_mxe_pda!()
-    )]
-    pub mxe_account: Box<Account<'info, MXEAccount>>,
-    #[account(mut)]
-    /// CHECK: comp_def_account, checked by arcium program.
-    pub comp_def_account: UncheckedAccount<'info>,
-    pub arcium_program: Program<'info, Arcium>,
-    pub system_program: Program<'info, System>,
-}
-
-#[event]
-pub struct MultiplyEvent {
-    pub result: [u8; 32],
-    pub nonce: [u8; 16],
-}
-
-#[queue_computation_accounts("divide", payer)]
-#[derive(Accounts)]
-#[instruction(computation_offset: u64)]
-pub struct Divide<'info> {
-    #[account(mut)]
-    pub payer: Signer<'info>,
-    #[account(
-        init_if_needed,
-        space = 9,
-        payer = payer,
-        seeds = [&SIGN_PDA_SEED],
-        bump,
-        address = derive_sign_pda!(),
-    )]
-    pub sign_pda_account: Account<'info, SignerAccount>,
-    #[account(
-        address = derive_mxe_pda!()
-    )]
-    pub mxe_account: Box<Account<'info, MXEAccount>>,
-    #[account(
-        mut,
-        address = derive_mempool_pda!()
-    )]
-    /// CHECK: mempool_account, checked by the arcium program.
-    pub mempool_account: UncheckedAccount<'info>,
-    #[account(
-        mut,
-        address = derive_execpool_pda!()
-    )]
-    /// CHECK: executing_pool, checked by the arcium program.
-    pub executing_pool: UncheckedAccount<'info>,
-    #[account(
-        mut,
-        address = derive_comp_pda!(computation_offset)
-    )]
-    /// CHECK: computation_account, checked by the arcium program.
-    pub computation_account: UncheckedAccount<'info>,
-    #[account(
-        address = derive_comp_def_pda!(COMP_DEF_OFFSET_DIVIDE)
-    )]
-    pub comp_def_account: Box<Account<'info, ComputationDefinitionAccount>>,
-    #[account(
-        mut,
-        address = derive_cluster_pda!(mxe_account)
-    )]
-    pub cluster_account: Box<Account<'info, Cluster>>,
-    #[account(
-        mut,
-        address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS,
-    )]
-    pub pool_account: Box<Account<'info, FeePool>>,
-    #[account(
-        address = ARCIUM_CLOCK_ACCOUNT_ADDRESS
-    )]
-    pub clock_account: Box<Account<'info, ClockAccount>>,
-    pub system_program: Program<'info, System>,
-    pub arcium_program: Program<'info, Arcium>,
-}
-
-#[callback_accounts("divide")]
-#[derive(Accounts)]
-pub struct DivideCallback<'info> {
-    pub arcium_program: Program<'info, Arcium>,
-    #[account(
-        address = derive_comp_def_pda!(COMP_DEF_OFFSET_DIVIDE)
-    )]
-    pub comp_def_account: Box<Account<'info, ComputationDefinitionAccount>>,
-    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
-    /// CHECK: instructions_sysvar, checked by the account constraint
-    pub instructions_sysvar: AccountInfo<'info>,
-}
-
-#[init_computation_definition_accounts("divide", payer)]
-#[derive(Accounts)]
-pub struct InitDivideCompDef<'info> {
-    #[account(mut)]
-    pub payer: Signer<'info>,
-    #[account(
-        mut,
-        address = derive_mxe_pda!()
-    )]
-    pub mxe_account: Box<Account<'info, MXEAccount>>,
-    #[account(mut)]
-    /// CHECK: comp_def_account, checked by arcium program.
-    pub comp_def_account: UncheckedAccount<'info>,
-    pub arcium_program: Program<'info, Arcium>,
-    pub system_program: Program<'info, System>,
-}
-
-#[event]
-pub struct DivideEvent {
-    pub result: [u8; 32],
-    pub nonce: [u8; 16],
-}
-
-#[queue_computation_accounts("modulo", payer)]
-#[derive(Accounts)]
-#[instruction(computation_offset: u64)]
-pub struct Modulo<'info> {
-    #[account(mut)]
-    pub payer: Signer<'info>,
-    #[account(
-        init_if_needed,
-        space = 9,
-        payer = payer,
-        seeds = [&SIGN_PDA_SEED],
-        bump,
-        address = derive_sign_pda!(),
-    )]
-    pub sign_pda_account: Account<'info, SignerAccount>,
-    #[account(
-        address = derive_mxe_pda!()
-    )]
-    pub mxe_account: Box<Account<'info, MXEAccount>>,
-    #[account(
-        mut,
-        address = derive_mempool_pda!()
-    )]
-    /// CHECK: mempool_account, checked by the arcium program.
-    pub mempool_account: UncheckedAccount<'info>,
-    #[account(
-        mut,
-        address = derive_execpool_pda!()
-    )]
-    /// CHECK: executing_pool, checked by the arcium program.
-    pub executing_pool: UncheckedAccount<'info>,
-    #[account(
-        mut,
-        address = derive_comp_pda!(computation_offset)
-    )]
-    /// CHECK: computation_account, checked by the arcium program.
-    pub computation_account: UncheckedAccount<'info>,
-    #[account(
-        address = derive_comp_def_pda!(COMP_DEF_OFFSET_MODULO)
-    )]
-    pub comp_def_account: Box<Account<'info, ComputationDefinitionAccount>>,
-    #[account(
-        mut,
-        address = derive_cluster_pda!(mxe_account)
-    )]
-    pub cluster_account: Box<Account<'info, Cluster>>,
-    #[account(
-        mut,
-        address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS,
-    )]
-    pub pool_account: Box<Account<'info, FeePool>>,
-    #[account(
-        address = ARCIUM_CLOCK_ACCOUNT_ADDRESS
-    )]
-    pub clock_account: Box<Account<'info, ClockAccount>>,
-    pub system_program: Program<'info, System>,
-    pub arcium_program: Program<'info, Arcium>,
-}
-
-#[callback_accounts("modulo")]
-#[derive(Accounts)]
-pub struct ModuloCallback<'info> {
-    pub arcium_program: Program<'info, Arcium>,
-    #[account(
-        address = derive_comp_def_pda!(COMP_DEF_OFFSET_MODULO)
-    )]
-    pub comp_def_account: Box<Account<'info, ComputationDefinitionAccount>>,
-    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
-    /// CHECK: instructions_sysvar, checked by the account constraint
-    pub instructions_sysvar: AccountInfo<'info>,
-}
-
-#[init_computation_definition_accounts("modulo", payer)]
-#[derive(Accounts)]
-pub struct InitModuloCompDef<'info> {
-    #[account(mut)]
-    pub payer: Signer<'info>,
-    #[account(
-        mut,
-        address = derive_mxe_pda!()
-    )]
-    pub mxe_account: Box<Account<'info, MXEAccount>>,
-    #[account(mut)]
-    /// CHECK: comp_def_account, checked by arcium program.
-    pub comp_def_account: UncheckedAccount<'info>,
-    pub arcium_program: Program<'info, Arcium>,
-    pub system_program: Program<'info, System>,
-}
-
-#[event]
-pub struct ModuloEvent {
-    pub result: [u8; 32],
-    pub nonce: [u8; 16],
-}
-
-#[queue_computation_accounts("abs_diff", payer)]
-#[derive(Accounts)]
-#[instruction(computation_offset: u64)]
-pub struct AbsDiff<'info> {
-    #[account(mut)]
-    pub payer: Signer<'info>,
-    #[account(
-        init_if_needed,
-        space = 9,
-        payer = payer,
-        seeds = [&SIGN_PDA_SEED],
-        bump,
-        address = derive_sign_pda!(),
-    )]
-    pub sign_pda_account: Account<'info, SignerAccount>,
-    #[account(
-        address = derive_mxe_pda!()
-    )]
-    pub mxe_account: Box<Account<'info, MXEAccount>>,
-    #[account(
-        mut,
-        address = derive_mempool_pda!()
-    )]
-    /// CHECK: mempool_account, checked by the arcium program.
-    pub mempool_account: UncheckedAccount<'info>,
-    #[account(
-        mut,
-        address = derive_execpool_pda!()
-    )]
-    /// CHECK: executing_pool, checked by the arcium program.
-    pub executing_pool: UncheckedAccount<'info>,
-    #[account(
-        mut,
-        address = derive_comp_pda!(computation_offset)
-    )]
-    /// CHECK: computation_account, checked by the arcium program.
-    pub computation_account: UncheckedAccount<'info>,
-    #[account(
-        address = derive_comp_def_pda!(COMP_DEF_OFFSET_ABS_DIFF)
-    )]
-    pub comp_def_account: Box<Account<'info, ComputationDefinitionAccount>>,
-    #[account(
-        mut,
-        address = derive_cluster_pda!(mxe_account)
-    )]
-    pub cluster_account: Box<Account<'info, Cluster>>,
-    #[account(
-        mut,
-        address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS,
-    )]
-    pub pool_account: Box<Account<'info, FeePool>>,
-    #[account(
-        address = ARCIUM_CLOCK_ACCOUNT_ADDRESS
-    )]
-    pub clock_account: Box<Account<'info, ClockAccount>>,
-    pub system_program: Program<'info, System>,
-    pub arcium_program: Program<'info, Arcium>,
-}
-
-#[callback_accounts("abs_diff")]
-#[derive(Accounts)]
-pub struct AbsDiffCallback<'info> {
-    pub arcium_program: Program<'info, Arcium>,
-    #[account(
-        address = derive_comp_def_pda!(COMP_DEF_OFFSET_ABS_DIFF)
-    )]
-    pub comp_def_account: Box<Account<'info, ComputationDefinitionAccount>>,
-    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
-    /// CHECK: instructions_sysvar, checked by the account constraint
-    pub instructions_sysvar: AccountInfo<'info>,
-}
-
-#[init_computation_definition_accounts("abs_diff", payer)]
-#[derive(Accounts)]
-pub struct InitAbsDiffCompDef<'info> {
-    #[account(mut)]
-    pub payer: Signer<'info>,
-    #[account(
-        mut,
-        address = derive_mxe_pda!()
-    )]
-    pub mxe_account: Box<Account<'info, MXEAccount>>,
-    #[account(mut)]
-    /// CHECK: comp_def_account, checked by arcium program.
-    pub comp_def_account: UncheckedAccount<'info>,
-    pub arcium_program: Program<'info, Arcium>,
-    pub system_program: Program<'info, System>,
-}
-
-#[event]
-pub struct AbsDiffEvent {
-    pub result: [u8; 32],
-    pub nonce: [u8; 16],
-}
-
-#[queue_computation_accounts("power", payer)]
-#[derive(Accounts)]
-#[instruction(computation_offset: u64)]
-pub struct Power<'info> {
-    #[account(mut)]
-    pub payer: Signer<'info>,
-    #[account(
-        init_if_needed,
-        space = 9,
-        payer = payer,
-        seeds = [&SIGN_PDA_SEED],
-        bump,
-        address = derive_sign_pda!(),
-    )]
-    pub sign_pda_account: Account<'info, SignerAccount>,
-    #[account(
-        address = derive_mxe_pda!()
-    )]
-    pub mxe_account: Box<Account<'info, MXEAccount>>,
-    #[account(
-        mut,
-        address = derive_mempool_pda!()
-    )]
-    /// CHECK: mempool_account, checked by the arcium program.
-    pub mempool_account: UncheckedAccount<'info>,
-    #[account(
-        mut,
-        address = derive_execpool_pda!()
-    )]
-    /// CHECK: executing_pool, checked by the arcium program.
-    pub executing_pool: UncheckedAccount<'info>,
-    #[account(
-        mut,
-        address = derive_comp_pda!(computation_offset)
-    )]
-    /// CHECK: computation_account, checked by the arcium program.
-    pub computation_account: UncheckedAccount<'info>,
-    #[account(
-        address = derive_comp_def_pda!(COMP_DEF_OFFSET_POWER)
-    )]
-    pub comp_def_account: Box<Account<'info, ComputationDefinitionAccount>>,
-    #[account(
-        mut,
-        address = derive_cluster_pda!(mxe_account)
-    )]
-    pub cluster_account: Box<Account<'info, Cluster>>,
-    #[account(
-        mut,
-        address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS,
-    )]
-    pub pool_account: Box<Account<'info, FeePool>>,
-    #[account(
-        address = ARCIUM_CLOCK_ACCOUNT_ADDRESS
-    )]
-    pub clock_account: Box<Account<'info, ClockAccount>>,
-    pub system_program: Program<'info, System>,
-    pub arcium_program: Program<'info, Arcium>,
-}
-
-#[callback_accounts("power")]
-#[derive(Accounts)]
-pub struct PowerCallback<'info> {
-    pub arcium_program: Program<'info, Arcium>,
-    #[account(
-        address = derive_comp_def_pda!(COMP_DEF_OFFSET_POWER)
-    )]
-    pub comp_def_account: Box<Account<'info, ComputationDefinitionAccount>>,
-    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
-    /// CHECK: instructions_sysvar, checked by the account constraint
-    pub instructions_sysvar: AccountInfo<'info>,
-}
-
-#[init_computation_definition_accounts("power", payer)]
-#[derive(Accounts)]
-pub struct InitPowerCompDef<'info> {
-    #[account(mut)]
-    pub payer: Signer<'info>,
-    #[account(
-        mut,
-        address = derive_mxe_pda!()
-    )]
-    pub mxe_account: Box<Account<'info, MXEAccount>>,
-    #[account(mut)]
-    /// CHECK: comp_def_account, checked by arcium program.
-    pub comp_def_account: UncheckedAccount<'info>,
-    pub arcium_program: Program<'info, Arcium>,
-    pub system_program: Program<'info, System>,
-}
-
-#[event]
-pub struct PowerEvent {
-    pub result: [u8; 32],
-    pub nonce: [u8; 16],
-}
-
-#[queue_computation_accounts("greater_than", payer)]
-#[derive(Accounts)]
-#[instruction(computation_offset: u64)]
-pub struct GreaterThan<'info> {
-    #[account(mut)]
-    pub payer: Signer<'info>,
-    #[account(
-        init_if_needed,
-        space = 9,
-        payer = payer,
-        seeds = [&SIGN_PDA_SEED],
-        bump,
-        address = derive_sign_pda!(),
-    )]
-    pub sign_pda_account: Account<'info, SignerAccount>,
-    #[account(
-        address = derive_mxe_pda!()
-    )]
-    pub mxe_account: Box<Account<'info, MXEAccount>>,
-    #[account(
-        mut,
-        address = derive_mempool_pda!()
-    )]
-    /// CHECK: mempool_account, checked by the arcium program.
-    pub mempool_account: UncheckedAccount<'info>,
-    #[account(
-        mut,
-        address = derive_execpool_pda!()
-    )]
-    /// CHECK: executing_pool, checked by the arcium program.
-    pub executing_pool: UncheckedAccount<'info>,
-    #[account(
-        mut,
-        address = derive_comp_pda!(computation_offset)
-    )]
-    /// CHECK: computation_account, checked by the arcium program.
-    pub computation_account: UncheckedAccount<'info>,
-    #[account(
-        address = derive_comp_def_pda!(COMP_DEF_OFFSET_GREATER_THAN)
-    )]
-    pub comp_def_account: Box<Account<'info, ComputationDefinitionAccount>>,
-    #[account(
-        mut,
-        address = derive_cluster_pda!(mxe_account)
-    )]
-    pub cluster_account: Box<Account<'info, Cluster>>,
-    #[account(
-        mut,
-        address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS,
-    )]
-    pub pool_account: Box<Account<'info, FeePool>>,
-    #[account(
-        address = ARCIUM_CLOCK_ACCOUNT_ADDRESS
-    )]
-    pub clock_account: Box<Account<'info, ClockAccount>>,
-    pub system_program: Program<'info, System>,
-    pub arcium_program: Program<'info, Arcium>,
-}
-
-#[callback_accounts("greater_than")]
-#[derive(Accounts)]
-pub struct GreaterThanCallback<'info> {
-    pub arcium_program: Program<'info, Arcium>,
-    #[account(
-        address = derive_comp_def_pda!(COMP_DEF_OFFSET_GREATER_THAN)
-    )]
-    pub comp_def_account: Box<Account<'info, ComputationDefinitionAccount>>,
-    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
-    /// CHECK: instructions_sysvar, checked by the account constraint
-    pub instructions_sysvar: AccountInfo<'info>,
-}
-
-#[init_computation_definition_accounts("greater_than", payer)]
-#[derive(Accounts)]
-pub struct InitGreaterThanCompDef<'info> {
-    #[account(mut)]
-    pub payer: Signer<'info>,
-    #[account(
-        mut,
-        address = derive_mxe_pda!()
-    )]
-    pub mxe_account: Box<Account<'info, MXEAccount>>,
-    #[account(mut)]
-    /// CHECK: comp_def_account, checked by arcium program.
-    pub comp_def_account: UncheckedAccount<'info>,
-    pub arcium_program: Program<'info, Arcium>,
-    pub system_program: Program<'info, System>,
-}
-
-#[event]
-pub struct GreaterThanEvent {
-    pub result: [u8; 32],
-    pub nonce: [u8; 16],
-}
-
-#[queue_computation_accounts("less_than", payer)]
-#[derive(Accounts)]
-#[instruction(computation_offset: u64)]
-pub struct LessThan<'info> {
-    #[account(mut)]
-    pub payer: Signer<'info>,
-    #[account(
-        init_if_needed,
-        space = 9,
-        payer = payer,
-        seeds = [&SIGN_PDA_SEED],
-        bump,
-        address = derive_sign_pda!(),
-    )]
-    pub sign_pda_account: Account<'info, SignerAccount>,
-    #[account(
-        address = derive_mxe_pda!()
-    )]
-    pub mxe_account: Box<Account<'info, MXEAccount>>,
-    #[account(
-        mut,
-        address = derive_mempool_pda!()
-    )]
-    /// CHECK: mempool_account, checked by the arcium program.
-    pub mempool_account: UncheckedAccount<'info>,
-    #[account(
-        mut,
-        address = derive_execpool_pda!()
-    )]
-    /// CHECK: executing_pool, checked by the arcium program.
-    pub executing_pool: UncheckedAccount<'info>,
-    #[account(
-        mut,
-        address = derive_comp_pda!(computation_offset)
-    )]
-    /// CHECK: computation_account, checked by the arcium program.
-    pub computation_account: UncheckedAccount<'info>,
-    #[account(
-        address = derive_comp_def_pda!(COMP_DEF_OFFSET_LESS_THAN)
-    )]
-    pub comp_def_account: Box<Account<'info, ComputationDefinitionAccount>>,
-    #[account(
-        mut,
-        address = derive_cluster_pda!(mxe_account)
-    )]
-    pub cluster_account: Box<Account<'info, Cluster>>,
-    #[account(
-        mut,
-        address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS,
-    )]
-    pub pool_account: Box<Account<'info, FeePool>>,
-    #[account(
-        address = ARCIUM_CLOCK_ACCOUNT_ADDRESS
-    )]
-    pub clock_account: Box<Account<'info, ClockAccount>>,
-    pub system_program: Program<'info, System>,
-    pub arcium_program: Program<'info, Arcium>,
-}
-
-#[callback_accounts("less_than")]
-#[derive(Accounts)]
-pub struct LessThanCallback<'info> {
-    pub arcium_program: Program<'info, Arcium>,
-    #[account(
-        address = derive_comp_def_pda!(COMP_DEF_OFFSET_LESS_THAN)
-    )]
-    pub comp_def_account: Box<Account<'info, ComputationDefinitionAccount>>,
-    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
-    /// CHECK: instructions_sysvar, checked by the account constraint
-    pub instructions_sysvar: AccountInfo<'info>,
-}
-
-#[init_computation_definition_accounts("less_than", payer)]
-#[derive(Accounts)]
-pub struct InitLessThanCompDef<'info> {
-    #[account(mut)]
-    pub payer: Signer<'info>,
-    #[account(
-        mut,
-        address = derive_mxe_pda!()
-    )]
-    pub mxe_account: Box<Account<'info, MXEAccount>>,
-    #[account(mut)]
-    /// CHECK: comp_def_account, checked by arcium program.
-    pub comp_def_account: UncheckedAccount<'info>,
-    pub arcium_program: Program<'info, Arcium>,
-    pub system_program: Program<'info, System>,
-}
-
-#[event]
-pub struct LessThanEvent {
-    pub result: [u8; 32],
-    pub nonce: [u8; 16],
-}
-
-#[queue_computation_accounts("equal", payer)]
-#[derive(Accounts)]
-#[instruction(computation_offset: u64)]
-pub struct Equal<'info> {
-    #[account(mut)]
-    pub payer: Signer<'info>,
-    #[account(
-        init_if_needed,
-        space = 9,
-        payer = payer,
-        seeds = [&SIGN_PDA_SEED],
-        bump,
-        address = derive_sign_pda!(),
-    )]
-    pub sign_pda_account: Account<'info, SignerAccount>,
-    #[account(
-        address = derive_mxe_pda!()
-    )]
-    pub mxe_account: Box<Account<'info, MXEAccount>>,
-    #[account(
-        mut,
-        address = derive_mempool_pda!()
-    )]
-    /// CHECK: mempool_account, checked by the arcium program.
-    pub mempool_account: UncheckedAccount<'info>,
-    #[account(
-        mut,
-        address = derive_execpool_pda!()
-    )]
-    /// CHECK: executing_pool, checked by the arcium program.
-    pub executing_pool: UncheckedAccount<'info>,
-    #[account(
-        mut,
-        address = derive_comp_pda!(computation_offset)
-    )]
-    /// CHECK: computation_account, checked by the arcium program.
-    pub computation_account: UncheckedAccount<'info>,
-    #[account(
-        address = derive_comp_def_pda!(COMP_DEF_OFFSET_EQUAL)
-    )]
-    pub comp_def_account: Box<Account<'info, ComputationDefinitionAccount>>,
-    #[account(
-        mut,
-        address = derive_cluster_pda!(mxe_account)
-    )]
-    pub cluster_account: Box<Account<'info, Cluster>>,
-    #[account(
-        mut,
-        address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS,
-    )]
-    pub pool_account: Box<Account<'info, FeePool>>,
-    #[account(
-        address = ARCIUM_CLOCK_ACCOUNT_ADDRESS
-    )]
-    pub clock_account: Box<Account<'info, ClockAccount>>,
-    pub system_program: Program<'info, System>,
-    pub arcium_program: Program<'info, Arcium>,
-}
-
-#[callback_accounts("equal")]
-#[derive(Accounts)]
-pub struct EqualCallback<'info> {
-    pub arcium_program: Program<'info, Arcium>,
-    #[account(
-        address = derive_comp_def_pda!(COMP_DEF_OFFSET_EQUAL)
-    )]
-    pub comp_def_account: Box<Account<'info, ComputationDefinitionAccount>>,
-    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
-    /// CHECK: instructions_sysvar, checked by the account constraint
-    pub instructions_sysvar: AccountInfo<'info>,
-}
-
-#[init_computation_definition_accounts("equal", payer)]
-#[derive(Accounts)]
-pub struct InitEqualCompDef<'info> {
-    #[account(mut)]
-    pub payer: Signer<'info>,
-    #[account(
-        mut,
-        address = derive_mxe_pda!()
-    )]
-    pub mxe_account: Box<Account<'info, MXEAccount>>,
-    #[account(mut)]
-    /// CHECK: comp_def_account, checked by arcium program.
-    pub comp_def_account: UncheckedAccount<'info>,
-    pub arcium_program: Program<'info, Arcium>,
-    pub system_program: Program<'info, System>,
-}
-
-#[event]
-pub struct EqualEvent {
-    pub result: [u8; 32],
-    pub nonce: [u8; 16],
-}
-
-#[queue_computation_accounts("greater_equal", payer)]
-#[derive(Accounts)]
-#[instruction(computation_offset: u64)]
-pub struct GreaterEqual<'info> {
-    #[account(mut)]
-    pub payer: Signer<'info>,
-    #[account(
-        init_if_needed,
-        space = 9,
-        payer = payer,
-        seeds = [&SIGN_PDA_SEED],
-        bump,
-        address = derive_sign_pda!(),
-    )]
-    pub sign_pda_account: Account<'info, SignerAccount>,
-    #[account(
-        address = derive_mxe_pda!()
-    )]
-    pub mxe_account: Box<Account<'info, MXEAccount>>,
-    #[account(
-        mut,
-        address = derive_mempool_pda!()
-    )]
-    /// CHECK: mempool_account, checked by the arcium program.
-    pub mempool_account: UncheckedAccount<'info>,
-    #[account(
-        mut,
-        address = derive_execpool_pda!()
-    )]
-    /// CHECK: executing_pool, checked by the arcium program.
-    pub executing_pool: UncheckedAccount<'info>,
-    #[account(
-        mut,
-        address = derive_comp_pda!(computation_offset)
-    )]
-    /// CHECK: computation_account, checked by the arcium program.
-    pub computation_account: UncheckedAccount<'info>,
-    #[account(
-        address = derive_comp_def_pda!(COMP_DEF_OFFSET_GREATER_EQUAL)
-    )]
-    pub comp_def_account: Box<Account<'info, ComputationDefinitionAccount>>,
-    #[account(
-        mut,
-        address = derive_cluster_pda!(mxe_account)
-    )]
-    pub cluster_account: Box<Account<'info, Cluster>>,
-    #[account(
-        mut,
-        address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS,
-    )]
-    pub pool_account: Box<Account<'info, FeePool>>,
-    #[account(
-        address = ARCIUM_CLOCK_ACCOUNT_ADDRESS
-    )]
-    pub clock_account: Box<Account<'info, ClockAccount>>,
-    pub system_program: Program<'info, System>,
-    pub arcium_program: Program<'info, Arcium>,
-}
-
-#[callback_accounts("greater_equal")]
-#[derive(Accounts)]
-pub struct GreaterEqualCallback<'info> {
-    pub arcium_program: Program<'info, Arcium>,
-    #[account(
-        address = derive_comp_def_pda!(COMP_DEF_OFFSET_GREATER_EQUAL)
-    )]
-    pub comp_def_account: Box<Account<'info, ComputationDefinitionAccount>>,
-    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
-    /// CHECK: instructions_sysvar, checked by the account constraint
-    pub instructions_sysvar: AccountInfo<'info>,
-}
-
-#[init_computation_definition_accounts("greater_equal", payer)]
-#[derive(Accounts)]
-pub struct InitGreaterEqualCompDef<'info> {
-    #[account(mut)]
-    pub payer: Signer<'info>,
-    #[account(
-        mut,
-        address = derive_mxe_pda!()
-    )]
-    pub mxe_account: Box<Account<'info, MXEAccount>>,
-    #[account(mut)]
-    /// CHECK: comp_def_account, checked by arcium program.
-    pub comp_def_account: UncheckedAccount<'info>,
-    pub arcium_program: Program<'info, Arcium>,
-    pub system_program: Program<'info, System>,
-}
-
-#[event]
-pub struct GreaterEqualEvent {
-    pub result: [u8; 32],
-    pub nonce: [u8; 16],
-}
-
-#[queue_computation_accounts("less_equal", payer)]
-#[derive(Accounts)]
-#[instruction(computation_offset: u64)]
-pub struct LessEqual<'info> {
-    #[account(mut)]
-    pub payer: Signer<'info>,
-    #[account(
-        init_if_needed,
-        space = 9,
-        payer = payer,
-        seeds = [&SIGN_PDA_SEED],
-        bump,
-        address = derive_sign_pda!(),
-    )]
-    pub sign_pda_account: Account<'info, SignerAccount>,
-    #[account(
-        address = derive_mxe_pda!()
-    )]
-    pub mxe_account: Box<Account<'info, MXEAccount>>,
-    #[account(
-        mut,
-        address = derive_mempool_pda!()
-    )]
-    /// CHECK: mempool_account, checked by the arcium program.
-    pub mempool_account: UncheckedAccount<'info>,
-    #[account(
-        mut,
-        address = derive_execpool_pda!()
-    )]
-    /// CHECK: executing_pool, checked by the arcium program.
-    pub executing_pool: UncheckedAccount<'info>,
-    #[account(
-        mut,
-        address = derive_comp_pda!(computation_offset)
-    )]
-    /// CHECK: computation_account, checked by the arcium program.
-    pub computation_account: UncheckedAccount<'info>,
-    #[account(
-        address = derive_comp_def_pda!(COMP_DEF_OFFSET_LESS_EQUAL)
-    )]
-    pub comp_def_account: Box<Account<'info, ComputationDefinitionAccount>>,
-    #[account(
-        mut,
-        address = derive_cluster_pda!(mxe_account)
-    )]
-    pub cluster_account: Box<Account<'info, Cluster>>,
-    #[account(
-        mut,
-        address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS,
-    )]
-    pub pool_account: Box<Account<'info, FeePool>>,
-    #[account(
-        address = ARCIUM_CLOCK_ACCOUNT_ADDRESS
-    )]
-    pub clock_account: Box<Account<'info, ClockAccount>>,
-    pub system_program: Program<'info, System>,
-    pub arcium_program: Program<'info, Arcium>,
-}
-
-#[callback_accounts("less_equal")]
-#[derive(Accounts)]
-pub struct LessEqualCallback<'info> {
-    pub arcium_program: Program<'info, Arcium>,
-    #[account(
-        address = derive_comp_def_pda!(COMP_DEF_OFFSET_LESS_EQUAL)
-    )]
-    pub comp_def_account: Box<Account<'info, ComputationDefinitionAccount>>,
-    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
-    /// CHECK: instructions_sysvar, checked by the account constraint
-    pub instructions_sysvar: AccountInfo<'info>,
-}
-
-#[init_computation_definition_accounts("less_equal", payer)]
-#[derive(Accounts)]
-pub struct InitLessEqualCompDef<'info> {
-    #[account(mut)]
-    pub payer: Signer<'info>,
-    #[account(
-        mut,
-        address = derive_mxe_pda!()
-    )]
-    pub mxe_account: Box<Account<'info, MXEAccount>>,
-    #[account(mut)]
-    /// CHECK: comp_def_account, checked by arcium program.
-    pub comp_def_account: UncheckedAccount<'info>,
-    pub arcium_program: Program<'info, Arcium>,
-    pub system_program: Program<'info, System>,
-}
-
-#[event]
-pub struct LessEqualEvent {
-    pub result: [u8; 32],
-    pub nonce: [u8; 16],
-}
-
-#[queue_computation_accounts("meets_threshold", payer)]
-#[derive(Accounts)]
-#[instruction(computation_offset: u64)]
-pub struct MeetsThreshold<'info> {
-    #[account(mut)]
-    pub payer: Signer<'info>,
-    #[account(
-        init_if_needed,
-        space = 9,
-        payer = payer,
-        seeds = [&SIGN_PDA_SEED],
-        bump,
-        address = derive_sign_pda!(),
-    )]
-    pub sign_pda_account: Account<'info, SignerAccount>,
-    #[account(
-        address = derive_mxe_pda!()
-    )]
-    pub mxe_account: Box<Account<'info, MXEAccount>>,
-    #[account(
-        mut,
-        address = derive_mempool_pda!()
-    )]
-    /// CHECK: mempool_account, checked by the arcium program.
-    pub mempool_account: UncheckedAccount<'info>,
-    #[account(
-        mut,
-        address = derive_execpool_pda!()
-    )]
-    /// CHECK: executing_pool, checked by the arcium program.
-    pub executing_pool: UncheckedAccount<'info>,
-    #[account(
-        mut,
-        address = derive_comp_pda!(computation_offset)
-    )]
-    /// CHECK: computation_account, checked by the arcium program.
-    pub computation_account: UncheckedAccount<'info>,
-    #[account(
-        address = derive_comp_def_pda!(COMP_DEF_OFFSET_MEETS_THRESHOLD)
-    )]
-    pub comp_def_account: Box<Account<'info, ComputationDefinitionAccount>>,
-    #[account(
-        mut,
-        address = derive_cluster_pda!(mxe_account)
-    )]
-    pub cluster_account: Box<Account<'info, Cluster>>,
-    #[account(
-        mut,
-        address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS,
-    )]
-    pub pool_account: Box<Account<'info, FeePool>>,
-    #[account(
-        address = ARCIUM_CLOCK_ACCOUNT_ADDRESS
-    )]
-    pub clock_account: Box<Account<'info, ClockAccount>>,
-    pub system_program: Program<'info, System>,
-    pub arcium_program: Program<'info, Arcium>,
-}
-
-#[callback_accounts("meets_threshold")]
-#[derive(Accounts)]
-pub struct MeetsThresholdCallback<'info> {
-    pub arcium_program: Program<'info, Arcium>,
-    #[account(
-        address = derive_comp_def_pda!(COMP_DEF_OFFSET_MEETS_THRESHOLD)
-    )]
-    pub comp_def_account: Box<Account<'info, ComputationDefinitionAccount>>,
-    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
-    /// CHECK: instructions_sysvar, checked by the account constraint
-    pub instructions_sysvar: AccountInfo<'info>,
-}
-
-#[init_computation_definition_accounts("meets_threshold", payer)]
-#[derive(Accounts)]
-pub struct InitMeetsThresholdCompDef<'info> {
-    #[account(mut)]
-    pub payer: Signer<'info>,
-    #[account(
-        mut,
-        address = derive_mxe_pda!()
-    )]
-    pub mxe_account: Box<Account<'info, MXEAccount>>,
-    #[account(mut)]
-    /// CHECK: comp_def_account, checked by arcium program.
-    pub comp_def_account: UncheckedAccount<'info>,
-    pub arcium_program: Program<'info, Arcium>,
-    pub system_program: Program<'info, System>,
-}
-
-#[event]
-pub struct MeetsThresholdEvent {
-    pub result: [u8; 32],
-    pub nonce: [u8; 16],
-}
-
-#[queue_computation_accounts("in_range", payer)]
-#[derive(Accounts)]
-#[instruction(computation_offset: u64)]
-pub struct InRange<'info> {
-    #[account(mut)]
-    pub payer: Signer<'info>,
-    #[account(
-        init_if_needed,
-        space = 9,
-        payer = payer,
-        seeds = [&SIGN_PDA_SEED],
-        bump,
-        address = derive_sign_pda!(),
-    )]
-    pub sign_pda_account: Account<'info, SignerAccount>,
-    #[account(
-        address = derive_mxe_pda!()
-    )]
-    pub mxe_account: Box<Account<'info, MXEAccount>>,
-    #[account(
-        mut,
-        address = derive_mempool_pda!()
-    )]
-    /// CHECK: mempool_account, checked by the arcium program.
-    pub mempool_account: UncheckedAccount<'info>,
-    #[account(
-        mut,
-        address = derive_execpool_pda!()
-    )]
-    /// CHECK: executing_pool, checked by the arcium program.
-    pub executing_pool: UncheckedAccount<'info>,
-    #[account(
-        mut,
-        address = derive_comp_pda!(computation_offset)
-    )]
-    /// CHECK: computation_account, checked by the arcium program.
-    pub computation_account: UncheckedAccount<'info>,
-    #[account(
-        address = derive_comp_def_pda!(COMP_DEF_OFFSET_IN_RANGE)
-    )]
-    pub comp_def_account: Box<Account<'info, ComputationDefinitionAccount>>,
-    #[account(
-        mut,
-        address = derive_cluster_pda!(mxe_account)
-    )]
-    pub cluster_account: Box<Account<'info, Cluster>>,
-    #[account(
-        mut,
-        address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS,
-    )]
-    pub pool_account: Box<Account<'info, FeePool>>,
-    #[account(
-        address = ARCIUM_CLOCK_ACCOUNT_ADDRESS
-    )]
-    pub clock_account: Box<Account<'info, ClockAccount>>,
-    pub system_program: Program<'info, System>,
-    pub arcium_program: Program<'info, Arcium>,
-}
-
-#[callback_accounts("in_range")]
-#[derive(Accounts)]
-pub struct InRangeCallback<'info> {
-    pub arcium_program: Program<'info, Arcium>,
-    #[account(
-        address = derive_comp_def_pda!(COMP_DEF_OFFSET_IN_RANGE)
-    )]
-    pub comp_def_account: Box<Account<'info, ComputationDefinitionAccount>>,
-    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
-    /// CHECK: instructions_sysvar, checked by the account constraint
-    pub instructions_sysvar: AccountInfo<'info>,
-}
-
-#[init_computation_definition_accounts("in_range", payer)]
-#[derive(Accounts)]
-pub struct InitInRangeCompDef<'info> {
-    #[account(mut)]
-    pub payer: Signer<'info>,
-    #[account(
-        mut,
-        address = derive_mxe_pda!()
-    )]
-    pub mxe_account: Box<Account<'info, MXEAccount>>,
-    #[account(mut)]
-    /// CHECK: comp_def_account, checked by arcium program.
-    pub comp_def_account: UncheckedAccount<'info>,
-    pub arcium_program: Program<'info, Arcium>,
-    pub system_program: Program<'info, System>,
-}
-
-#[event]
-pub struct InRangeEvent {
-    pub result: [u8; 32],
-    pub nonce: [u8; 16],
-}
-
-#[queue_computation_accounts("and", payer)]
-#[derive(Accounts)]
-#[instruction(computation_offset: u64)]
-pub struct And<'info> {
-    #[account(mut)]
-    pub payer: Signer<'info>,
-    #[account(
-        init_if_needed,
-        space = 9,
-        payer = payer,
-        seeds = [&SIGN_PDA_SEED],
-        bump,
-        address = derive_sign_pda!(),
-    )]
-    pub sign_pda_account: Account<'info, SignerAccount>,
-    #[account(
-        address = derive_mxe_pda!()
-    )]
-    pub mxe_account: Box<Account<'info, MXEAccount>>,
-    #[account(
-        mut,
-        address = derive_mempool_pda!()
-    )]
-    /// CHECK: mempool_account, checked by the arcium program.
-    pub mempool_account: UncheckedAccount<'info>,
-    #[account(
-        mut,
-        address = derive_execpool_pda!()
-    )]
-    /// CHECK: executing_pool, checked by the arcium program.
-    pub executing_pool: UncheckedAccount<'info>,
-    #[account(
-        mut,
-        address = derive_comp_pda!(computation_offset)
-    )]
-    /// CHECK: computation_account, checked by the arcium program.
-    pub computation_account: UncheckedAccount<'info>,
-    #[account(
-        address = derive_comp_def_pda!(COMP_DEF_OFFSET_AND)
-    )]
-    pub comp_def_account: Box<Account<'info, ComputationDefinitionAccount>>,
-    #[account(
-        mut,
-        address = derive_cluster_pda!(mxe_account)
-    )]
-    pub cluster_account: Box<Account<'info, Cluster>>,
-    #[account(
-        mut,
-        address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS,
-    )]
-    pub pool_account: Box<Account<'info, FeePool>>,
-    #[account(
-        address = ARCIUM_CLOCK_ACCOUNT_ADDRESS
-    )]
-    pub clock_account: Box<Account<'info, ClockAccount>>,
-    pub system_program: Program<'info, System>,
-    pub arcium_program: Program<'info, Arcium>,
-}
-
-#[callback_accounts("and")]
-#[derive(Accounts)]
-pub struct AndCallback<'info> {
-    pub arcium_program: Program<'info, Arcium>,
-    #[account(
-        address = derive_comp_def_pda!(COMP_DEF_OFFSET_AND)
-    )]
-    pub comp_def_account: Box<Account<'info, ComputationDefinitionAccount>>,
-    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
-    /// CHECK: instructions_sysvar, checked by the account constraint
-    pub instructions_sysvar: AccountInfo<'info>,
-}
-
-#[init_computation_definition_accounts("and", payer)]
-#[derive(Accounts)]
-pub struct InitAndCompDef<'info> {
-    #[account(mut)]
-    pub payer: Signer<'info>,
-    #[account(
-        mut,
-        address = derive_mxe_pda!()
-    )]
-    pub mxe_account: Box<Account<'info, MXEAccount>>,
-    #[account(mut)]
-    /// CHECK: comp_def_account, checked by arcium program.
-    pub comp_def_account: UncheckedAccount<'info>,
-    pub arcium_program: Program<'info, Arcium>,
-    pub system_program: Program<'info, System>,
-}
-
-#[event]
-pub struct AndEvent {
-    pub result: [u8; 32],
-    pub nonce: [u8; 16],
-}
-
-#[queue_computation_accounts("or", payer)]
-#[derive(Accounts)]
-#[instruction(computation_offset: u64)]
-pub struct Or<'info> {
-    #[account(mut)]
-    pub payer: Signer<'info>,
-    #[account(
-        init_if_needed,
-        space = 9,
-        payer = payer,
-        seeds = [&SIGN_PDA_SEED],
-        bump,
-        address = derive_sign_pda!(),
-    )]
-    pub sign_pda_account: Account<'info, SignerAccount>,
-    #[account(
-        address = derive_mxe_pda!()
-    )]
-    pub mxe_account: Box<Account<'info, MXEAccount>>,
-    #[account(
-        mut,
-        address = derive_mempool_pda!()
-    )]
-    /// CHECK: mempool_account, checked by the arcium program.
-    pub mempool_account: UncheckedAccount<'info>,
-    #[account(
-        mut,
-        address = derive_execpool_pda!()
-    )]
-    /// CHECK: executing_pool, checked by the arcium program.
-    pub executing_pool: UncheckedAccount<'info>,
-    #[account(
-        mut,
-        address = derive_comp_pda!(computation_offset)
-    )]
-    /// CHECK: computation_account, checked by the arcium program.
-    pub computation_account: UncheckedAccount<'info>,
-    #[account(
-        address = derive_comp_def_pda!(COMP_DEF_OFFSET_OR)
-    )]
-    pub comp_def_account: Box<Account<'info, ComputationDefinitionAccount>>,
-    #[account(
-        mut,
-        address = derive_cluster_pda!(mxe_account)
-    )]
-    pub cluster_account: Box<Account<'info, Cluster>>,
-    #[account(
-        mut,
-        address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS,
-    )]
-    pub pool_account: Box<Account<'info, FeePool>>,
-    #[account(
-        address = ARCIUM_CLOCK_ACCOUNT_ADDRESS
-    )]
-    pub clock_account: Box<Account<'info, ClockAccount>>,
-    pub system_program: Program<'info, System>,
-    pub arcium_program: Program<'info, Arcium>,
-}
-
-#[callback_accounts("or")]
-#[derive(Accounts)]
-pub struct OrCallback<'info> {
-    pub arcium_program: Program<'info, Arcium>,
-    #[account(
-        address = derive_comp_def_pda!(COMP_DEF_OFFSET_OR)
-    )]
-    pub comp_def_account: Box<Account<'info, ComputationDefinitionAccount>>,
-    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
-    /// CHECK: instructions_sysvar, checked by the account constraint
-    pub instructions_sysvar: AccountInfo<'info>,
-}
-
-#[init_computation_definition_accounts("or", payer)]
-#[derive(Accounts)]
-pub struct InitOrCompDef<'info> {
-    #[account(mut)]
-    pub payer: Signer<'info>,
-    #[account(
-        mut,
-        address = derive_mxe_pda!()
-    )]
-    pub mxe_account: Box<Account<'info, MXEAccount>>,
-    #[account(mut)]
-    /// CHECK: comp_def_account, checked by arcium program.
-    pub comp_def_account: UncheckedAccount<'info>,
-    pub arcium_program: Program<'info, Arcium>,
-    pub system_program: Program<'info, System>,
-}
-
-#[event]
-pub struct OrEvent {
-    pub result: [u8; 32],
-    pub nonce: [u8; 16],
-}
-
-#[queue_computation_accounts("xor", payer)]
-#[derive(Accounts)]
-#[instruction(computation_offset: u64)]
-pub struct Xor<'info> {
-    #[account(mut)]
-    pub payer: Signer<'info>,
-    #[account(
-        init_if_needed,
-        space = 9,
-        payer = payer,
-        seeds = [&SIGN_PDA_SEED],
-        bump,
-        address = derive_sign_pda!(),
-    )]
-    pub sign_pda_account: Account<'info, SignerAccount>,
-    #[account(
-        address = derive_mxe_pda!()
-    )]
-    pub mxe_account: Box<Account<'info, MXEAccount>>,
-    #[account(
-        mut,
-        address = derive_mempool_pda!()
-    )]
-    /// CHECK: mempool_account, checked by the arcium program.
-    pub mempool_account: UncheckedAccount<'info>,
-    #[account(
-        mut,
-        address = derive_execpool_pda!()
-    )]
-    /// CHECK: executing_pool, checked by the arcium program.
-    pub executing_pool: UncheckedAccount<'info>,
-    #[account(
-        mut,
-        address = derive_comp_pda!(computation_offset)
-    )]
-    /// CHECK: computation_account, checked by the arcium program.
-    pub computation_account: UncheckedAccount<'info>,
-    #[account(
-        address = derive_comp_def_pda!(COMP_DEF_OFFSET_XOR)
-    )]
-    pub comp_def_account: Box<Account<'info, ComputationDefinitionAccount>>,
-    #[account(
-        mut,
-        address = derive_cluster_pda!(mxe_account)
-    )]
-    pub cluster_account: Box<Account<'info, Cluster>>,
-    #[account(
-        mut,
-        address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS,
-    )]
-    pub pool_account: Box<Account<'info, FeePool>>,
-    #[account(
-        address = ARCIUM_CLOCK_ACCOUNT_ADDRESS
-    )]
-    pub clock_account: Box<Account<'info, ClockAccount>>,
-    pub system_program: Program<'info, System>,
-    pub arcium_program: Program<'info, Arcium>,
-}
-
-#[callback_accounts("xor")]
-#[derive(Accounts)]
-pub struct XorCallback<'info> {
-    pub arcium_program: Program<'info, Arcium>,
-    #[account(
-        address = derive_comp_def_pda!(COMP_DEF_OFFSET_XOR)
-    )]
-    pub comp_def_account: Box<Account<'info, ComputationDefinitionAccount>>,
-    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
-    /// CHECK: instructions_sysvar, checked by the account constraint
-    pub instructions_sysvar: AccountInfo<'info>,
-}
-
-#[init_computation_definition_accounts("xor", payer)]
-#[derive(Accounts)]
-pub struct InitXorCompDef<'info> {
-    #[account(mut)]
-    pub payer: Signer<'info>,
-    #[account(
-        mut,
-        address = derive_mxe_pda!()
-    )]
-    pub mxe_account: Box<Account<'info, MXEAccount>>,
-    #[account(mut)]
-    /// CHECK: comp_def_account, checked by arcium program.
-    pub comp_def_account: UncheckedAccount<'info>,
-    pub arcium_program: Program<'info, Arcium>,
-    pub system_program: Program<'info, System>,
-}
-
-#[event]
-pub struct XorEvent {
-    pub result: [u8; 32],
-    pub nonce: [u8; 16],
-}
-
-#[queue_computation_accounts("not", payer)]
-#[derive(Accounts)]
-#[instruction(computation_offset: u64)]
-pub struct Not<'info> {
-    #[account(mut)]
-    pub payer: Signer<'info>,
-    #[account(
-        init_if_needed,
-        space = 9,
-        payer = payer,
-        seeds = [&SIGN_PDA_SEED],
-        bump,
-        address = derive_sign_pda!(),
-    )]
-    pub sign_pda_account: Account<'info, SignerAccount>,
-    #[account(
-        address = derive_mxe_pda!()
-    )]
-    pub mxe_account: Box<Account<'info, MXEAccount>>,
-    #[account(
-        mut,
-        address = derive_mempool_pda!()
-    )]
-    /// CHECK: mempool_account, checked by the arcium program.
-    pub mempool_account: UncheckedAccount<'info>,
-    #[account(
-        mut,
-        address = derive_execpool_pda!()
-    )]
-    /// CHECK: executing_pool, checked by the arcium program.
-    pub executing_pool: UncheckedAccount<'info>,
-    #[account(
-        mut,
-        address = derive_comp_pda!(computation_offset)
-    )]
-    /// CHECK: computation_account, checked by the arcium program.
-    pub computation_account: UncheckedAccount<'info>,
-    #[account(
-        address = derive_comp_def_pda!(COMP_DEF_OFFSET_NOT)
-    )]
-    pub comp_def_account: Box<Account<'info, ComputationDefinitionAccount>>,
-    #[account(
-        mut,
-        address = derive_cluster_pda!(mxe_account)
-    )]
-    pub cluster_account: Box<Account<'info, Cluster>>,
-    #[account(
-        mut,
-        address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS,
-    )]
-    pub pool_account: Box<Account<'info, FeePool>>,
-    #[account(
-        address = ARCIUM_CLOCK_ACCOUNT_ADDRESS
-    )]
-    pub clock_account: Box<Account<'info, ClockAccount>>,
-    pub system_program: Program<'info, System>,
-    pub arcium_program: Program<'info, Arcium>,
-}
-
-#[callback_accounts("not")]
-#[derive(Accounts)]
-pub struct NotCallback<'info> {
-    pub arcium_program: Program<'info, Arcium>,
-    #[account(
-        address = derive_comp_def_pda!(COMP_DEF_OFFSET_NOT)
-    )]
-    pub comp_def_account: Box<Account<'info, ComputationDefinitionAccount>>,
-    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
-    /// CHECK: instructions_sysvar, checked by the account constraint
-    pub instructions_sysvar: AccountInfo<'info>,
-}
-
-#[init_computation_definition_accounts("not", payer)]
-#[derive(Accounts)]
-pub struct InitNotCompDef<'info> {
-    #[account(mut)]
-    pub payer: Signer<'info>,
-    #[account(
-        mut,
-        address = derive_mxe_pda!()
-    )]
-    pub mxe_account: Box<Account<'info, MXEAccount>>,
-    #[account(mut)]
-    /// CHECK: comp_def_account, checked by arcium program.
-    pub comp_def_account: UncheckedAccount<'info>,
-    pub arcium_program: Program<'info, Arcium>,
-    pub system_program: Program<'info, System>,
-}
-
-#[event]
-pub struct NotEvent {
-    pub result: [u8; 32],
-    pub nonce: [u8; 16],
-}
-
-#[queue_computation_accounts("if_else", payer)]
-#[derive(Accounts)]
-#[instruction(computation_offset: u64)]
-pub struct IfElse<'info> {
-    #[account(mut)]
-    pub payer: Signer<'info>,
-    #[account(
-        init_if_needed,
-        space = 9,
-        payer = payer,
-        seeds = [&SIGN_PDA_SEED],
-        bump,
-        address = derive_sign_pda!(),
-    )]
-    pub sign_pda_account: Account<'info, SignerAccount>,
-    #[account(
-        address = derive_mxe_pda!()
-    )]
-    pub mxe_account: Box<Account<'info, MXEAccount>>,
-    #[account(
-        mut,
-        address = derive_mempool_pda!()
-    )]
-    /// CHECK: mempool_account, checked by the arcium program.
-    pub mempool_account: UncheckedAccount<'info>,
-    #[account(
-        mut,
-        address = derive_execpool_pda!()
-    )]
-    /// CHECK: executing_pool, checked by the arcium program.
-    pub executing_pool: UncheckedAccount<'info>,
-    #[account(
-        mut,
-        address = derive_comp_pda!(computation_offset)
-    )]
-    /// CHECK: computation_account, checked by the arcium program.
-    pub computation_account: UncheckedAccount<'info>,
-    #[account(
-        address = derive_comp_def_pda!(COMP_DEF_OFFSET_IF_ELSE)
-    )]
-    pub comp_def_account: Box<Account<'info, ComputationDefinitionAccount>>,
-    #[account(
-        mut,
-        address = derive_cluster_pda!(mxe_account)
-    )]
-    pub cluster_account: Box<Account<'info, Cluster>>,
-    #[account(
-        mut,
-        address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS,
-    )]
-    pub pool_account: Box<Account<'info, FeePool>>,
-    #[account(
-        address = ARCIUM_CLOCK_ACCOUNT_ADDRESS
-    )]
-    pub clock_account: Box<Account<'info, ClockAccount>>,
-    pub system_program: Program<'info, System>,
-    pub arcium_program: Program<'info, Arcium>,
-}
-
-#[callback_accounts("if_else")]
-#[derive(Accounts)]
-pub struct IfElseCallback<'info> {
-    pub arcium_program: Program<'info, Arcium>,
-    #[account(
-        address = derive_comp_def_pda!(COMP_DEF_OFFSET_IF_ELSE)
-    )]
-    pub comp_def_account: Box<Account<'info, ComputationDefinitionAccount>>,
-    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
-    /// CHECK: instructions_sysvar, checked by the account constraint
-    pub instructions_sysvar: AccountInfo<'info>,
-}
-
-#[init_computation_definition_accounts("if_else", payer)]
-#[derive(Accounts)]
-pub struct InitIfElseCompDef<'info> {
-    #[account(mut)]
-    pub payer: Signer<'info>,
-    #[account(
-        mut,
-        address = derive_mxe_pda!()
-    )]
-    pub mxe_account: Box<Account<'info, MXEAccount>>,
-    #[account(mut)]
-    /// CHECK: comp_def_account, checked by arcium program.
-    pub comp_def_account: UncheckedAccount<'info>,
-    pub arcium_program: Program<'info, Arcium>,
-    pub system_program: Program<'info, System>,
-}
-
-#[event]
-pub struct IfElseEvent {
-    pub result: [u8; 32],
-    pub nonce: [u8; 16],
-}
-
-#[queue_computation_accounts("vote_tally", payer)]
-#[derive(Accounts)]
-#[instruction(computation_offset: u64)]
-pub struct VoteTally<'info> {
-    #[account(mut)]
-    pub payer: Signer<'info>,
-    #[account(
-        init_if_needed,
-        space = 9,
-        payer = payer,
-        seeds = [&SIGN_PDA_SEED],
-        bump,
-        address = derive_sign_pda!(),
-    )]
-    pub sign_pda_account: Account<'info, SignerAccount>,
-    #[account(
-        address = derive_mxe_pda!()
-    )]
-    pub mxe_account: Box<Account<'info, MXEAccount>>,
-    #[account(
-        mut,
-        address = derive_mempool_pda!()
-    )]
-    /// CHECK: mempool_account, checked by the arcium program.
-    pub mempool_account: UncheckedAccount<'info>,
-    #[account(
-        mut,
-        address = derive_execpool_pda!()
-    )]
-    /// CHECK: executing_pool, checked by the arcium program.
-    pub executing_pool: UncheckedAccount<'info>,
-    #[account(
-        mut,
-        address = derive_comp_pda!(computation_offset)
-    )]
-    /// CHECK: computation_account, checked by the arcium program.
-    pub computation_account: UncheckedAccount<'info>,
-    #[account(
-        address = derive_comp_def_pda!(COMP_DEF_OFFSET_VOTE_TALLY)
-    )]
-    pub comp_def_account: Box<Account<'info, ComputationDefinitionAccount>>,
-    #[account(
-        mut,
-        address = derive_cluster_pda!(mxe_account)
-    )]
-    pub cluster_account: Box<Account<'info, Cluster>>,
-    #[account(
-        mut,
-        address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS,
-    )]
-    pub pool_account: Box<Account<'info, FeePool>>,
-    #[account(
-        address = ARCIUM_CLOCK_ACCOUNT_ADDRESS
-    )]
-    pub clock_account: Box<Account<'info, ClockAccount>>,
-    pub system_program: Program<'info, System>,
-    pub arcium_program: Program<'info, Arcium>,
-}
-
-#[callback_accounts("vote_tally")]
-#[derive(Accounts)]
-pub struct VoteTallyCallback<'info> {
-    pub arcium_program: Program<'info, Arcium>,
-    #[account(
-        address = derive_comp_def_pda!(COMP_DEF_OFFSET_VOTE_TALLY)
-    )]
-    pub comp_def_account: Box<Account<'info, ComputationDefinitionAccount>>,
-    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
-    /// CHECK: instructions_sysvar, checked by the account constraint
-    pub instructions_sysvar: AccountInfo<'info>,
-}
-
-#[init_computation_definition_accounts("vote_tally", payer)]
-#[derive(Accounts)]
-pub struct InitVoteTallyCompDef<'info> {
-    #[account(mut)]
-    pub payer: Signer<'info>,
-    #[account(
-        mut,
-        address = derive_mxe_pda!()
-    )]
-    pub mxe_account: Box<Account<'info, MXEAccount>>,
-    #[account(mut)]
-    /// CHECK: comp_def_account, checked by arcium program.
-    pub comp_def_account: UncheckedAccount<'info>,
-    pub arcium_program: Program<'info, Arcium>,
-    pub system_program: Program<'info, System>,
-}
-
-#[event]
-pub struct VoteTallyEvent {
-    pub result: [u8; 32],
-    pub nonce: [u8; 16],
-}
-
-#[queue_computation_accounts("average", payer)]
-#[derive(Accounts)]
-#[instruction(computation_offset: u64)]
-pub struct Average<'info> {
-    #[account(mut)]
-    pub payer: Signer<'info>,
-    #[account(
-        init_if_needed,
-        space = 9,
-        payer = payer,
-        seeds = [&SIGN_PDA_SEED],
-        bump,
-        address = derive_sign_pda!(),
-    )]
-    pub sign_pda_account: Account<'info, SignerAccount>,
-    #[account(
-        address = derive_mxe_pda!()
-    )]
-    pub mxe_account: Box<Account<'info, MXEAccount>>,
-    #[account(
-        mut,
-        address = derive_mempool_pda!()
-    )]
-    /// CHECK: mempool_account, checked by the arcium program.
-    pub mempool_account: UncheckedAccount<'info>,
-    #[account(
-        mut,
-        address = derive_execpool_pda!()
-    )]
-    /// CHECK: executing_pool, checked by the arcium program.
-    pub executing_pool: UncheckedAccount<'info>,
-    #[account(
-        mut,
-        address = derive_comp_pda!(computation_offset)
-    )]
-    /// CHECK: computation_account, checked by the arcium program.
-    pub computation_account: UncheckedAccount<'info>,
-    #[account(
-        address = derive_comp_def_pda!(COMP_DEF_OFFSET_AVERAGE)
-    )]
-    pub comp_def_account: Box<Account<'info, ComputationDefinitionAccount>>,
-    #[account(
-        mut,
-        address = derive_cluster_pda!(mxe_account)
-    )]
-    pub cluster_account: Box<Account<'info, Cluster>>,
-    #[account(
-        mut,
-        address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS,
-    )]
-    pub pool_account: Box<Account<'info, FeePool>>,
-    #[account(
-        address = ARCIUM_CLOCK_ACCOUNT_ADDRESS
-    )]
-    pub clock_account: Box<Account<'info, ClockAccount>>,
-    pub system_program: Program<'info, System>,
-    pub arcium_program: Program<'info, Arcium>,
-}
-
-#[callback_accounts("average")]
-#[derive(Accounts)]
-pub struct AverageCallback<'info> {
-    pub arcium_program: Program<'info, Arcium>,
-    #[account(
-        address = derive_comp_def_pda!(COMP_DEF_OFFSET_AVERAGE)
-    )]
-    pub comp_def_account: Box<Account<'info, ComputationDefinitionAccount>>,
-    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
-    /// CHECK: instructions_sysvar, checked by the account constraint
-    pub instructions_sysvar: AccountInfo<'info>,
-}
-
-#[init_computation_definition_accounts("average", payer)]
-#[derive(Accounts)]
-pub struct InitAverageCompDef<'info> {
-    #[account(mut)]
-    pub payer: Signer<'info>,
-    #[account(
-        mut,
-        address = derive_mxe_pda!()
-    )]
-    pub mxe_account: Box<Account<'info, MXEAccount>>,
-    #[account(mut)]
-    /// CHECK: comp_def_account, checked by arcium program.
-    pub comp_def_account: UncheckedAccount<'info>,
-    pub arcium_program: Program<'info, Arcium>,
-    pub system_program: Program<'info, System>,
-}
-
-#[event]
-pub struct AverageEvent {
-    pub result: [u8; 32],
-    pub nonce: [u8; 16],
-}
-
-#[queue_computation_accounts("sum", payer)]
-#[derive(Accounts)]
-#[instruction(computation_offset: u64)]
-pub struct Sum<'info> {
-    #[account(mut)]
-    pub payer: Signer<'info>,
-    #[account(
-        init_if_needed,
-        space = 9,
-        payer = payer,
-        seeds = [&SIGN_PDA_SEED],
-        bump,
-        address = derive_sign_pda!(),
-    )]
-    pub sign_pda_account: Account<'info, SignerAccount>,
-    #[account(
-        address = derive_mxe_pda!()
-    )]
-    pub mxe_account: Box<Account<'info, MXEAccount>>,
-    #[account(
-        mut,
-        address = derive_mempool_pda!()
-    )]
-    /// CHECK: mempool_account, checked by the arcium program.
-    pub mempool_account: UncheckedAccount<'info>,
-    #[account(
-        mut,
-        address = derive_execpool_pda!()
-    )]
-    /// CHECK: executing_pool, checked by the arcium program.
-    pub executing_pool: UncheckedAccount<'info>,
-    #[account(
-        mut,
-        address = derive_comp_pda!(computation_offset)
-    )]
-    /// CHECK: computation_account, checked by the arcium program.
-    pub computation_account: UncheckedAccount<'info>,
-    #[account(
-        address = derive_comp_def_pda!(COMP_DEF_OFFSET_SUM)
-    )]
-    pub comp_def_account: Box<Account<'info, ComputationDefinitionAccount>>,
-    #[account(
-        mut,
-        address = derive_cluster_pda!(mxe_account)
-    )]
-    pub cluster_account: Box<Account<'info, Cluster>>,
-    #[account(
-        mut,
-        address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS,
-    )]
-    pub pool_account: Box<Account<'info, FeePool>>,
-    #[account(
-        address = ARCIUM_CLOCK_ACCOUNT_ADDRESS
-    )]
-    pub clock_account: Box<Account<'info, ClockAccount>>,
-    pub system_program: Program<'info, System>,
-    pub arcium_program: Program<'info, Arcium>,
-}
-
-#[callback_accounts("sum")]
-#[derive(Accounts)]
-pub struct SumCallback<'info> {
-    pub arcium_program: Program<'info, Arcium>,
-    #[account(
-        address = derive_comp_def_pda!(COMP_DEF_OFFSET_SUM)
-    )]
-    pub comp_def_account: Box<Account<'info, ComputationDefinitionAccount>>,
-    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
-    /// CHECK: instructions_sysvar, checked by the account constraint
-    pub instructions_sysvar: AccountInfo<'info>,
-}
-
-#[init_computation_definition_accounts("sum", payer)]
-#[derive(Accounts)]
-pub struct InitSumCompDef<'info> {
-    #[account(mut)]
-    pub payer: Signer<'info>,
-    #[account(
-        mut,
-        address = derive_mxe_pda!()
-    )]
-    pub mxe_account: Box<Account<'info, MXEAccount>>,
-    #[account(mut)]
-    /// CHECK: comp_def_account, checked by arcium program.
-    pub comp_def_account: UncheckedAccount<'info>,
-    pub arcium_program: Program<'info, Arcium>,
-    pub system_program: Program<'info, System>,
-}
-
-#[event]
-pub struct SumEvent {
-    pub result: [u8; 32],
-    pub nonce: [u8; 16],
-}
-
-#[queue_computation_accounts("min", payer)]
-#[derive(Accounts)]
-#[instruction(computation_offset: u64)]
-pub struct Min<'info> {
-    #[account(mut)]
-    pub payer: Signer<'info>,
-    #[account(
-        init_if_needed,
-        space = 9,
-        payer = payer,
-        seeds = [&SIGN_PDA_SEED],
-        bump,
-        address = derive_sign_pda!(),
-    )]
-    pub sign_pda_account: Account<'info, SignerAccount>,
-    #[account(
-        address = derive_mxe_pda!()
-    )]
-    pub mxe_account: Box<Account<'info, MXEAccount>>,
-    #[account(
-        mut,
-        address = derive_mempool_pda!()
-    )]
-    /// CHECK: mempool_account, checked by the arcium program.
-    pub mempool_account: UncheckedAccount<'info>,
-    #[account(
-        mut,
-        address = derive_execpool_pda!()
-    )]
-    /// CHECK: executing_pool, checked by the arcium program.
-    pub executing_pool: UncheckedAccount<'info>,
-    #[account(
-        mut,
-        address = derive_comp_pda!(computation_offset)
-    )]
-    /// CHECK: computation_account, checked by the arcium program.
-    pub computation_account: UncheckedAccount<'info>,
-    #[account(
-        address = derive_comp_def_pda!(COMP_DEF_OFFSET_MIN)
-    )]
-    pub comp_def_account: Box<Account<'info, ComputationDefinitionAccount>>,
-    #[account(
-        mut,
-        address = derive_cluster_pda!(mxe_account)
-    )]
-    pub cluster_account: Box<Account<'info, Cluster>>,
-    #[account(
-        mut,
-        address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS,
-    )]
-    pub pool_account: Box<Account<'info, FeePool>>,
-    #[account(
-        address = ARCIUM_CLOCK_ACCOUNT_ADDRESS
-    )]
-    pub clock_account: Box<Account<'info, ClockAccount>>,
-    pub system_program: Program<'info, System>,
-    pub arcium_program: Program<'info, Arcium>,
-}
-
-#[callback_accounts("min")]
-#[derive(Accounts)]
-pub struct MinCallback<'info> {
-    pub arcium_program: Program<'info, Arcium>,
-    #[account(
-        address = derive_comp_def_pda!(COMP_DEF_OFFSET_MIN)
-    )]
-    pub comp_def_account: Box<Account<'info, ComputationDefinitionAccount>>,
-    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
-    /// CHECK: instructions_sysvar, checked by the account constraint
-    pub instructions_sysvar: AccountInfo<'info>,
-}
-
-#[init_computation_definition_accounts("min", payer)]
-#[derive(Accounts)]
-pub struct InitMinCompDef<'info> {
-    #[account(mut)]
-    pub payer: Signer<'info>,
-    #[account(
-        mut,
-        address = derive_mxe_pda!()
-    )]
-    pub mxe_account: Box<Account<'info, MXEAccount>>,
-    #[account(mut)]
-    /// CHECK: comp_def_account, checked by arcium program.
-    pub comp_def_account: UncheckedAccount<'info>,
-    pub arcium_program: Program<'info, Arcium>,
-    pub system_program: Program<'info, System>,
-}
-
-#[event]
-pub struct MinEvent {
-    pub result: [u8; 32],
-    pub nonce: [u8; 16],
-}
-
-#[queue_computation_accounts("max", payer)]
-#[derive(Accounts)]
-#[instruction(computation_offset: u64)]
-pub struct Max<'info> {
-    #[account(mut)]
-    pub payer: Signer<'info>,
-    #[account(
-        init_if_needed,
-        space = 9,
-        payer = payer,
-        seeds = [&SIGN_PDA_SEED],
-        bump,
-        address = derive_sign_pda!(),
-    )]
-    pub sign_pda_account: Account<'info, SignerAccount>,
-    #[account(
-        address = derive_mxe_pda!()
-    )]
-    pub mxe_account: Box<Account<'info, MXEAccount>>,
-    #[account(
-        mut,
-        address = derive_mempool_pda!()
-    )]
-    /// CHECK: mempool_account, checked by the arcium program.
-    pub mempool_account: UncheckedAccount<'info>,
-    #[account(
-        mut,
-        address = derive_execpool_pda!()
-    )]
-    /// CHECK: executing_pool, checked by the arcium program.
-    pub executing_pool: UncheckedAccount<'info>,
-    #[account(
-        mut,
-        address = derive_comp_pda!(computation_offset)
-    )]
-    /// CHECK: computation_account, checked by the arcium program.
-    pub computation_account: UncheckedAccount<'info>,
-    #[account(
-        address = derive_comp_def_pda!(COMP_DEF_OFFSET_MAX)
-    )]
-    pub comp_def_account: Box<Account<'info, ComputationDefinitionAccount>>,
-    #[account(
-        mut,
-        address = derive_cluster_pda!(mxe_account)
-    )]
-    pub cluster_account: Box<Account<'info, Cluster>>,
-    #[account(
-        mut,
-        address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS,
-    )]
-    pub pool_account: Box<Account<'info, FeePool>>,
-    #[account(
-        address = ARCIUM_CLOCK_ACCOUNT_ADDRESS
-    )]
-    pub clock_account: Box<Account<'info, ClockAccount>>,
-    pub system_program: Program<'info, System>,
-    pub arcium_program: Program<'info, Arcium>,
-}
-
-#[callback_accounts("max")]
-#[derive(Accounts)]
-pub struct MaxCallback<'info> {
-    pub arcium_program: Program<'info, Arcium>,
-    #[account(
-        address = derive_comp_def_pda!(COMP_DEF_OFFSET_MAX)
-    )]
-    pub comp_def_account: Box<Account<'info, ComputationDefinitionAccount>>,
-    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
-    /// CHECK: instructions_sysvar, checked by the account constraint
-    pub instructions_sysvar: AccountInfo<'info>,
-}
-
-#[init_computation_definition_accounts("max", payer)]
-#[derive(Accounts)]
-pub struct InitMaxCompDef<'info> {
-    #[account(mut)]
-    pub payer: Signer<'info>,
-    #[account(
-        mut,
-        address = derive_mxe_pda!()
-    )]
-    pub mxe_account: Box<Account<'info, MXEAccount>>,
-    #[account(mut)]
-    /// CHECK: comp_def_account, checked by arcium program.
-    pub comp_def_account: UncheckedAccount<'info>,
-    pub arcium_program: Program<'info, Arcium>,
-    pub system_program: Program<'info, System>,
-}
-
-#[event]
-pub struct MaxEvent {
-    pub result: [u8; 32],
-    pub nonce: [u8; 16],
-}
-
-#[queue_computation_accounts("median", payer)]
-#[derive(Accounts)]
-#[instruction(computation_offset: u64)]
-pub struct Median<'info> {
-    #[account(mut)]
-    pub payer: Signer<'info>,
-    #[account(
-        init_if_needed,
-        space = 9,
-        payer = payer,
-        seeds = [&SIGN_PDA_SEED],
-        bump,
-        address = derive_sign_pda!(),
-    )]
-    pub sign_pda_account: Account<'info, SignerAccount>,
-    #[account(
-        address = derive_mxe_pda!()
-    )]
-    pub mxe_account: Box<Account<'info, MXEAccount>>,
-    #[account(
-        mut,
-        address = derive_mempool_pda!()
-    )]
-    /// CHECK: mempool_account, checked by the arcium program.
-    pub mempool_account: UncheckedAccount<'info>,
-    #[account(
-        mut,
-        address = derive_execpool_pda!()
-    )]
-    /// CHECK: executing_pool, checked by the arcium program.
-    pub executing_pool: UncheckedAccount<'info>,
-    #[account(
-        mut,
-        address = derive_comp_pda!(computation_offset)
-    )]
-    /// CHECK: computation_account, checked by the arcium program.
-    pub computation_account: UncheckedAccount<'info>,
-    #[account(
-        address = derive_comp_def_pda!(COMP_DEF_OFFSET_MEDIAN)
-    )]
-    pub comp_def_account: Box<Account<'info, ComputationDefinitionAccount>>,
-    #[account(
-        mut,
-        address = derive_cluster_pda!(mxe_account)
-    )]
-    pub cluster_account: Box<Account<'info, Cluster>>,
-    #[account(
-        mut,
-        address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS,
-    )]
-    pub pool_account: Box<Account<'info, FeePool>>,
-    #[account(
-        address = ARCIUM_CLOCK_ACCOUNT_ADDRESS
-    )]
-    pub clock_account: Box<Account<'info, ClockAccount>>,
-    pub system_program: Program<'info, System>,
-    pub arcium_program: Program<'info, Arcium>,
-}
-
-#[callback_accounts("median")]
-#[derive(Accounts)]
-pub struct MedianCallback<'info> {
-    pub arcium_program: Program<'info, Arcium>,
-    #[account(
-        address = derive_comp_def_pda!(COMP_DEF_OFFSET_MEDIAN)
-    )]
-    pub comp_def_account: Box<Account<'info, ComputationDefinitionAccount>>,
-    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
-    /// CHECK: instructions_sysvar, checked by the account constraint
-    pub instructions_sysvar: AccountInfo<'info>,
-}
-
-#[init_computation_definition_accounts("median", payer)]
-#[derive(Accounts)]
-pub struct InitMedianCompDef<'info> {
-    #[account(mut)]
-    pub payer: Signer<'info>,
-    #[account(
-        mut,
-        address = derive_mxe_pda!()
-    )]
-    pub mxe_account: Box<Account<'info, MXEAccount>>,
-    #[account(mut)]
-    /// CHECK: comp_def_account, checked by arcium program.
-    pub comp_def_account: UncheckedAccount<'info>,
-    pub arcium_program: Program<'info, Arcium>,
-    pub system_program: Program<'info, System>,
-}
-
-#[event]
-pub struct MedianEvent {
-    pub result: [u8; 32],
-    pub nonce: [u8; 16],
-}
-
-#[queue_computation_accounts("weighted_average", payer)]
-#[derive(Accounts)]
-#[instruction(computation_offset: u64)]
-pub struct WeightedAverage<'info> {
-    #[account(mut)]
-    pub payer: Signer<'info>,
-    #[account(
-        init_if_needed,
-        space = 9,
-        payer = payer,
-        seeds = [&SIGN_PDA_SEED],
-        bump,
-        address = derive_sign_pda!(),
-    )]
-    pub sign_pda_account: Account<'info, SignerAccount>,
-    #[account(
-        address = derive_mxe_pda!()
-    )]
-    pub mxe_account: Box<Account<'info, MXEAccount>>,
-    #[account(
-        mut,
-        address = derive_mempool_pda!()
-    )]
-    /// CHECK: mempool_account, checked by the arcium program.
-    pub mempool_account: UncheckedAccount<'info>,
-    #[account(
-        mut,
-        address = derive_execpool_pda!()
-    )]
-    /// CHECK: executing_pool, checked by the arcium program.
-    pub executing_pool: UncheckedAccount<'info>,
-    #[account(
-        mut,
-        address = derive_comp_pda!(computation_offset)
-    )]
-    /// CHECK: computation_account, checked by the arcium program.
-    pub computation_account: UncheckedAccount<'info>,
-    #[account(
-        address = derive_comp_def_pda!(COMP_DEF_OFFSET_WEIGHTED_AVERAGE)
-    )]
-    pub comp_def_account: Box<Account<'info, ComputationDefinitionAccount>>,
-    #[account(
-        mut,
-        address = derive_cluster_pda!(mxe_account)
-    )]
-    pub cluster_account: Box<Account<'info, Cluster>>,
-    #[account(
-        mut,
-        address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS,
-    )]
-    pub pool_account: Box<Account<'info, FeePool>>,
-    #[account(
-        address = ARCIUM_CLOCK_ACCOUNT_ADDRESS
-    )]
-    pub clock_account: Box<Account<'info, ClockAccount>>,
-    pub system_program: Program<'info, System>,
-    pub arcium_program: Program<'info, Arcium>,
-}
-
-#[callback_accounts("weighted_average")]
-#[derive(Accounts)]
-pub struct WeightedAverageCallback<'info> {
-    pub arcium_program: Program<'info, Arcium>,
-    #[account(
-        address = derive_comp_def_pda!(COMP_DEF_OFFSET_WEIGHTED_AVERAGE)
-    )]
-    pub comp_def_account: Box<Account<'info, ComputationDefinitionAccount>>,
-    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
-    /// CHECK: instructions_sysvar, checked by the account constraint
-    pub instructions_sysvar: AccountInfo<'info>,
-}
-
-#[init_computation_definition_accounts("weighted_average", payer)]
-#[derive(Accounts)]
-pub struct InitWeightedAverageCompDef<'info> {
-    #[account(mut)]
-    pub payer: Signer<'info>,
-    #[account(
-        mut,
-        address = derive_mxe_pda!()
-    )]
-    pub mxe_account: Box<Account<'info, MXEAccount>>,
-    #[account(mut)]
-    /// CHECK: comp_def_account, checked by arcium program.
-    pub comp_def_account: UncheckedAccount<'info>,
-    pub arcium_program: Program<'info, Arcium>,
-    pub system_program: Program<'info, System>,
-}
-
-#[event]
-pub struct WeightedAverageEvent {
-    pub result: [u8; 32],
-    pub nonce: [u8; 16],
-}
-
-#[queue_computation_accounts("credit_score", payer)]
-#[derive(Accounts)]
-#[instruction(computation_offset: u64)]
-pub struct CreditScore<'info> {
-    #[account(mut)]
-    pub payer: Signer<'info>,
-    #[account(
-        init_if_needed,
-        space = 9,
-        payer = payer,
-        seeds = [&SIGN_PDA_SEED],
-        bump,
-        address = derive_sign_pda!(),
-    )]
-    pub sign_pda_account: Account<'info, SignerAccount>,
-    #[account(
-        address = derive_mxe_pda!()
-    )]
-    pub mxe_account: Box<Account<'info, MXEAccount>>,
-    #[account(
-        mut,
-        address = derive_mempool_pda!()
-    )]
-    /// CHECK: mempool_account, checked by the arcium program.
-    pub mempool_account: UncheckedAccount<'info>,
-    #[account(
-        mut,
-        address = derive_execpool_pda!()
-    )]
-    /// CHECK: executing_pool, checked by the arcium program.
-    pub executing_pool: UncheckedAccount<'info>,
-    #[account(
-        mut,
-        address = derive_comp_pda!(computation_offset)
-    )]
-    /// CHECK: computation_account, checked by the arcium program.
-    pub computation_account: UncheckedAccount<'info>,
-    #[account(
-        address = derive_comp_def_pda!(COMP_DEF_OFFSET_CREDIT_SCORE)
-    )]
-    pub comp_def_account: Box<Account<'info, ComputationDefinitionAccount>>,
-    #[account(
-        mut,
-        address = derive_cluster_pda!(mxe_account)
-    )]
-    pub cluster_account: Box<Account<'info, Cluster>>,
-    #[account(
-        mut,
-        address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS,
-    )]
-    pub pool_account: Box<Account<'info, FeePool>>,
-    #[account(
-        address = ARCIUM_CLOCK_ACCOUNT_ADDRESS
-    )]
-    pub clock_account: Box<Account<'info, ClockAccount>>,
-    pub system_program: Program<'info, System>,
-    pub arcium_program: Program<'info, Arcium>,
-}
-
-#[callback_accounts("credit_score")]
-#[derive(Accounts)]
-pub struct CreditScoreCallback<'info> {
-    pub arcium_program: Program<'info, Arcium>,
-    #[account(
-        address = derive_comp_def_pda!(COMP_DEF_OFFSET_CREDIT_SCORE)
-    )]
-    pub comp_def_account: Box<Account<'info, ComputationDefinitionAccount>>,
-    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
-    /// CHECK: instructions_sysvar, checked by the account constraint
-    pub instructions_sysvar: AccountInfo<'info>,
-}
-
-#[init_computation_definition_accounts("credit_score", payer)]
-#[derive(Accounts)]
-pub struct InitCreditScoreCompDef<'info> {
-    #[account(mut)]
-    pub payer: Signer<'info>,
-    #[account(
-        mut,
-        address = derive_mxe_pda!()
-    )]
-    pub mxe_account: Box<Account<'info, MXEAccount>>,
-    #[account(mut)]
-    /// CHECK: comp_def_account, checked by arcium program.
-    pub comp_def_account: UncheckedAccount<'info>,
-    pub arcium_program: Program<'info, Arcium>,
-    pub system_program: Program<'info, System>,
-}
-
-#[event]
-pub struct CreditScoreEvent {
-    pub score: [u8; 32],
-    pub approved: [u8; 32],
-    pub nonce: [u8; 16],
-}
-
-#[queue_computation_accounts("health_risk", payer)]
-#[derive(Accounts)]
-#[instruction(computation_offset: u64)]
-pub struct HealthRisk<'info> {
-    #[account(mut)]
-    pub payer: Signer<'info>,
-    #[account(
-        init_if_needed,
-        space = 9,
-        payer = payer,
-        seeds = [&SIGN_PDA_SEED],
-        bump,
-        address = derive_sign_pda!(),
-    )]
-    pub sign_pda_account: Account<'info, SignerAccount>,
-    #[account(
-        address = derive_mxe_pda!()
-    )]
-    pub mxe_account: Box<Account<'info, MXEAccount>>,
-    #[account(
-        mut,
-        address = derive_mempool_pda!()
-    )]
-    /// CHECK: mempool_account, checked by the arcium program.
-    pub mempool_account: UncheckedAccount<'info>,
-    #[account(
-        mut,
-        address = derive_execpool_pda!()
-    )]
-    /// CHECK: executing_pool, checked by the arcium program.
-    pub executing_pool: UncheckedAccount<'info>,
-    #[account(
-        mut,
-        address = derive_comp_pda!(computation_offset)
-    )]
-    /// CHECK: computation_account, checked by the arcium program.
-    pub computation_account: UncheckedAccount<'info>,
-    #[account(
-        address = derive_comp_def_pda!(COMP_DEF_OFFSET_HEALTH_RISK)
-    )]
-    pub comp_def_account: Box<Account<'info, ComputationDefinitionAccount>>,
-    #[account(
-        mut,
-        address = derive_cluster_pda!(mxe_account)
-    )]
-    pub cluster_account: Box<Account<'info, Cluster>>,
-    #[account(
-        mut,
-        address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS,
-    )]
-    pub pool_account: Box<Account<'info, FeePool>>,
-    #[account(
-        address = ARCIUM_CLOCK_ACCOUNT_ADDRESS
-    )]
-    pub clock_account: Box<Account<'info, ClockAccount>>,
-    pub system_program: Program<'info, System>,
-    pub arcium_program: Program<'info, Arcium>,
-}
-
-#[callback_accounts("health_risk")]
-#[derive(Accounts)]
-pub struct HealthRiskCallback<'info> {
-    pub arcium_program: Program<'info, Arcium>,
-    #[account(
-        address = derive_comp_def_pda!(COMP_DEF_OFFSET_HEALTH_RISK)
-    )]
-    pub comp_def_account: Box<Account<'info, ComputationDefinitionAccount>>,
-    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
-    /// CHECK: instructions_sysvar, checked by the account constraint
-    pub instructions_sysvar: AccountInfo<'info>,
-}
-
-#[init_computation_definition_accounts("health_risk", payer)]
-#[derive(Accounts)]
-pub struct InitHealthRiskCompDef<'info> {
-    #[account(mut)]
-    pub payer: Signer<'info>,
-    #[account(
-        mut,
-        address = derive_mxe_pda!()
-    )]
-    pub mxe_account: Box<Account<'info, MXEAccount>>,
-    #[account(mut)]
-    /// CHECK: comp_def_account, checked by arcium program.
-    pub comp_def_account: UncheckedAccount<'info>,
-    pub arcium_program: Program<'info, Arcium>,
-    pub system_program: Program<'info, System>,
-}
-
-#[event]
-pub struct HealthRiskEvent {
-    pub risk_score: [u8; 32],
-    pub risk_category: [u8; 32],
-    pub nonce: [u8; 16],
+/// Prepaid credits of one tenant, in lamports held by the account itself.
+/// Queuing moves the cost from `balance` to `pending`; the callback spends
+/// it, or refunds it to `balance` when the computation fails. The tenant can
+/// withdraw `balance` at any time.
+#[account]
+#[derive(InitSpace)]
+pub struct TenantCredits {
+    pub tenant: Pubkey,
+    pub balance: u64,
+    /// Debited by computations whose callback has not landed yet.
+    pub pending: u64,
+    pub bump: u8,
+}
+
+/// Who may queue computations on our MXE and cluster.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub enum Gate {
+    /// Any payer.
+    Open,
+    /// Payers in `Config::allowed_payers`.
+    AllowList,
+    /// Any payer, as long as this key co-signs, e.g. the server's.
+    CoSigner(Pubkey),
+}
+
+/// Where a computation definition's circuit lives and the hash it was pinned
+/// to, so clients can discover sources instead of assuming them.
+#[account]
+#[derive(InitSpace)]
+pub struct CircuitRegistry {
+    pub comp_def_offset: u32,
+    #[max_len(32)]
+    pub circuit: String,
+    /// Empty when the circuit was uploaded on-chain.
+    #[max_len(200)]
+    pub source: String,
+    pub hash: [u8; 32],
+    pub bump: u8,
+}
+
+impl CircuitRegistry {
+    pub const MAX_SOURCE_LEN: usize = 200;
 }
 
 #[error_code]
@@ -4244,4 +1135,34 @@ pub enum ErrorCode {
     AbortedComputation,
     #[msg("Cluster not set")]
     ClusterNotSet,
+    #[msg("Circuit hash is a placeholder; rebuild with the compiled circuit in build/")]
+    CircuitHashMissing,
+    #[msg("Circuit source URL is too long")]
+    CircuitSourceTooLong,
+    #[msg("Computation produced more ciphertexts than a result account holds")]
+    TooManyOutputs,
+    #[msg("Computation is still queued")]
+    ComputationPending,
+    #[msg("Intermediate belongs to another payer")]
+    IntermediateNotOwned,
+    #[msg("Intermediate has no value yet")]
+    IntermediateNotReady,
+    #[msg("Signer is not the program authority")]
+    NotAuthority,
+    #[msg("Payer may not queue computations")]
+    Unauthorized,
+    #[msg("Allow-list is full")]
+    AllowListFull,
+    #[msg("Fee table is full")]
+    FeeTableFull,
+    #[msg("Token accounts and program are required for SPL fees")]
+    MissingFeeAccounts,
+    #[msg("Fee token account has the wrong mint or owner")]
+    InvalidFeeAccount,
+    #[msg("Not enough prepaid credits")]
+    InsufficientCredits,
+    #[msg("Withdrawal would leave the treasury below its rent-exempt minimum")]
+    TreasuryBelowRent,
+    #[msg("Credit balance overflow")]
+    CreditsOverflow,
 }
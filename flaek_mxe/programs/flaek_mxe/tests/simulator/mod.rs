@@ -0,0 +1,688 @@
+//! MPC-free harness: runs flaek_mxe natively under solana-program-test next
+//! to a stand-in for the Arcium program. The stand-in records every
+//! `queue_computation` CPI and can deliver a callback the way the cluster
+//! would, so argument packing, callbacks and events can be tested offline.
+//!
+//! Nothing is encrypted here: `ct` and `plain` treat a 32-byte ciphertext as
+//! a little-endian plaintext, which tests feed to `encrypted_ixs::reference`.
+
+#![allow(dead_code)]
+
+use std::{collections::HashMap, sync::Mutex};
+
+use anchor_lang::{
+    prelude::*,
+    solana_program::{
+        entrypoint::ProgramResult, hash::hashv, instruction::Instruction, program::invoke,
+    },
+    Discriminator, InstructionData, ToAccountMetas,
+};
+use anchor_spl::token::spl_token::{
+    self,
+    solana_program::{program_option::COption, program_pack::Pack},
+    state::{Account as TokenAccount, AccountState, Mint},
+};
+use arcium_anchor::prelude::*;
+use arcium_client::idl::arcium::{client::args::QueueComputation, types::Argument};
+use base64::{engine::general_purpose::STANDARD, Engine};
+use flaek_mxe::{
+    CircuitRegistry, Config, Gate, ATTESTATION_SEED, CIRCUIT_REGISTRY_SEED,
+    COMPUTATION_RESULT_SEED, CONFIG_SEED, CREDITS_SEED, ID, INTERMEDIATE_SEED, TREASURY_SEED,
+};
+use solana_program_test::{
+    processor, BanksClientError, BanksTransactionResultWithMetadata, ProgramTest,
+    ProgramTestContext,
+};
+use solana_sdk::{
+    account::Account as SolanaAccount,
+    bpf_loader_upgradeable::{self, UpgradeableLoaderState},
+    instruction::InstructionError,
+    signature::{Keypair, Signer},
+    transaction::{Transaction, TransactionError},
+};
+
+pub const CLUSTER_OFFSET: u32 = 1;
+pub const PUB_KEY: [u8; 32] = [7; 32];
+pub const NONCE: u128 = 42;
+/// Key and nonce of a circuit's `Shared` recipient.
+pub const RECIPIENT_PUB_KEY: [u8; 32] = [9; 32];
+pub const RECIPIENT_NONCE: u128 = 43;
+
+/// Prefix telling the stand-in to CPI the rest of the data into flaek_mxe.
+const DELIVER: &[u8] = b"deliver!";
+/// Room for any of the Arcium accounts the program deserializes.
+const STUB_ACCOUNT_LEN: usize = 4096;
+
+/// `queue_computation` arguments by computation offset, shared across the
+/// test threads.
+static QUEUED: Mutex<Option<HashMap<u64, Vec<Argument>>>> = Mutex::new(None);
+
+pub struct Simulator {
+    pub ctx: ProgramTestContext,
+}
+
+impl Simulator {
+    /// Boots the program with comp def accounts for `circuits`, open to any
+    /// payer and free.
+    pub async fn start(circuits: &[&str]) -> Self {
+        Self::start_with(circuits, |_| {}).await
+    }
+
+    /// Boots the program with its default config adjusted by `edit`.
+    pub async fn start_with(circuits: &[&str], edit: impl FnOnce(&mut Config)) -> Self {
+        let mut config = Config {
+            authority: Pubkey::new_unique(),
+            gate: Gate::Open,
+            allowed_payers: Vec::new(),
+            fee_mint: None,
+            fees: Vec::new(),
+            credit_costs: Vec::new(),
+            bump: Pubkey::find_program_address(&[CONFIG_SEED], &ID).1,
+        };
+        edit(&mut config);
+        Self::boot(circuits, Some(config)).await
+    }
+
+    /// Boots the program before `init_config`, deployed as an upgradeable
+    /// program whose upgrade authority is the payer.
+    pub async fn start_unconfigured() -> Self {
+        let mut sim = Self::boot(&[], None).await;
+        // Only the loader accounts change; the program keeps running natively.
+        sim.ctx.set_account(
+            &ID,
+            &loader_account(
+                &UpgradeableLoaderState::Program {
+                    programdata_address: program_data_address(),
+                },
+                true,
+            )
+            .into(),
+        );
+        sim.set_upgrade_authority(Some(sim.payer()));
+        sim
+    }
+
+    pub fn set_upgrade_authority(&mut self, authority: Option<Pubkey>) {
+        self.ctx.set_account(
+            &program_data_address(),
+            &loader_account(
+                &UpgradeableLoaderState::ProgramData {
+                    slot: 0,
+                    upgrade_authority_address: authority,
+                },
+                false,
+            )
+            .into(),
+        );
+    }
+
+    async fn boot(circuits: &[&str], config: Option<Config>) -> Self {
+        let mut test = ProgramTest::new("flaek_mxe", ID, processor!(flaek_mxe_entry));
+        test.prefer_bpf(false);
+        test.add_program("arcium", ARCIUM_PROG_ID, processor!(arcium_stub));
+
+        test.add_account(derive_mxe_pda!(), arcium_account(&mxe()));
+        test.add_account(cluster_address(), arcium_account(&stub::<Cluster>(|_| {})));
+        test.add_account(
+            ARCIUM_FEE_POOL_ACCOUNT_ADDRESS,
+            arcium_account(&stub::<FeePool>(|_| {})),
+        );
+        test.add_account(
+            ARCIUM_CLOCK_ACCOUNT_ADDRESS,
+            arcium_account(&stub::<ClockAccount>(|_| {})),
+        );
+        if let Some(config) = config {
+            test.add_account(config_address(), flaek_account(&config));
+            // What `init_config` leaves in the treasury.
+            test.add_account(
+                treasury_address(),
+                SolanaAccount {
+                    lamports: Rent::default().minimum_balance(0),
+                    owner: anchor_lang::system_program::ID,
+                    ..SolanaAccount::default()
+                },
+            );
+        }
+        for circuit in circuits {
+            test.add_account(
+                comp_def_address(circuit),
+                arcium_account(&stub::<ComputationDefinitionAccount>(|_| {})),
+            );
+            test.add_account(
+                registry_address(circuit),
+                flaek_account(&registry(circuit, |_| {})),
+            );
+        }
+
+        Simulator {
+            ctx: test.start_with_context().await,
+        }
+    }
+
+    pub fn payer(&self) -> Pubkey {
+        self.ctx.payer.pubkey()
+    }
+
+    /// Sends a flaek_mxe instruction and returns its logs.
+    pub async fn send(
+        &mut self,
+        accounts: impl ToAccountMetas,
+        data: impl InstructionData,
+    ) -> std::result::Result<Vec<String>, BanksClientError> {
+        self.send_signed(accounts, data, &[]).await
+    }
+
+    /// Like [`Simulator::send`], with `signers` signing next to the payer.
+    pub async fn send_signed(
+        &mut self,
+        accounts: impl ToAccountMetas,
+        data: impl InstructionData,
+        signers: &[&Keypair],
+    ) -> std::result::Result<Vec<String>, BanksClientError> {
+        self.process(
+            Instruction {
+                program_id: ID,
+                accounts: accounts.to_account_metas(None),
+                data: data.data(),
+            },
+            signers,
+        )
+        .await
+    }
+
+    /// Sends a flaek_mxe instruction and decodes what it returned.
+    pub async fn view<T: AnchorDeserialize>(
+        &mut self,
+        accounts: impl ToAccountMetas,
+        data: impl InstructionData,
+    ) -> T {
+        let outcome = self
+            .execute(
+                Instruction {
+                    program_id: ID,
+                    accounts: accounts.to_account_metas(None),
+                    data: data.data(),
+                },
+                &[],
+            )
+            .await
+            .unwrap();
+        let return_data = outcome
+            .metadata
+            .and_then(|metadata| metadata.return_data)
+            .expect("nothing returned");
+        T::try_from_slice(&return_data.data).unwrap()
+    }
+
+    /// Invokes a `*_callback` through the Arcium stand-in, as the cluster
+    /// does once a computation finishes.
+    pub async fn deliver(
+        &mut self,
+        accounts: impl ToAccountMetas,
+        data: impl InstructionData,
+    ) -> std::result::Result<Vec<String>, BanksClientError> {
+        let mut metas = vec![AccountMeta::new_readonly(ID, false)];
+        metas.extend(accounts.to_account_metas(None));
+        self.process(
+            Instruction {
+                program_id: ARCIUM_PROG_ID,
+                accounts: metas,
+                data: [DELIVER, &data.data()].concat(),
+            },
+            &[],
+        )
+        .await
+    }
+
+    /// The arguments `computation_offset` was queued with.
+    pub fn take_queued(&self, computation_offset: u64) -> Vec<Argument> {
+        QUEUED
+            .lock()
+            .unwrap()
+            .as_mut()
+            .and_then(|queued| queued.remove(&computation_offset))
+            .expect("computation was never queued")
+    }
+
+    pub async fn balance(&mut self, address: Pubkey) -> u64 {
+        self.ctx.banks_client.get_balance(address).await.unwrap()
+    }
+
+    pub async fn account<T: AccountDeserialize>(&mut self, address: Pubkey) -> T {
+        let data = self.account_data(address).await;
+        T::try_deserialize(&mut data.as_slice()).unwrap()
+    }
+
+    /// Overwrites a flaek_mxe account, e.g. to hand it to another payer.
+    pub fn set_account<T: AccountSerialize + Space>(&mut self, address: Pubkey, account: &T) {
+        self.ctx
+            .set_account(&address, &flaek_account(account).into());
+    }
+
+    /// Rewrites the config in place.
+    pub async fn edit_config(&mut self, edit: impl FnOnce(&mut Config)) {
+        let mut config: Config = self.account(config_address()).await;
+        edit(&mut config);
+        self.set_account(config_address(), &config);
+    }
+
+    /// Replaces `circuit`'s comp def with a stub adjusted by `edit`.
+    pub fn set_comp_def(
+        &mut self,
+        circuit: &str,
+        edit: impl FnOnce(&mut ComputationDefinitionAccount),
+    ) {
+        self.ctx.set_account(
+            &comp_def_address(circuit),
+            &arcium_account(&stub(edit)).into(),
+        );
+    }
+
+    /// Creates an SPL mint at `address`.
+    pub fn set_mint(&mut self, address: Pubkey) {
+        let mint = Mint {
+            mint_authority: COption::Some(self.payer()),
+            decimals: 6,
+            is_initialized: true,
+            ..Mint::default()
+        };
+        self.set_packed(address, mint);
+    }
+
+    /// Creates an SPL token account of `mint` owned by `owner`, holding
+    /// `amount`.
+    pub fn set_token_account(&mut self, address: Pubkey, mint: Pubkey, owner: Pubkey, amount: u64) {
+        let account = TokenAccount {
+            mint,
+            owner,
+            amount,
+            state: AccountState::Initialized,
+            ..TokenAccount::default()
+        };
+        self.set_packed(address, account);
+    }
+
+    pub async fn token_balance(&mut self, address: Pubkey) -> u64 {
+        let data = self.account_data(address).await;
+        TokenAccount::unpack(&data).unwrap().amount
+    }
+
+    fn set_packed<T: Pack>(&mut self, address: Pubkey, state: T) {
+        let mut data = vec![0; T::LEN];
+        state.pack_into_slice(&mut data);
+        self.ctx.set_account(
+            &address,
+            &SolanaAccount {
+                lamports: Rent::default().minimum_balance(T::LEN),
+                data,
+                owner: spl_token::ID,
+                ..SolanaAccount::default()
+            }
+            .into(),
+        );
+    }
+
+    /// Sets the lamports of a system-owned account.
+    pub fn set_lamports(&mut self, address: Pubkey, lamports: u64) {
+        self.ctx.set_account(
+            &address,
+            &SolanaAccount {
+                lamports,
+                owner: anchor_lang::system_program::ID,
+                ..SolanaAccount::default()
+            }
+            .into(),
+        );
+    }
+
+    /// Replaces `circuit`'s registry entry with one adjusted by `edit`.
+    pub fn set_registry(&mut self, circuit: &str, edit: impl FnOnce(&mut CircuitRegistry)) {
+        self.set_account(registry_address(circuit), &registry(circuit, edit));
+    }
+
+    /// What an `Argument::Account(address, offset, len)` hands the cluster.
+    pub async fn read(&mut self, address: Pubkey, offset: u32, len: u32) -> Vec<u8> {
+        let data = self.account_data(address).await;
+        data[offset as usize..(offset + len) as usize].to_vec()
+    }
+
+    async fn account_data(&mut self, address: Pubkey) -> Vec<u8> {
+        self.ctx
+            .banks_client
+            .get_account(address)
+            .await
+            .unwrap()
+            .expect("account does not exist")
+            .data
+    }
+
+    async fn process(
+        &mut self,
+        ix: Instruction,
+        signers: &[&Keypair],
+    ) -> std::result::Result<Vec<String>, BanksClientError> {
+        Ok(self
+            .execute(ix, signers)
+            .await?
+            .metadata
+            .map(|metadata| metadata.log_messages)
+            .unwrap_or_default())
+    }
+
+    async fn execute(
+        &mut self,
+        ix: Instruction,
+        signers: &[&Keypair],
+    ) -> std::result::Result<BanksTransactionResultWithMetadata, BanksClientError> {
+        let blockhash = self.ctx.get_new_latest_blockhash().await?;
+        let mut keypairs = vec![&self.ctx.payer];
+        keypairs.extend(signers);
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&self.ctx.payer.pubkey()),
+            &keypairs,
+            blockhash,
+        );
+        let outcome = self
+            .ctx
+            .banks_client
+            .process_transaction_with_metadata(tx)
+            .await?;
+        outcome
+            .result
+            .clone()
+            .map_err(BanksClientError::TransactionError)?;
+        Ok(outcome)
+    }
+}
+
+/// Anchor's entry wants the account slice and its infos to share a lifetime.
+fn flaek_mxe_entry(program_id: &Pubkey, accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
+    let accounts = Box::leak(Box::new(accounts.to_vec()));
+    flaek_mxe::entry(program_id, accounts, data)
+}
+
+fn arcium_stub(_program_id: &Pubkey, accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
+    if let Some(callback) = data.strip_prefix(DELIVER) {
+        let ix = Instruction {
+            program_id: ID,
+            accounts: accounts[1..]
+                .iter()
+                .map(|info| AccountMeta {
+                    pubkey: *info.key,
+                    is_signer: info.is_signer,
+                    is_writable: info.is_writable,
+                })
+                .collect(),
+            data: callback.to_vec(),
+        };
+        return invoke(&ix, accounts);
+    }
+    if let Some(mut args) = data.strip_prefix(QueueComputation::DISCRIMINATOR) {
+        let queued = QueueComputation::deserialize(&mut args)
+            .map_err(|_| ProgramError::InvalidInstructionData)?;
+        QUEUED
+            .lock()
+            .unwrap()
+            .get_or_insert_with(HashMap::new)
+            .insert(queued.computation_offset, queued.args);
+    }
+    // Everything else (comp def init, finalization) is accepted as is.
+    Ok(())
+}
+
+/// An Arcium account decoded from zeroed data, then adjusted by `edit`.
+fn stub<T: AccountDeserialize + Discriminator>(edit: impl FnOnce(&mut T)) -> T {
+    let mut data = T::DISCRIMINATOR.to_vec();
+    data.resize(STUB_ACCOUNT_LEN, 0);
+    let mut account = T::try_deserialize(&mut data.as_slice()).unwrap();
+    edit(&mut account);
+    account
+}
+
+fn arcium_account<T: AccountSerialize>(account: &T) -> SolanaAccount {
+    let mut data = Vec::new();
+    account.try_serialize(&mut data).unwrap();
+    data.resize(STUB_ACCOUNT_LEN, 0);
+    SolanaAccount {
+        lamports: 1_000_000_000,
+        data,
+        owner: ARCIUM_PROG_ID,
+        executable: false,
+        rent_epoch: 0,
+    }
+}
+
+fn flaek_account<T: AccountSerialize + Space>(account: &T) -> SolanaAccount {
+    let mut data = Vec::new();
+    account.try_serialize(&mut data).unwrap();
+    data.resize(8 + T::INIT_SPACE, 0);
+    SolanaAccount {
+        lamports: 1_000_000_000,
+        data,
+        owner: ID,
+        executable: false,
+        rent_epoch: 0,
+    }
+}
+
+fn loader_account(state: &UpgradeableLoaderState, executable: bool) -> SolanaAccount {
+    SolanaAccount {
+        executable,
+        ..SolanaAccount::new_data(1_000_000_000, state, &bpf_loader_upgradeable::ID).unwrap()
+    }
+}
+
+/// The registry entry booted for `circuit`, pinned to [`circuit_hash`] and
+/// adjusted by `edit`.
+fn registry(circuit: &str, edit: impl FnOnce(&mut CircuitRegistry)) -> CircuitRegistry {
+    let mut registry = CircuitRegistry {
+        comp_def_offset: comp_def_offset(circuit),
+        circuit: circuit.to_string(),
+        source: String::new(),
+        hash: circuit_hash(circuit),
+        bump: Pubkey::find_program_address(
+            &[
+                CIRCUIT_REGISTRY_SEED,
+                &comp_def_offset(circuit).to_le_bytes(),
+            ],
+            &ID,
+        )
+        .1,
+    };
+    edit(&mut registry);
+    registry
+}
+
+/// The hash a booted circuit's registry entry pins, standing in for the
+/// SHA-256 of its `.arcis` file.
+pub fn circuit_hash(circuit: &str) -> [u8; 32] {
+    hashv(&[circuit.as_bytes()]).to_bytes()
+}
+
+fn mxe() -> MXEAccount {
+    stub(|mxe: &mut MXEAccount| mxe.cluster = Some(CLUSTER_OFFSET))
+}
+
+fn derive_cluster(mxe_account: &MXEAccount) -> Result<Pubkey> {
+    Ok(derive_cluster_pda!(mxe_account))
+}
+
+pub fn cluster_address() -> Pubkey {
+    derive_cluster(&mxe()).unwrap()
+}
+
+pub fn comp_def_address(circuit: &str) -> Pubkey {
+    derive_comp_def_pda!(comp_def_offset(circuit))
+}
+
+pub fn sign_pda_address() -> Pubkey {
+    derive_sign_pda!()
+}
+
+pub fn mxe_address() -> Pubkey {
+    derive_mxe_pda!()
+}
+
+pub fn mempool_address() -> Pubkey {
+    derive_mempool_pda!()
+}
+
+pub fn execpool_address() -> Pubkey {
+    derive_execpool_pda!()
+}
+
+pub fn computation_address(computation_offset: u64) -> Pubkey {
+    derive_comp_pda!(computation_offset)
+}
+
+pub fn program_data_address() -> Pubkey {
+    Pubkey::find_program_address(&[ID.as_ref()], &bpf_loader_upgradeable::ID).0
+}
+
+pub fn registry_address(circuit: &str) -> Pubkey {
+    Pubkey::find_program_address(
+        &[
+            CIRCUIT_REGISTRY_SEED,
+            &comp_def_offset(circuit).to_le_bytes(),
+        ],
+        &ID,
+    )
+    .0
+}
+
+pub fn config_address() -> Pubkey {
+    Pubkey::find_program_address(&[CONFIG_SEED], &ID).0
+}
+
+pub fn treasury_address() -> Pubkey {
+    Pubkey::find_program_address(&[TREASURY_SEED], &ID).0
+}
+
+pub fn credits_address(tenant: Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[CREDITS_SEED, tenant.as_ref()], &ID).0
+}
+
+pub fn computation_result_address(computation_offset: u64) -> Pubkey {
+    Pubkey::find_program_address(
+        &[COMPUTATION_RESULT_SEED, &computation_offset.to_le_bytes()],
+        &ID,
+    )
+    .0
+}
+
+pub fn attestation_address(computation_offset: u64) -> Pubkey {
+    Pubkey::find_program_address(&[ATTESTATION_SEED, &computation_offset.to_le_bytes()], &ID).0
+}
+
+pub fn intermediate_address(computation_offset: u64) -> Pubkey {
+    Pubkey::find_program_address(&[INTERMEDIATE_SEED, &computation_offset.to_le_bytes()], &ID).0
+}
+
+pub fn ct(value: u64) -> [u8; 32] {
+    let mut ciphertext = [0; 32];
+    ciphertext[..8].copy_from_slice(&value.to_le_bytes());
+    ciphertext
+}
+
+pub fn plain(ciphertext: &[u8]) -> u64 {
+    u64::from_le_bytes(ciphertext[..8].try_into().unwrap())
+}
+
+/// Output encrypted back to the caller's key.
+pub fn shared<const N: usize>(ciphertexts: [[u8; 32]; N]) -> SharedEncryptedStruct<N> {
+    SharedEncryptedStruct {
+        encryption_key: PUB_KEY,
+        nonce: NONCE,
+        ciphertexts,
+    }
+}
+
+/// Output encrypted to the circuit's `Shared` recipient.
+pub fn shared_to_recipient<const N: usize>(ciphertexts: [[u8; 32]; N]) -> SharedEncryptedStruct<N> {
+    SharedEncryptedStruct {
+        encryption_key: RECIPIENT_PUB_KEY,
+        nonce: RECIPIENT_NONCE,
+        ciphertexts,
+    }
+}
+
+/// Output kept under the MXE key.
+pub fn mxe_owned<const N: usize>(ciphertexts: [[u8; 32]; N]) -> MXEEncryptedStruct<N> {
+    MXEEncryptedStruct {
+        nonce: NONCE,
+        ciphertexts,
+    }
+}
+
+/// The custom error code `err` failed with, if any.
+pub fn error_code(err: &BanksClientError) -> Option<u32> {
+    match err {
+        BanksClientError::TransactionError(TransactionError::InstructionError(
+            _,
+            InstructionError::Custom(code),
+        )) => Some(*code),
+        _ => None,
+    }
+}
+
+/// Events of type `E` emitted in `logs`.
+pub fn events<E: anchor_lang::Event + AnchorDeserialize>(logs: &[String]) -> Vec<E> {
+    logs.iter()
+        .filter_map(|line| line.strip_prefix("Program data: "))
+        .filter_map(|data| STANDARD.decode(data).ok())
+        .filter_map(|bytes| {
+            let mut body = bytes.strip_prefix(E::DISCRIMINATOR)?;
+            E::deserialize(&mut body).ok()
+        })
+        .collect()
+}
+
+/// `flaek_mxe::accounts::$accs` for queuing `$circuit` at `$offset`, plus
+/// any accounts specific to that circuit.
+macro_rules! queue_accounts {
+    ($sim:expr, $accs:ident, $circuit:literal, $offset:expr $(, $field:ident: $value:expr)* $(,)?) => {
+        flaek_mxe::accounts::$accs {
+            payer: $sim.payer(),
+            sign_pda_account: simulator::sign_pda_address(),
+            mxe_account: simulator::mxe_address(),
+            mempool_account: simulator::mempool_address(),
+            executing_pool: simulator::execpool_address(),
+            computation_account: simulator::computation_address($offset),
+            comp_def_account: simulator::comp_def_address($circuit),
+            circuit_registry: simulator::registry_address($circuit),
+            cluster_account: simulator::cluster_address(),
+            pool_account: ARCIUM_FEE_POOL_ACCOUNT_ADDRESS,
+            clock_account: ARCIUM_CLOCK_ACCOUNT_ADDRESS,
+            computation_result: simulator::computation_result_address($offset),
+            config: simulator::config_address(),
+            co_signer: None,
+            treasury: simulator::treasury_address(),
+            payer_fee_account: None,
+            treasury_fee_account: None,
+            token_program: None,
+            credits: None,
+            attestation: simulator::attestation_address($offset),
+            system_program: anchor_lang::system_program::ID,
+            arcium_program: ARCIUM_PROG_ID,
+            $($field: $value,)*
+        }
+    };
+}
+
+/// `flaek_mxe::accounts::$accs` for the callback of `$circuit` at `$offset`.
+macro_rules! callback_accounts {
+    ($accs:ident, $circuit:literal, $offset:expr $(, $field:ident: $value:expr)* $(,)?) => {
+        flaek_mxe::accounts::$accs {
+            arcium_program: ARCIUM_PROG_ID,
+            comp_def_account: simulator::comp_def_address($circuit),
+            instructions_sysvar: anchor_lang::solana_program::sysvar::instructions::ID,
+            computation_result: simulator::computation_result_address($offset),
+            credits: None,
+            attestation: simulator::attestation_address($offset),
+            $($field: $value,)*
+        }
+    };
+}
+
+pub(crate) use {callback_accounts, queue_accounts};
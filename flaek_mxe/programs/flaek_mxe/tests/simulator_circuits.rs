@@ -0,0 +1,1070 @@
+mod simulator;
+
+use anchor_lang::prelude::*;
+use arcium_anchor::prelude::*;
+use arcium_client::idl::arcium::types::Argument;
+use encrypted_ixs::reference;
+use flaek_mxe::{
+    instruction, AddEvent, AddOutput, Attestation, CircuitFee, ComputationFailedEvent,
+    ComputationResult, ComputationStatus, CreditScoreEvent, CreditScoreOutput,
+    CreditScoreOutputStruct0, ErrorCode, FailureReason, Gate, HealthRiskEvent, HealthRiskOutput,
+    HealthRiskOutputStruct0, Intermediate, MedianEvent, MedianOutput, PipelineEvalEvent,
+    PipelineEvalOutput, PipelineStepEvent, PipelineStepOutput, SealOutput, SharedOutput,
+    TenantCredits, UnsealEvent, UnsealOutput, VoteTallyEvent, VoteTallyOutput,
+    VoteTallyOutputStruct0, WeightedAverageEvent, WeightedAverageOutput,
+    WeightedAverageOutputStruct0,
+};
+use simulator::{
+    callback_accounts, ct, error_code, events, intermediate_address, mxe_owned, plain,
+    queue_accounts, shared, shared_to_recipient, Simulator, NONCE, PUB_KEY, RECIPIENT_NONCE,
+    RECIPIENT_PUB_KEY,
+};
+use solana_program_test::BanksClientError;
+use solana_sdk::{instruction::InstructionError, transaction::TransactionError};
+
+#[tokio::test]
+async fn add_round_trips_through_the_reference_circuit() {
+    let mut sim = Simulator::start(&["add"]).await;
+    let offset = 1;
+
+    sim.send(
+        queue_accounts!(sim, Add, "add", offset),
+        instruction::Add {
+            computation_offset: offset,
+            a: ct(20),
+            b: ct(22),
+            pub_key: PUB_KEY,
+            nonce: NONCE,
+        },
+    )
+    .await
+    .unwrap();
+
+    let args = sim.take_queued(offset);
+    let [Argument::ArcisPubkey(key), Argument::PlaintextU128(nonce), Argument::EncryptedU64(a), Argument::EncryptedU64(b)] =
+        args.as_slice()
+    else {
+        panic!("add queued {} arguments in the wrong layout", args.len());
+    };
+    assert_eq!((*key, *nonce), (PUB_KEY, NONCE));
+    let result = reference::math::add(plain(a), plain(b));
+
+    let logs = sim
+        .deliver(
+            callback_accounts!(AddCallback, "add", offset),
+            instruction::AddCallback {
+                output: ComputationOutputs::Success(AddOutput {
+                    field_0: shared([ct(result)]),
+                }),
+            },
+        )
+        .await
+        .unwrap();
+
+    let event = events::<AddEvent>(&logs).pop().expect("no AddEvent");
+    assert_eq!(event.computation_offset, offset);
+    assert_eq!(event.payer, sim.payer());
+    assert_eq!(plain(&event.result), 42);
+    assert_eq!(event.nonce, NONCE.to_le_bytes());
+
+    let stored: ComputationResult = sim
+        .account(simulator::computation_result_address(offset))
+        .await;
+    assert!(stored.status == ComputationStatus::Completed);
+    assert_eq!(stored.ciphertexts, vec![ct(42)]);
+    assert!(stored.revealed.is_empty());
+
+    let attestation: Attestation = sim.account(simulator::attestation_address(offset)).await;
+    assert_eq!(attestation.comp_def_offset, comp_def_offset("add"));
+    assert_eq!(attestation.circuit_hash, simulator::circuit_hash("add"));
+    assert_eq!(attestation.cluster, simulator::cluster_address());
+    assert_eq!(
+        attestation.inputs_hash,
+        flaek_mxe::inputs_hash(&args).unwrap()
+    );
+    assert_eq!(
+        attestation.outputs_hash,
+        flaek_mxe::outputs_hash(&[ct(42)], NONCE, None, &[])
+    );
+    assert_ne!(attestation.slot, 0);
+}
+
+#[tokio::test]
+async fn verify_attestation_matches_only_the_attested_circuit_and_outputs() {
+    let mut sim = Simulator::start(&["add"]).await;
+    let offset = 21;
+    sim.send(
+        queue_accounts!(sim, Add, "add", offset),
+        instruction::Add {
+            computation_offset: offset,
+            a: ct(1),
+            b: ct(2),
+            pub_key: PUB_KEY,
+            nonce: NONCE,
+        },
+    )
+    .await
+    .unwrap();
+    sim.take_queued(offset);
+    let verify = || flaek_mxe::accounts::VerifyAttestation {
+        attestation: simulator::attestation_address(offset),
+    };
+    let circuit_hash = simulator::circuit_hash("add");
+    let outputs_hash = flaek_mxe::outputs_hash(&[ct(3)], NONCE, None, &[]);
+    let verify_attestation = |circuit_hash, outputs_hash| instruction::VerifyAttestation {
+        computation_offset: offset,
+        circuit_hash,
+        outputs_hash,
+    };
+
+    // Nothing is attested before the callback lands.
+    let verified: bool = sim
+        .view(verify(), verify_attestation(circuit_hash, outputs_hash))
+        .await;
+    assert!(!verified);
+
+    sim.deliver(
+        callback_accounts!(AddCallback, "add", offset),
+        instruction::AddCallback {
+            output: ComputationOutputs::Success(AddOutput {
+                field_0: shared([ct(3)]),
+            }),
+        },
+    )
+    .await
+    .unwrap();
+    let verified: bool = sim
+        .view(verify(), verify_attestation(circuit_hash, outputs_hash))
+        .await;
+    assert!(verified);
+    let verified: bool = sim
+        .view(verify(), verify_attestation([9; 32], outputs_hash))
+        .await;
+    assert!(!verified);
+    let other_outputs = flaek_mxe::outputs_hash(&[ct(4)], NONCE, None, &[]);
+    let verified: bool = sim
+        .view(verify(), verify_attestation(circuit_hash, other_outputs))
+        .await;
+    assert!(!verified);
+}
+
+#[tokio::test]
+async fn queuing_needs_a_pinned_circuit_hash() {
+    let mut sim = Simulator::start(&["add"]).await;
+    sim.set_registry("add", |registry| registry.hash = [0; 32]);
+
+    let err = sim
+        .send(
+            queue_accounts!(sim, Add, "add", 22),
+            instruction::Add {
+                computation_offset: 22,
+                a: ct(1),
+                b: ct(2),
+                pub_key: PUB_KEY,
+                nonce: NONCE,
+            },
+        )
+        .await
+        .unwrap_err();
+    assert_eq!(
+        error_code(&err),
+        Some(u32::from(ErrorCode::CircuitHashMissing))
+    );
+}
+
+#[tokio::test]
+async fn aborted_computation_is_recorded_as_failed() {
+    let mut sim = Simulator::start(&["divide"]).await;
+    let offset = 2;
+
+    sim.send(
+        queue_accounts!(sim, Divide, "divide", offset),
+        instruction::Divide {
+            computation_offset: offset,
+            a: ct(1),
+            b: ct(0),
+            pub_key: PUB_KEY,
+            nonce: NONCE,
+        },
+    )
+    .await
+    .unwrap();
+    sim.take_queued(offset);
+
+    let logs = sim
+        .deliver(
+            callback_accounts!(DivideCallback, "divide", offset),
+            instruction::DivideCallback {
+                output: ComputationOutputs::Failure,
+            },
+        )
+        .await
+        .unwrap();
+
+    let event = events::<ComputationFailedEvent>(&logs)
+        .pop()
+        .expect("no ComputationFailedEvent");
+    assert_eq!(event.computation_offset, offset);
+    assert!(event.reason == FailureReason::Aborted);
+
+    let stored: ComputationResult = sim
+        .account(simulator::computation_result_address(offset))
+        .await;
+    assert!(stored.status == ComputationStatus::Failed);
+}
+
+#[tokio::test]
+async fn allow_list_rejects_other_payers() {
+    let mut sim = Simulator::start_with(&["add"], |config| {
+        config.gate = Gate::AllowList;
+        config.allowed_payers = vec![Pubkey::new_unique()];
+    })
+    .await;
+
+    let err = sim
+        .send(
+            queue_accounts!(sim, Add, "add", 3),
+            instruction::Add {
+                computation_offset: 3,
+                a: ct(1),
+                b: ct(2),
+                pub_key: PUB_KEY,
+                nonce: NONCE,
+            },
+        )
+        .await
+        .unwrap_err();
+    assert!(matches!(
+        err,
+        BanksClientError::TransactionError(TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(code),
+        )) if code == u32::from(ErrorCode::Unauthorized)
+    ));
+}
+
+#[tokio::test]
+async fn queuing_pays_the_circuit_fee_into_the_treasury() {
+    let fee = 5_000_000;
+    let mut sim = Simulator::start_with(&["add", "subtract"], |config| {
+        config.fees = vec![CircuitFee {
+            comp_def_offset: comp_def_offset("add"),
+            amount: fee,
+        }];
+    })
+    .await;
+    let treasury = simulator::treasury_address();
+    let rent = Rent::default().minimum_balance(0);
+
+    sim.send(
+        queue_accounts!(sim, Subtract, "subtract", 4),
+        instruction::Subtract {
+            computation_offset: 4,
+            a: ct(2),
+            b: ct(1),
+            pub_key: PUB_KEY,
+            nonce: NONCE,
+        },
+    )
+    .await
+    .unwrap();
+    assert_eq!(sim.balance(treasury).await, rent);
+
+    sim.send(
+        queue_accounts!(sim, Add, "add", 5),
+        instruction::Add {
+            computation_offset: 5,
+            a: ct(1),
+            b: ct(2),
+            pub_key: PUB_KEY,
+            nonce: NONCE,
+        },
+    )
+    .await
+    .unwrap();
+    assert_eq!(sim.balance(treasury).await, rent + fee);
+}
+
+#[tokio::test]
+async fn failed_computation_refunds_its_credits() {
+    let cost = 1_000;
+    let mut sim = Simulator::start_with(&["divide"], |config| {
+        config.credit_costs = vec![CircuitFee {
+            comp_def_offset: comp_def_offset("divide"),
+            amount: cost,
+        }];
+    })
+    .await;
+    let credits = simulator::credits_address(sim.payer());
+    let offset = 6;
+
+    sim.send(
+        flaek_mxe::accounts::DepositCredits {
+            tenant: sim.payer(),
+            credits,
+            system_program: anchor_lang::system_program::ID,
+        },
+        instruction::DepositCredits { amount: 5 * cost },
+    )
+    .await
+    .unwrap();
+
+    let mut accounts = queue_accounts!(sim, Divide, "divide", offset);
+    accounts.credits = Some(credits);
+    sim.send(
+        accounts,
+        instruction::Divide {
+            computation_offset: offset,
+            a: ct(1),
+            b: ct(0),
+            pub_key: PUB_KEY,
+            nonce: NONCE,
+        },
+    )
+    .await
+    .unwrap();
+    sim.take_queued(offset);
+    let debited: TenantCredits = sim.account(credits).await;
+    assert_eq!(debited.balance, 4 * cost);
+    assert_eq!(debited.pending, cost);
+
+    let mut accounts = callback_accounts!(DivideCallback, "divide", offset);
+    accounts.credits = Some(credits);
+    sim.deliver(
+        accounts,
+        instruction::DivideCallback {
+            output: ComputationOutputs::Failure,
+        },
+    )
+    .await
+    .unwrap();
+    let refunded: TenantCredits = sim.account(credits).await;
+    assert_eq!(refunded.balance, 5 * cost);
+    assert_eq!(refunded.pending, 0);
+}
+
+#[tokio::test]
+async fn credits_are_swept_once_spent_and_withdrawable_until_then() {
+    let cost = 1_000;
+    let mut sim = Simulator::start_with(&["add"], |config| {
+        config.credit_costs = vec![CircuitFee {
+            comp_def_offset: comp_def_offset("add"),
+            amount: cost,
+        }];
+    })
+    .await;
+    let payer = sim.payer();
+    sim.edit_config(|config| config.authority = payer).await;
+    let credits = simulator::credits_address(payer);
+    let treasury = simulator::treasury_address();
+    let offset = 20;
+    let sweep = || flaek_mxe::accounts::SweepCredits {
+        authority: payer,
+        config: simulator::config_address(),
+        credits,
+        treasury,
+    };
+    let withdraw = || flaek_mxe::accounts::WithdrawCredits {
+        tenant: payer,
+        credits,
+    };
+
+    sim.send(
+        flaek_mxe::accounts::DepositCredits {
+            tenant: payer,
+            credits,
+            system_program: anchor_lang::system_program::ID,
+        },
+        instruction::DepositCredits { amount: 3 * cost },
+    )
+    .await
+    .unwrap();
+    let mut accounts = queue_accounts!(sim, Add, "add", offset);
+    accounts.credits = Some(credits);
+    sim.send(
+        accounts,
+        instruction::Add {
+            computation_offset: offset,
+            a: ct(1),
+            b: ct(2),
+            pub_key: PUB_KEY,
+            nonce: NONCE,
+        },
+    )
+    .await
+    .unwrap();
+    sim.take_queued(offset);
+
+    // Pending credits stay put until the callback lands.
+    let swept_from = sim.balance(treasury).await;
+    sim.send(sweep(), instruction::SweepCredits {})
+        .await
+        .unwrap();
+    assert_eq!(sim.balance(treasury).await, swept_from);
+
+    let mut accounts = callback_accounts!(AddCallback, "add", offset);
+    accounts.credits = Some(credits);
+    sim.deliver(
+        accounts,
+        instruction::AddCallback {
+            output: ComputationOutputs::Success(AddOutput {
+                field_0: shared([ct(3)]),
+            }),
+        },
+    )
+    .await
+    .unwrap();
+    let spent: TenantCredits = sim.account(credits).await;
+    assert_eq!((spent.balance, spent.pending), (2 * cost, 0));
+    sim.send(sweep(), instruction::SweepCredits {})
+        .await
+        .unwrap();
+    assert_eq!(sim.balance(treasury).await, swept_from + cost);
+
+    let err = sim
+        .send(
+            withdraw(),
+            instruction::WithdrawCredits {
+                amount: 2 * cost + 1,
+            },
+        )
+        .await
+        .unwrap_err();
+    assert_eq!(
+        error_code(&err),
+        Some(u32::from(ErrorCode::InsufficientCredits))
+    );
+    let before = sim.balance(credits).await;
+    sim.send(
+        withdraw(),
+        instruction::WithdrawCredits { amount: 2 * cost },
+    )
+    .await
+    .unwrap();
+    let withdrawn: TenantCredits = sim.account(credits).await;
+    assert_eq!(withdrawn.balance, 0);
+    assert_eq!(sim.balance(credits).await, before - 2 * cost);
+}
+
+#[tokio::test]
+async fn median_packs_every_array_element_before_the_count() {
+    let mut sim = Simulator::start(&["median"]).await;
+    let offset = 7;
+    let values = [4, 1, 3, 2, 9, 9, 9, 9, 9, 9];
+
+    sim.send(
+        queue_accounts!(sim, Median, "median", offset),
+        instruction::Median {
+            computation_offset: offset,
+            values: values.map(ct),
+            count: ct(4),
+            pub_key: PUB_KEY,
+            nonce: NONCE,
+        },
+    )
+    .await
+    .unwrap();
+
+    let args = sim.take_queued(offset);
+    let [Argument::ArcisPubkey(_), Argument::PlaintextU128(_), elements @ .., Argument::EncryptedU8(count)] =
+        args.as_slice()
+    else {
+        panic!("median queued {} arguments in the wrong layout", args.len());
+    };
+    let queued: Vec<u64> = elements
+        .iter()
+        .map(|arg| match arg {
+            Argument::EncryptedU64(value) => plain(value),
+            _ => panic!("median queued a non-u64 array element"),
+        })
+        .collect();
+    assert_eq!(queued, values);
+    let result = reference::statistics::median(values, plain(count) as u8);
+
+    let logs = sim
+        .deliver(
+            callback_accounts!(MedianCallback, "median", offset),
+            instruction::MedianCallback {
+                output: ComputationOutputs::Success(MedianOutput {
+                    field_0: shared([ct(result)]),
+                }),
+            },
+        )
+        .await
+        .unwrap();
+
+    let event = events::<MedianEvent>(&logs).pop().expect("no MedianEvent");
+    assert_eq!(plain(&event.result), 2);
+}
+
+#[tokio::test]
+async fn weighted_average_packs_values_then_weights() {
+    let mut sim = Simulator::start(&["weighted_average"]).await;
+    let offset = 8;
+    let values = [10, 20, 30, 40, 50];
+    let weights = [1u8, 0, 0, 0, 3];
+
+    sim.send(
+        queue_accounts!(sim, WeightedAverage, "weighted_average", offset),
+        instruction::WeightedAverage {
+            computation_offset: offset,
+            values: values.map(ct),
+            weights: weights.map(|w| ct(w.into())),
+            recipient_pub_key: RECIPIENT_PUB_KEY,
+            recipient_nonce: RECIPIENT_NONCE,
+            pub_key: PUB_KEY,
+            nonce: NONCE,
+        },
+    )
+    .await
+    .unwrap();
+
+    let args = sim.take_queued(offset);
+    assert_eq!(args.len(), 2 + 5 + 5 + 2);
+    let queued_values: Vec<u64> = args[2..7]
+        .iter()
+        .map(|arg| match arg {
+            Argument::EncryptedU64(value) => plain(value),
+            _ => panic!("weighted_average queued a non-u64 value"),
+        })
+        .collect();
+    let queued_weights: Vec<u8> = args[7..12]
+        .iter()
+        .map(|arg| match arg {
+            Argument::EncryptedU8(weight) => plain(weight) as u8,
+            _ => panic!("weighted_average queued a non-u8 weight"),
+        })
+        .collect();
+    assert_eq!(
+        (queued_values, queued_weights),
+        (values.to_vec(), weights.to_vec())
+    );
+    assert!(matches!(
+        args[12..],
+        [Argument::ArcisPubkey(key), Argument::PlaintextU128(nonce)]
+            if key == RECIPIENT_PUB_KEY && nonce == RECIPIENT_NONCE
+    ));
+    let result = reference::use_cases::weighted_average(values, weights);
+
+    let logs = sim
+        .deliver(
+            callback_accounts!(WeightedAverageCallback, "weighted_average", offset),
+            instruction::WeightedAverageCallback {
+                output: ComputationOutputs::Success(WeightedAverageOutput {
+                    field_0: WeightedAverageOutputStruct0 {
+                        field_0: shared([ct(result)]),
+                        field_1: shared_to_recipient([ct(result)]),
+                    },
+                }),
+            },
+        )
+        .await
+        .unwrap();
+
+    let event = events::<WeightedAverageEvent>(&logs)
+        .pop()
+        .expect("no WeightedAverageEvent");
+    assert_eq!(plain(&event.result), 40);
+    assert_eq!(plain(&event.recipient_result), 40);
+    assert_eq!(event.recipient_pub_key, RECIPIENT_PUB_KEY);
+}
+
+#[tokio::test]
+async fn credit_score_shares_and_reveals_the_approval() {
+    let mut sim = Simulator::start(&["credit_score"]).await;
+    let offset = 16;
+
+    sim.send(
+        queue_accounts!(sim, CreditScore, "credit_score", offset),
+        instruction::CreditScore {
+            computation_offset: offset,
+            income: ct(75_000),
+            debt: ct(15_000),
+            credit_history: ct(10),
+            missed_payments: ct(0),
+            lender_pub_key: RECIPIENT_PUB_KEY,
+            lender_nonce: RECIPIENT_NONCE,
+            pub_key: PUB_KEY,
+            nonce: NONCE,
+        },
+    )
+    .await
+    .unwrap();
+
+    let args = sim.take_queued(offset);
+    let [Argument::ArcisPubkey(key), Argument::PlaintextU128(nonce), Argument::EncryptedU64(income), Argument::EncryptedU64(debt), Argument::EncryptedU8(history), Argument::EncryptedU8(missed), Argument::ArcisPubkey(lender_key), Argument::PlaintextU128(lender_nonce)] =
+        args.as_slice()
+    else {
+        panic!(
+            "credit_score queued {} arguments in the wrong layout",
+            args.len()
+        );
+    };
+    assert_eq!((*key, *nonce), (PUB_KEY, NONCE));
+    assert_eq!(
+        (*lender_key, *lender_nonce),
+        (RECIPIENT_PUB_KEY, RECIPIENT_NONCE)
+    );
+    let (score, approved) = reference::use_cases::credit_score(
+        plain(income),
+        plain(debt),
+        plain(history) as u8,
+        plain(missed) as u8,
+    );
+    assert_eq!((score, approved), (750, 1));
+
+    let logs = sim
+        .deliver(
+            callback_accounts!(CreditScoreCallback, "credit_score", offset),
+            instruction::CreditScoreCallback {
+                output: ComputationOutputs::Success(CreditScoreOutput {
+                    field_0: CreditScoreOutputStruct0 {
+                        field_0: shared([ct(score.into()), ct(approved.into())]),
+                        field_1: shared_to_recipient([ct(approved.into())]),
+                        field_2: approved,
+                    },
+                }),
+            },
+        )
+        .await
+        .unwrap();
+
+    let event = events::<CreditScoreEvent>(&logs)
+        .pop()
+        .expect("no CreditScoreEvent");
+    assert_eq!((plain(&event.score), plain(&event.approved)), (750, 1));
+    assert_eq!(plain(&event.lender_approved), 1);
+    assert_eq!(event.lender_pub_key, RECIPIENT_PUB_KEY);
+    assert_eq!(event.lender_nonce, RECIPIENT_NONCE.to_le_bytes());
+    assert_eq!(event.revealed_approved, 1);
+
+    let stored: ComputationResult = sim
+        .account(simulator::computation_result_address(offset))
+        .await;
+    let recipient = SharedOutput {
+        ciphertexts: vec![ct(1)],
+        encryption_key: RECIPIENT_PUB_KEY,
+        nonce: RECIPIENT_NONCE.to_le_bytes(),
+    };
+    assert_eq!(stored.recipient, Some(recipient.clone()));
+    assert_eq!(stored.revealed, vec![1]);
+
+    let attestation: Attestation = sim.account(simulator::attestation_address(offset)).await;
+    assert_eq!(
+        attestation.outputs_hash,
+        flaek_mxe::outputs_hash(&[ct(750), ct(1)], NONCE, Some(&recipient), &[1])
+    );
+    assert_ne!(
+        attestation.outputs_hash,
+        flaek_mxe::outputs_hash(&[ct(750), ct(1)], NONCE, None, &[1])
+    );
+}
+
+#[tokio::test]
+async fn health_risk_shares_the_category_with_the_insurer() {
+    let mut sim = Simulator::start(&["health_risk"]).await;
+    let offset = 17;
+
+    sim.send(
+        queue_accounts!(sim, HealthRisk, "health_risk", offset),
+        instruction::HealthRisk {
+            computation_offset: offset,
+            age: ct(58),
+            bmi: ct(31),
+            smoker: ct(1),
+            exercise_hours: ct(1),
+            family_history: ct(0),
+            insurer_pub_key: RECIPIENT_PUB_KEY,
+            insurer_nonce: RECIPIENT_NONCE,
+            pub_key: PUB_KEY,
+            nonce: NONCE,
+        },
+    )
+    .await
+    .unwrap();
+
+    let args = sim.take_queued(offset);
+    let [Argument::ArcisPubkey(key), Argument::PlaintextU128(nonce), Argument::EncryptedU8(age), Argument::EncryptedU8(bmi), Argument::EncryptedU8(smoker), Argument::EncryptedU8(exercise), Argument::EncryptedU8(family), Argument::ArcisPubkey(insurer_key), Argument::PlaintextU128(insurer_nonce)] =
+        args.as_slice()
+    else {
+        panic!(
+            "health_risk queued {} arguments in the wrong layout",
+            args.len()
+        );
+    };
+    assert_eq!((*key, *nonce), (PUB_KEY, NONCE));
+    assert_eq!(
+        (*insurer_key, *insurer_nonce),
+        (RECIPIENT_PUB_KEY, RECIPIENT_NONCE)
+    );
+    let (risk_score, risk_category) = reference::use_cases::health_risk(
+        plain(age) as u8,
+        plain(bmi) as u8,
+        plain(smoker) as u8,
+        plain(exercise) as u8,
+        plain(family) as u8,
+    );
+    assert_eq!((risk_score, risk_category), (75, 3));
+
+    let logs = sim
+        .deliver(
+            callback_accounts!(HealthRiskCallback, "health_risk", offset),
+            instruction::HealthRiskCallback {
+                output: ComputationOutputs::Success(HealthRiskOutput {
+                    field_0: HealthRiskOutputStruct0 {
+                        field_0: shared([ct(risk_score.into()), ct(risk_category.into())]),
+                        field_1: shared_to_recipient([ct(risk_category.into())]),
+                    },
+                }),
+            },
+        )
+        .await
+        .unwrap();
+
+    let event = events::<HealthRiskEvent>(&logs)
+        .pop()
+        .expect("no HealthRiskEvent");
+    assert_eq!(plain(&event.risk_score), 75);
+    assert_eq!(plain(&event.insurer_risk_category), 3);
+    assert_eq!(event.insurer_pub_key, RECIPIENT_PUB_KEY);
+    assert_eq!(event.insurer_nonce, RECIPIENT_NONCE.to_le_bytes());
+
+    let stored: ComputationResult = sim
+        .account(simulator::computation_result_address(offset))
+        .await;
+    assert_eq!(
+        stored.recipient.map(|recipient| recipient.ciphertexts),
+        Some(vec![ct(3)])
+    );
+    assert!(stored.revealed.is_empty());
+}
+
+#[tokio::test]
+async fn vote_tally_reveals_the_count() {
+    let mut sim = Simulator::start(&["vote_tally"]).await;
+    let offset = 18;
+    let votes = [1, 0, 1, 1, 0, 1, 0, 0, 1, 1];
+
+    sim.send(
+        queue_accounts!(sim, VoteTally, "vote_tally", offset),
+        instruction::VoteTally {
+            computation_offset: offset,
+            votes: votes.map(|vote| ct(vote.into())),
+            count: ct(6),
+            tallier_pub_key: RECIPIENT_PUB_KEY,
+            tallier_nonce: RECIPIENT_NONCE,
+            pub_key: PUB_KEY,
+            nonce: NONCE,
+        },
+    )
+    .await
+    .unwrap();
+
+    let args = sim.take_queued(offset);
+    assert_eq!(args.len(), 2 + 10 + 1 + 2);
+    let queued_votes = args[2..12].iter().map(|arg| match arg {
+        Argument::EncryptedU8(vote) => plain(vote) as u8,
+        _ => panic!("vote_tally queued a non-u8 vote"),
+    });
+    assert!(queued_votes.eq(votes));
+    let Argument::EncryptedU8(count) = &args[12] else {
+        panic!("vote_tally queued a non-u8 count");
+    };
+    let tally = reference::use_cases::vote_tally(votes, plain(count) as u8);
+    assert_eq!(tally, 4);
+
+    let logs = sim
+        .deliver(
+            callback_accounts!(VoteTallyCallback, "vote_tally", offset),
+            instruction::VoteTallyCallback {
+                output: ComputationOutputs::Success(VoteTallyOutput {
+                    field_0: VoteTallyOutputStruct0 {
+                        field_0: shared([ct(tally.into())]),
+                        field_1: shared_to_recipient([ct(tally.into())]),
+                        field_2: tally,
+                    },
+                }),
+            },
+        )
+        .await
+        .unwrap();
+
+    let event = events::<VoteTallyEvent>(&logs)
+        .pop()
+        .expect("no VoteTallyEvent");
+    assert_eq!(plain(&event.result), 4);
+    assert_eq!(plain(&event.tallier_result), 4);
+    assert_eq!(event.revealed_result, 4);
+
+    let stored: ComputationResult = sim
+        .account(simulator::computation_result_address(offset))
+        .await;
+    assert_eq!(stored.revealed, vec![4]);
+}
+
+#[test]
+fn results_that_overflow_the_account_are_rejected() {
+    let mut result = ComputationResult {
+        computation_offset: 0,
+        payer: Pubkey::default(),
+        comp_def_offset: 0,
+        status: ComputationStatus::Queued,
+        ciphertexts: Vec::new(),
+        nonce: [0; 16],
+        recipient: None,
+        revealed: Vec::new(),
+        slot: 0,
+        credits_debited: 0,
+        bump: 0,
+    };
+    let too_many = anchor_lang::error::Error::from(ErrorCode::TooManyOutputs);
+
+    let revealed = [1; ComputationResult::MAX_REVEALED + 1];
+    let err = flaek_mxe::store_computation_result(&mut result, &[], NONCE, None, &revealed);
+    assert_eq!(err.unwrap_err(), too_many);
+
+    let ciphertexts = [ct(1); ComputationResult::MAX_CIPHERTEXTS + 1];
+    let err = flaek_mxe::store_computation_result(&mut result, &ciphertexts, NONCE, None, &[]);
+    assert_eq!(err.unwrap_err(), too_many);
+
+    let recipient = SharedOutput {
+        ciphertexts: ciphertexts.to_vec(),
+        encryption_key: RECIPIENT_PUB_KEY,
+        nonce: RECIPIENT_NONCE.to_le_bytes(),
+    };
+    let err = flaek_mxe::store_computation_result(&mut result, &[], NONCE, Some(&recipient), &[]);
+    assert_eq!(err.unwrap_err(), too_many);
+    assert!(result.status == ComputationStatus::Queued);
+}
+
+#[tokio::test]
+async fn pipeline_eval_returns_every_register() {
+    let mut sim = Simulator::start(&["pipeline_eval"]).await;
+    let offset = 9;
+    let mut program = [0u8; 64];
+    program[..4].copy_from_slice(&[3, 2, 0, 1]); // r2 = r0 * r1
+    let registers = [6, 7, 0, 0, 0, 0, 0, 5];
+
+    sim.send(
+        queue_accounts!(sim, PipelineEval, "pipeline_eval", offset),
+        instruction::PipelineEval {
+            computation_offset: offset,
+            program,
+            registers: registers.map(ct),
+            pub_key: PUB_KEY,
+            nonce: NONCE,
+        },
+    )
+    .await
+    .unwrap();
+
+    let args = sim.take_queued(offset);
+    assert_eq!(args.len(), 64 + 2 + 8);
+    assert!(args[..64]
+        .iter()
+        .zip(program)
+        .all(|(arg, byte)| matches!(arg, Argument::PlaintextU8(b) if *b == byte)));
+    assert!(matches!(args[64], Argument::ArcisPubkey(key) if key == PUB_KEY));
+    let result = reference::pipeline::pipeline_eval(program, registers);
+
+    let logs = sim
+        .deliver(
+            callback_accounts!(PipelineEvalCallback, "pipeline_eval", offset),
+            instruction::PipelineEvalCallback {
+                output: ComputationOutputs::Success(PipelineEvalOutput {
+                    field_0: shared(result.map(ct)),
+                }),
+            },
+        )
+        .await
+        .unwrap();
+
+    let event = events::<PipelineEvalEvent>(&logs)
+        .pop()
+        .expect("no PipelineEvalEvent");
+    assert_eq!(
+        event.registers.map(|r| plain(&r)),
+        [6, 7, 42, 0, 0, 0, 0, 5]
+    );
+
+    let stored: ComputationResult = sim
+        .account(simulator::computation_result_address(offset))
+        .await;
+    assert_eq!(stored.ciphertexts, result.map(ct).to_vec());
+}
+
+/// Seals `value` into a fresh intermediate and returns its address.
+async fn seal(sim: &mut Simulator, offset: u64, value: u64) -> Pubkey {
+    sim.send(
+        queue_accounts!(sim, Seal, "seal", offset, intermediate: intermediate_address(offset)),
+        instruction::Seal {
+            computation_offset: offset,
+            value: ct(value),
+            pub_key: PUB_KEY,
+            nonce: NONCE,
+        },
+    )
+    .await
+    .unwrap();
+
+    let args = sim.take_queued(offset);
+    let [_, _, Argument::EncryptedU64(sealed)] = args.as_slice() else {
+        panic!("seal queued {} arguments in the wrong layout", args.len());
+    };
+    let sealed = reference::pipeline::seal(plain(sealed));
+
+    sim.deliver(
+        callback_accounts!(SealCallback, "seal", offset, intermediate: intermediate_address(offset)),
+        instruction::SealCallback {
+            output: ComputationOutputs::Success(SealOutput {
+                field_0: mxe_owned([ct(sealed)]),
+            }),
+        },
+    )
+    .await
+    .unwrap();
+    intermediate_address(offset)
+}
+
+#[tokio::test]
+async fn pipeline_step_reads_intermediates_from_their_accounts() {
+    let mut sim = Simulator::start(&["seal", "pipeline_step"]).await;
+    let lhs = seal(&mut sim, 10, 6).await;
+    let rhs = seal(&mut sim, 11, 7).await;
+    let offset = 12;
+    let op = 2; // multiply
+
+    sim.send(
+        queue_accounts!(
+            sim,
+            PipelineStep,
+            "pipeline_step",
+            offset,
+            lhs: lhs,
+            rhs: rhs,
+            intermediate: intermediate_address(offset),
+        ),
+        instruction::PipelineStep {
+            computation_offset: offset,
+            op,
+        },
+    )
+    .await
+    .unwrap();
+
+    let args = sim.take_queued(offset);
+    let [Argument::PlaintextU8(queued_op), Argument::PlaintextU128(lhs_nonce), Argument::Account(lhs_key, lhs_offset, lhs_len), Argument::PlaintextU128(rhs_nonce), Argument::Account(rhs_key, rhs_offset, rhs_len)] =
+        args.as_slice()
+    else {
+        panic!(
+            "pipeline_step queued {} arguments in the wrong layout",
+            args.len()
+        );
+    };
+    assert_eq!(*queued_op, op);
+    assert_eq!((*lhs_key, *rhs_key), (lhs, rhs));
+    assert_eq!((*lhs_nonce, *rhs_nonce), (NONCE, NONCE));
+
+    let a = sim.read(*lhs_key, *lhs_offset, *lhs_len).await;
+    let b = sim.read(*rhs_key, *rhs_offset, *rhs_len).await;
+    let result = reference::pipeline::pipeline_step(op, plain(&a), plain(&b));
+
+    let logs = sim
+        .deliver(
+            callback_accounts!(
+                PipelineStepCallback,
+                "pipeline_step",
+                offset,
+                intermediate: intermediate_address(offset),
+            ),
+            instruction::PipelineStepCallback {
+                output: ComputationOutputs::Success(PipelineStepOutput {
+                    field_0: mxe_owned([ct(result)]),
+                }),
+            },
+        )
+        .await
+        .unwrap();
+
+    let event = events::<PipelineStepEvent>(&logs)
+        .pop()
+        .expect("no PipelineStepEvent");
+    assert_eq!(event.intermediate, intermediate_address(offset));
+
+    let product: Intermediate = sim.account(intermediate_address(offset)).await;
+    assert!(product.status == ComputationStatus::Completed);
+    assert_eq!(plain(&product.ciphertext), 42);
+}
+
+#[tokio::test]
+async fn unseal_encrypts_an_intermediate_to_the_recipient() {
+    let mut sim = Simulator::start(&["seal", "unseal"]).await;
+    let sealed = seal(&mut sim, 13, 42).await;
+    let offset = 14;
+    let recipient = [9; 32];
+
+    sim.send(
+        queue_accounts!(sim, Unseal, "unseal", offset, value: sealed),
+        instruction::Unseal {
+            computation_offset: offset,
+            recipient_pub_key: recipient,
+            recipient_nonce: NONCE + 1,
+        },
+    )
+    .await
+    .unwrap();
+
+    let args = sim.take_queued(offset);
+    let [Argument::ArcisPubkey(key), Argument::PlaintextU128(nonce), Argument::PlaintextU128(_), Argument::Account(value_key, value_offset, value_len)] =
+        args.as_slice()
+    else {
+        panic!("unseal queued {} arguments in the wrong layout", args.len());
+    };
+    assert_eq!((*key, *nonce), (recipient, NONCE + 1));
+    assert_eq!(*value_key, sealed);
+    let value = sim.read(*value_key, *value_offset, *value_len).await;
+    let result = reference::pipeline::unseal(plain(&value));
+
+    let logs = sim
+        .deliver(
+            callback_accounts!(UnsealCallback, "unseal", offset),
+            instruction::UnsealCallback {
+                output: ComputationOutputs::Success(UnsealOutput {
+                    field_0: shared([ct(result)]),
+                }),
+            },
+        )
+        .await
+        .unwrap();
+
+    let event = events::<UnsealEvent>(&logs).pop().expect("no UnsealEvent");
+    assert_eq!(plain(&event.result), 42);
+}
+
+#[tokio::test]
+async fn intermediates_of_other_payers_are_rejected() {
+    let mut sim = Simulator::start(&["seal", "unseal"]).await;
+    let sealed = seal(&mut sim, 15, 42).await;
+    let mut intermediate: Intermediate = sim.account(sealed).await;
+    intermediate.payer = Pubkey::new_unique();
+    sim.set_account(sealed, &intermediate);
+
+    let err = sim
+        .send(
+            queue_accounts!(sim, Unseal, "unseal", 16, value: sealed),
+            instruction::Unseal {
+                computation_offset: 16,
+                recipient_pub_key: PUB_KEY,
+                recipient_nonce: NONCE,
+            },
+        )
+        .await
+        .unwrap_err();
+    assert!(matches!(
+        err,
+        BanksClientError::TransactionError(TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(code),
+        )) if code == u32::from(ErrorCode::IntermediateNotOwned)
+    ));
+}
@@ -0,0 +1,368 @@
+mod simulator;
+
+use anchor_lang::prelude::*;
+use anchor_spl::token::spl_token;
+use arcium_anchor::prelude::*;
+use arcium_client::idl::arcium::types::{CircuitSource, OffChainCircuitSource};
+use flaek_mxe::{instruction, CircuitFee, CircuitRegistry, Config, ErrorCode, Gate};
+use simulator::{ct, error_code, queue_accounts, Simulator, NONCE, PUB_KEY};
+use solana_sdk::signature::{Keypair, Signer};
+
+fn init_config(sim: &Simulator) -> flaek_mxe::accounts::InitConfig {
+    flaek_mxe::accounts::InitConfig {
+        authority: sim.payer(),
+        config: simulator::config_address(),
+        treasury: simulator::treasury_address(),
+        program: flaek_mxe::ID,
+        program_data: simulator::program_data_address(),
+        system_program: anchor_lang::system_program::ID,
+    }
+}
+
+fn update_config(authority: Pubkey) -> flaek_mxe::accounts::UpdateConfig {
+    flaek_mxe::accounts::UpdateConfig {
+        authority,
+        config: simulator::config_address(),
+    }
+}
+
+fn update_registry(sim: &Simulator, circuit: &str) -> flaek_mxe::accounts::UpdateCircuitRegistry {
+    flaek_mxe::accounts::UpdateCircuitRegistry {
+        authority: sim.payer(),
+        config: simulator::config_address(),
+        circuit_registry: simulator::registry_address(circuit),
+        comp_def_account: simulator::comp_def_address(circuit),
+        system_program: anchor_lang::system_program::ID,
+    }
+}
+
+fn queue_add(sim: &Simulator, offset: u64, co_signer: Option<Pubkey>) -> flaek_mxe::accounts::Add {
+    let mut accounts = queue_accounts!(sim, Add, "add", offset);
+    accounts.co_signer = co_signer;
+    accounts
+}
+
+fn add(offset: u64) -> instruction::Add {
+    instruction::Add {
+        computation_offset: offset,
+        a: ct(1),
+        b: ct(2),
+        pub_key: PUB_KEY,
+        nonce: NONCE,
+    }
+}
+
+fn withdraw_treasury(
+    sim: &Simulator,
+    recipient: Pubkey,
+    fee_accounts: Option<(Pubkey, Pubkey)>,
+) -> flaek_mxe::accounts::WithdrawTreasury {
+    flaek_mxe::accounts::WithdrawTreasury {
+        authority: sim.payer(),
+        config: simulator::config_address(),
+        treasury: simulator::treasury_address(),
+        recipient,
+        treasury_fee_account: fee_accounts.map(|(from, _)| from),
+        recipient_fee_account: fee_accounts.map(|(_, to)| to),
+        token_program: fee_accounts.map(|_| spl_token::ID),
+        system_program: anchor_lang::system_program::ID,
+    }
+}
+
+fn off_chain(source: &str, hash: [u8; 32]) -> impl FnOnce(&mut ComputationDefinitionAccount) {
+    let source = source.to_string();
+    move |comp_def| {
+        comp_def.circuit_source = CircuitSource::OffChain(OffChainCircuitSource { source, hash })
+    }
+}
+
+#[tokio::test]
+async fn only_the_upgrade_authority_initializes_the_config() {
+    let mut sim = Simulator::start_unconfigured().await;
+    let payer = sim.payer();
+
+    sim.set_upgrade_authority(Some(Pubkey::new_unique()));
+    let err = sim
+        .send(init_config(&sim), instruction::InitConfig {})
+        .await
+        .unwrap_err();
+    assert_eq!(error_code(&err), Some(u32::from(ErrorCode::NotAuthority)));
+
+    sim.set_upgrade_authority(Some(payer));
+    sim.send(init_config(&sim), instruction::InitConfig {})
+        .await
+        .unwrap();
+    let config: Config = sim.account(simulator::config_address()).await;
+    assert_eq!(config.authority, payer);
+    assert!(config.gate == Gate::Open);
+    assert_eq!(
+        sim.balance(simulator::treasury_address()).await,
+        Rent::default().minimum_balance(0)
+    );
+}
+
+#[tokio::test]
+async fn transferring_authority_hands_over_the_config() {
+    let mut sim = Simulator::start(&[]).await;
+    let payer = sim.payer();
+    sim.edit_config(|config| config.authority = payer).await;
+    let next = Keypair::new();
+
+    sim.send(
+        flaek_mxe::accounts::TransferAuthority {
+            authority: payer,
+            config: simulator::config_address(),
+        },
+        instruction::TransferAuthority {
+            new_authority: next.pubkey(),
+        },
+    )
+    .await
+    .unwrap();
+    let config: Config = sim.account(simulator::config_address()).await;
+    assert_eq!(config.authority, next.pubkey());
+
+    let err = sim
+        .send(
+            update_config(payer),
+            instruction::SetGate {
+                gate: Gate::AllowList,
+            },
+        )
+        .await
+        .unwrap_err();
+    assert_eq!(error_code(&err), Some(u32::from(ErrorCode::NotAuthority)));
+
+    sim.send_signed(
+        update_config(next.pubkey()),
+        instruction::SetGate {
+            gate: Gate::AllowList,
+        },
+        &[&next],
+    )
+    .await
+    .unwrap();
+    let config: Config = sim.account(simulator::config_address()).await;
+    assert!(config.gate == Gate::AllowList);
+}
+
+#[tokio::test]
+async fn circuit_registry_mirrors_the_comp_def_source() {
+    let mut sim = Simulator::start(&["add"]).await;
+    let payer = sim.payer();
+    let registry = simulator::registry_address("add");
+
+    sim.set_comp_def(
+        "add",
+        off_chain("https://circuits.example/add_devnet.arcis", [1; 32]),
+    );
+    let err = sim
+        .send(
+            update_registry(&sim, "add"),
+            instruction::UpdateCircuitRegistry {
+                circuit: "add".into(),
+            },
+        )
+        .await
+        .unwrap_err();
+    assert_eq!(error_code(&err), Some(u32::from(ErrorCode::NotAuthority)));
+
+    sim.edit_config(|config| config.authority = payer).await;
+    sim.send(
+        update_registry(&sim, "add"),
+        instruction::UpdateCircuitRegistry {
+            circuit: "add".into(),
+        },
+    )
+    .await
+    .unwrap();
+    let pinned: CircuitRegistry = sim.account(registry).await;
+    assert_eq!(pinned.comp_def_offset, comp_def_offset("add"));
+    assert_eq!(pinned.circuit, "add");
+    assert_eq!(pinned.source, "https://circuits.example/add_devnet.arcis");
+    assert_eq!(pinned.hash, [1; 32]);
+
+    // A re-pointed comp def is picked up on the next resync.
+    sim.set_comp_def(
+        "add",
+        off_chain("https://mirror.example/add_devnet.arcis", [2; 32]),
+    );
+    sim.send(
+        update_registry(&sim, "add"),
+        instruction::UpdateCircuitRegistry {
+            circuit: "add".into(),
+        },
+    )
+    .await
+    .unwrap();
+    let pinned: CircuitRegistry = sim.account(registry).await;
+    assert_eq!(pinned.source, "https://mirror.example/add_devnet.arcis");
+    assert_eq!(pinned.hash, [2; 32]);
+}
+
+#[tokio::test]
+async fn circuit_registry_needs_a_hash_for_non_off_chain_sources() {
+    let mut sim = Simulator::start(&["add"]).await;
+    let payer = sim.payer();
+    sim.edit_config(|config| config.authority = payer).await;
+
+    // The stub comp def has a local source, and the registry no hash.
+    sim.set_registry("add", |registry| registry.hash = [0; 32]);
+    let err = sim
+        .send(
+            update_registry(&sim, "add"),
+            instruction::UpdateCircuitRegistry {
+                circuit: "add".into(),
+            },
+        )
+        .await
+        .unwrap_err();
+    assert_eq!(
+        error_code(&err),
+        Some(u32::from(ErrorCode::CircuitHashMissing))
+    );
+}
+
+#[tokio::test]
+async fn co_signer_gate_needs_the_configured_co_signer() {
+    let co_signer = Keypair::new();
+    let key = co_signer.pubkey();
+    let mut sim = Simulator::start_with(&["add"], |config| config.gate = Gate::CoSigner(key)).await;
+
+    let err = sim
+        .send(queue_add(&sim, 1, None), add(1))
+        .await
+        .unwrap_err();
+    assert_eq!(error_code(&err), Some(u32::from(ErrorCode::Unauthorized)));
+
+    let stranger = Keypair::new();
+    let err = sim
+        .send_signed(
+            queue_add(&sim, 2, Some(stranger.pubkey())),
+            add(2),
+            &[&stranger],
+        )
+        .await
+        .unwrap_err();
+    assert_eq!(error_code(&err), Some(u32::from(ErrorCode::Unauthorized)));
+
+    sim.send_signed(queue_add(&sim, 3, Some(key)), add(3), &[&co_signer])
+        .await
+        .unwrap();
+    sim.take_queued(3);
+}
+
+#[tokio::test]
+async fn fee_mint_charges_the_fee_in_tokens() {
+    let fee = 250;
+    let mint = Pubkey::new_unique();
+    let mut sim = Simulator::start_with(&["add"], |config| {
+        config.fee_mint = Some(mint);
+        config.fees = vec![CircuitFee {
+            comp_def_offset: comp_def_offset("add"),
+            amount: fee,
+        }];
+    })
+    .await;
+    let (payer_fee_account, treasury_fee_account) = (Pubkey::new_unique(), Pubkey::new_unique());
+    sim.set_mint(mint);
+    sim.set_token_account(payer_fee_account, mint, sim.payer(), 1_000);
+    sim.set_token_account(treasury_fee_account, mint, simulator::treasury_address(), 0);
+
+    let err = sim
+        .send(queue_add(&sim, 4, None), add(4))
+        .await
+        .unwrap_err();
+    assert_eq!(
+        error_code(&err),
+        Some(u32::from(ErrorCode::MissingFeeAccounts))
+    );
+
+    let mut accounts = queue_add(&sim, 5, None);
+    accounts.payer_fee_account = Some(payer_fee_account);
+    accounts.treasury_fee_account = Some(treasury_fee_account);
+    accounts.token_program = Some(spl_token::ID);
+    sim.send(accounts, add(5)).await.unwrap();
+    sim.take_queued(5);
+    assert_eq!(sim.token_balance(payer_fee_account).await, 1_000 - fee);
+    assert_eq!(sim.token_balance(treasury_fee_account).await, fee);
+}
+
+#[tokio::test]
+async fn lamport_withdrawals_keep_the_treasury_rent_exempt() {
+    let mut sim = Simulator::start(&[]).await;
+    let payer = sim.payer();
+    sim.edit_config(|config| config.authority = payer).await;
+    let treasury = simulator::treasury_address();
+    let rent = Rent::default().minimum_balance(0);
+    sim.set_lamports(treasury, rent + 5_000);
+    let recipient = Pubkey::new_unique();
+    sim.set_lamports(recipient, rent);
+
+    let err = sim
+        .send(
+            withdraw_treasury(&sim, recipient, None),
+            instruction::WithdrawTreasury { amount: 5_001 },
+        )
+        .await
+        .unwrap_err();
+    assert_eq!(
+        error_code(&err),
+        Some(u32::from(ErrorCode::TreasuryBelowRent))
+    );
+
+    sim.send(
+        withdraw_treasury(&sim, recipient, None),
+        instruction::WithdrawTreasury { amount: 5_000 },
+    )
+    .await
+    .unwrap();
+    assert_eq!(sim.balance(treasury).await, rent);
+    assert_eq!(sim.balance(recipient).await, rent + 5_000);
+}
+
+#[tokio::test]
+async fn token_withdrawals_need_both_token_accounts() {
+    let mut sim = Simulator::start(&[]).await;
+    let payer = sim.payer();
+    sim.edit_config(|config| config.authority = payer).await;
+    let mint = Pubkey::new_unique();
+    let (treasury_fee_account, recipient_fee_account) =
+        (Pubkey::new_unique(), Pubkey::new_unique());
+    sim.set_mint(mint);
+    sim.set_token_account(
+        treasury_fee_account,
+        mint,
+        simulator::treasury_address(),
+        700,
+    );
+    sim.set_token_account(recipient_fee_account, mint, payer, 0);
+
+    let mut accounts = withdraw_treasury(
+        &sim,
+        payer,
+        Some((treasury_fee_account, recipient_fee_account)),
+    );
+    accounts.recipient_fee_account = None;
+    let err = sim
+        .send(accounts, instruction::WithdrawTreasury { amount: 300 })
+        .await
+        .unwrap_err();
+    assert_eq!(
+        error_code(&err),
+        Some(u32::from(ErrorCode::MissingFeeAccounts))
+    );
+
+    sim.send(
+        withdraw_treasury(
+            &sim,
+            payer,
+            Some((treasury_fee_account, recipient_fee_account)),
+        ),
+        instruction::WithdrawTreasury { amount: 300 },
+    )
+    .await
+    .unwrap();
+    assert_eq!(sim.token_balance(treasury_fee_account).await, 400);
+    assert_eq!(sim.token_balance(recipient_fee_account).await, 300);
+}
@@ -33,12 +33,15 @@ describe("FlaekMxe", () => {
 
   type Event = anchor.IdlEvents<(typeof program)["idl"]>;
   const awaitEvent = async <E extends keyof Event>(
-    eventName: E
+    eventName: E,
+    computationOffset: anchor.BN
   ): Promise<Event[E]> => {
     let listenerId: number;
     const event = await new Promise<Event[E]>((res) => {
-      listenerId = program.addEventListener(eventName, (event) => {
-        res(event);
+      listenerId = program.addEventListener(eventName, (event: any) => {
+        if (event.computationOffset.eq(computationOffset)) {
+          res(event);
+        }
       });
     });
     await program.removeEventListener(listenerId);
@@ -49,6 +52,88 @@ describe("FlaekMxe", () => {
   const CLUSTER_OFFSET = 1078779259;
   const clusterAccount = getClusterAccAddress(CLUSTER_OFFSET);
   const HEAP_FRAME_BYTES = 256_000;
+  // Directory serving the build/ artifacts under their own file names, e.g.
+  // add_devnet.arcis; when unset circuits are uploaded on-chain.
+  const CIRCUITS_BASE_URL = process.env.CIRCUITS_BASE_URL ?? null;
+
+  const getCircuitRegistryAddress = (circuitName: string) =>
+    PublicKey.findProgramAddressSync(
+      [Buffer.from("circuit_registry"), getCompDefAccOffset(circuitName)],
+      program.programId
+    )[0];
+  const configAddress = PublicKey.findProgramAddressSync(
+    [Buffer.from("config")],
+    program.programId
+  )[0];
+  const treasuryAddress = PublicKey.findProgramAddressSync(
+    [Buffer.from("treasury")],
+    program.programId
+  )[0];
+
+  // Every account queuing `circuit` at `offset` takes. The config's gate is
+  // open and no fees or credit costs are set here, so nobody co-signs, fees,
+  // if any, are paid in lamports and no credits are debited.
+  const queueAccounts = (circuit: string, offset: anchor.BN) => ({
+    computationAccount: getComputationAccAddress(program.programId, offset),
+    clusterAccount,
+    mxeAccount: getMXEAccAddress(program.programId),
+    mempoolAccount: getMempoolAccAddress(program.programId),
+    executingPool: getExecutingPoolAccAddress(program.programId),
+    compDefAccount: getCompDefAccAddress(
+      program.programId,
+      Buffer.from(getCompDefAccOffset(circuit)).readUInt32LE()
+    ),
+    circuitRegistry: getCircuitRegistryAddress(circuit),
+    attestation: PublicKey.findProgramAddressSync(
+      [Buffer.from("attestation"), offset.toArrayLike(Buffer, "le", 8)],
+      program.programId
+    )[0],
+    config: configAddress,
+    coSigner: null,
+    treasury: treasuryAddress,
+    payerFeeAccount: null,
+    treasuryFeeAccount: null,
+    tokenProgram: null,
+    credits: null,
+  });
+
+  // Comp defs can only be initialized by the config authority, which has to
+  // be the upgrade authority, i.e. the wallet that deployed the program.
+  before(async () => {
+    const owner = readKpJson(`${os.homedir()}/.config/solana/id.json`);
+    try {
+      await program.methods
+        .initConfig()
+        .accountsPartial({
+          authority: owner.publicKey,
+          treasury: treasuryAddress,
+          programData: PublicKey.findProgramAddressSync(
+            [program.programId.toBuffer()],
+            new PublicKey("BPFLoaderUpgradeab1e11111111111111111111111")
+          )[0],
+        })
+        .signers([owner])
+        .rpc({ commitment: "confirmed" });
+    } catch (err: any) {
+      if (!/already in use/i.test(String(err?.message ?? ""))) {
+        throw err;
+      }
+    }
+  });
+
+  it("rejects authority transfers from anyone but the authority", async () => {
+    const stranger = anchor.web3.Keypair.generate();
+    try {
+      await program.methods
+        .transferAuthority(stranger.publicKey)
+        .accounts({ authority: stranger.publicKey })
+        .signers([stranger])
+        .rpc();
+      expect.fail("transfer_authority accepted a non-authority signer");
+    } catch (err: any) {
+      expect(err.error?.errorCode?.code).to.equal("NotAuthority");
+    }
+  });
 
   it("Is initialized!", async () => {
     const owner = readKpJson(`${os.homedir()}/.config/solana/id.json`);
@@ -57,8 +142,8 @@ describe("FlaekMxe", () => {
     const initATSig = await initAddCompDef(
       program,
       owner,
-      false,
-      false
+      !CIRCUITS_BASE_URL,
+      !!CIRCUITS_BASE_URL
     );
     console.log(
       "Add computation definition initialized with signature",
@@ -85,8 +170,8 @@ describe("FlaekMxe", () => {
     const nonce = randomBytes(16);
     const ciphertext = cipher.encrypt(plaintext, nonce);
 
-    const addEventPromise = awaitEvent("addEvent");
     const computationOffset = new anchor.BN(randomBytes(8), "hex");
+    const addEventPromise = awaitEvent("addEvent", computationOffset);
 
     const queueSig = await program.methods
       .add(
@@ -96,20 +181,7 @@ describe("FlaekMxe", () => {
         Array.from(publicKey),
         new anchor.BN(deserializeLE(nonce).toString())
       )
-      .accountsPartial({
-        computationAccount: getComputationAccAddress(
-          program.programId,
-          computationOffset
-        ),
-        clusterAccount: clusterAccount,
-        mxeAccount: getMXEAccAddress(program.programId),
-        mempoolAccount: getMempoolAccAddress(program.programId),
-        executingPool: getExecutingPoolAccAddress(program.programId),
-        compDefAccount: getCompDefAccAddress(
-          program.programId,
-          Buffer.from(getCompDefAccOffset("add")).readUInt32LE()
-        ),
-      })
+      .accountsPartial(queueAccounts("add", computationOffset))
       .rpc({ skipPreflight: true, commitment: "confirmed" });
     console.log("Queue sig is ", queueSig);
 
@@ -124,11 +196,40 @@ describe("FlaekMxe", () => {
     const addEvent = await addEventPromise;
     const decrypted = cipher.decrypt([addEvent.result], new Uint8Array(addEvent.nonce))[0];
     expect(decrypted).to.equal(val1 + val2);
+    expect(addEvent.payer.equals(owner.publicKey)).to.be.true;
+    expect(addEvent.circuit).to.equal(
+      Buffer.from(getCompDefAccOffset("add")).readUInt32LE()
+    );
+
+    const [computationResultPDA] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("computation_result"),
+        computationOffset.toArrayLike(Buffer, "le", 8),
+      ],
+      program.programId
+    );
+    const stored = await program.account.computationResult.fetch(
+      computationResultPDA
+    );
+    expect(stored.status).to.deep.equal({ completed: {} });
+    expect(stored.ciphertexts[0]).to.deep.equal(addEvent.result);
+    expect(stored.nonce).to.deep.equal(addEvent.nonce);
+
+    await program.methods
+      .closeComputationResult(computationOffset)
+      .accounts({ payer: owner.publicKey })
+      .signers([owner])
+      .rpc({ commitment: "confirmed" });
   });
 
   it("Fails without heap frame and succeeds when heap frame is requested", async () => {
     const owner = readKpJson(`${os.homedir()}/.config/solana/id.json`);
-    await initAddCompDef(program, owner, false, false).catch(() => {});
+    await initAddCompDef(
+      program,
+      owner,
+      !CIRCUITS_BASE_URL,
+      !!CIRCUITS_BASE_URL
+    ).catch(() => {});
 
     const mxePublicKey = await getMXEPublicKeyWithRetry(
       provider as anchor.AnchorProvider,
@@ -142,18 +243,6 @@ describe("FlaekMxe", () => {
     const nonce = randomBytes(16);
     const ciphertext = cipher.encrypt([BigInt(1), BigInt(2)], nonce);
 
-    const buildAccounts = (offset: anchor.BN) => ({
-      computationAccount: getComputationAccAddress(program.programId, offset),
-      clusterAccount,
-      mxeAccount: getMXEAccAddress(program.programId),
-      mempoolAccount: getMempoolAccAddress(program.programId),
-      executingPool: getExecutingPoolAccAddress(program.programId),
-      compDefAccount: getCompDefAccAddress(
-        program.programId,
-        Buffer.from(getCompDefAccOffset("add")).readUInt32LE()
-      ),
-    });
-
     const firstOffset = new anchor.BN(randomBytes(8), "hex");
     let sawOutOfMemory = false;
     try {
@@ -165,7 +254,7 @@ describe("FlaekMxe", () => {
           Array.from(publicKey),
           new anchor.BN(deserializeLE(nonce).toString())
         )
-        .accountsPartial(buildAccounts(firstOffset))
+        .accountsPartial(queueAccounts("add", firstOffset))
         .preInstructions([
           ComputeBudgetProgram.setComputeUnitLimit({ units: 1_400_000 }),
           ComputeBudgetProgram.setComputeUnitPrice({ microLamports: 1 }),
@@ -194,7 +283,7 @@ describe("FlaekMxe", () => {
         Array.from(publicKey),
         new anchor.BN(deserializeLE(nonce).toString())
       )
-      .accountsPartial(buildAccounts(secondOffset))
+      .accountsPartial(queueAccounts("add", secondOffset))
       .preInstructions([
         ComputeBudgetProgram.requestHeapFrame({ bytes: HEAP_FRAME_BYTES }),
         ComputeBudgetProgram.setComputeUnitLimit({ units: 1_400_000 }),
@@ -212,6 +301,557 @@ describe("FlaekMxe", () => {
     );
   });
 
+  describe("array-input circuits", () => {
+    const VALUES = [12, 7, 250, 3, 99, 41, 41, 8, 1000, 5].map(BigInt);
+    const WEIGHTS = [1, 2, 3, 4, 5].map(BigInt);
+    // Five yes votes among the first eight ballots; the last two are ignored.
+    const BALLOTS = [1, 0, 1, 1, 0, 1, 0, 1, 1, 1].map(BigInt);
+
+    const sorted = [...VALUES].sort((a, b) => (a < b ? -1 : a > b ? 1 : 0));
+    const total = VALUES.reduce((a, b) => a + b, BigInt(0));
+    const weightedTotal = WEIGHTS.reduce(
+      (acc, w, i) => acc + VALUES[i] * w,
+      BigInt(0)
+    );
+    const weightTotal = WEIGHTS.reduce((a, b) => a + b, BigInt(0));
+
+    const cases: {
+      circuit: string;
+      method: string;
+      event: keyof Event;
+      plaintext: bigint[];
+      split: number;
+      expected: bigint;
+      // The `Shared` argument the result is also encrypted to, if any.
+      recipient?: string;
+      // Whether the result is also revealed in the clear.
+      revealed?: boolean;
+    }[] = [
+      { circuit: "average", method: "average", event: "averageEvent", plaintext: [...VALUES, BigInt(10)], split: 10, expected: total / BigInt(10) },
+      { circuit: "sum", method: "sum", event: "sumEvent", plaintext: [...VALUES, BigInt(10)], split: 10, expected: total },
+      { circuit: "min", method: "min", event: "minEvent", plaintext: [...VALUES, BigInt(10)], split: 10, expected: sorted[0] },
+      { circuit: "max", method: "max", event: "maxEvent", plaintext: [...VALUES, BigInt(10)], split: 10, expected: sorted[9] },
+      { circuit: "median", method: "median", event: "medianEvent", plaintext: [...VALUES, BigInt(10)], split: 10, expected: (sorted[4] + sorted[5]) / BigInt(2) },
+      { circuit: "weighted_average", method: "weightedAverage", event: "weightedAverageEvent", plaintext: [...VALUES.slice(0, 5), ...WEIGHTS], split: 5, expected: weightedTotal / weightTotal, recipient: "recipient" },
+      { circuit: "vote_tally", method: "voteTally", event: "voteTallyEvent", plaintext: [...BALLOTS, BigInt(8)], split: 10, expected: BigInt(5), recipient: "tallier", revealed: true },
+    ];
+
+    for (const c of cases) {
+      it(`round-trips a full input through ${c.circuit}`, async () => {
+        const owner = readKpJson(`${os.homedir()}/.config/solana/id.json`);
+        await initCompDef(program, owner, c.circuit);
+
+        const mxePublicKey = await getMXEPublicKeyWithRetry(
+          provider as anchor.AnchorProvider,
+          program.programId
+        );
+
+        const privateKey = x25519.utils.randomSecretKey();
+        const publicKey = x25519.getPublicKey(privateKey);
+        const cipher = new RescueCipher(
+          x25519.getSharedSecret(privateKey, mxePublicKey)
+        );
+
+        const nonce = randomBytes(16);
+        const ciphertext = cipher.encrypt(c.plaintext, nonce).map((ct) => Array.from(ct));
+        const head = ciphertext.slice(0, c.split);
+        const tail = ciphertext.slice(c.split);
+
+        const recipientKey = x25519.utils.randomSecretKey();
+        const recipientNonce = randomBytes(16);
+        const recipientArgs = c.recipient
+          ? [
+              Array.from(x25519.getPublicKey(recipientKey)),
+              new anchor.BN(deserializeLE(recipientNonce).toString()),
+            ]
+          : [];
+
+        const computationOffset = new anchor.BN(randomBytes(8), "hex");
+        const eventPromise = awaitEvent(c.event, computationOffset);
+
+        await (program.methods as any)
+          [c.method](
+            computationOffset,
+            head,
+            tail.length === 1 ? tail[0] : tail,
+            ...recipientArgs,
+            Array.from(publicKey),
+            new anchor.BN(deserializeLE(nonce).toString())
+          )
+          .accountsPartial(queueAccounts(c.circuit, computationOffset))
+          .preInstructions([
+            ComputeBudgetProgram.requestHeapFrame({ bytes: HEAP_FRAME_BYTES }),
+            ComputeBudgetProgram.setComputeUnitLimit({ units: 1_400_000 }),
+          ])
+          .rpc({ skipPreflight: true, commitment: "confirmed" });
+
+        await awaitComputationFinalization(
+          provider as anchor.AnchorProvider,
+          computationOffset,
+          program.programId,
+          "confirmed"
+        );
+
+        const event: any = await eventPromise;
+        const decrypted = cipher.decrypt(
+          [event.result],
+          new Uint8Array(event.nonce)
+        )[0];
+        expect(decrypted).to.equal(c.expected);
+        if (c.recipient) {
+          const recipientCipher = new RescueCipher(
+            x25519.getSharedSecret(recipientKey, mxePublicKey)
+          );
+          const shared = recipientCipher.decrypt(
+            [event[`${c.recipient}Result`]],
+            new Uint8Array(event[`${c.recipient}Nonce`])
+          )[0];
+          expect(shared).to.equal(c.expected);
+        }
+        if (c.revealed) {
+          expect(BigInt(event.revealedResult)).to.equal(c.expected);
+        }
+      });
+    }
+  });
+
+  describe("checked circuits", () => {
+    const U64_MAX = (BigInt(1) << BigInt(64)) - BigInt(1);
+
+    const cases: {
+      circuit: string;
+      method: string;
+      event: keyof Event;
+      plaintext: bigint[];
+      expected: { result: bigint; overflow: bigint };
+    }[] = [
+      { circuit: "checked_add", method: "checkedAdd", event: "checkedAddEvent", plaintext: [U64_MAX, BigInt(1)], expected: { result: U64_MAX, overflow: BigInt(1) } },
+      { circuit: "checked_multiply", method: "checkedMultiply", event: "checkedMultiplyEvent", plaintext: [BigInt(1) << BigInt(40), BigInt(1) << BigInt(20)], expected: { result: BigInt(1) << BigInt(60), overflow: BigInt(0) } },
+      { circuit: "checked_power", method: "checkedPower", event: "checkedPowerEvent", plaintext: [BigInt(10), BigInt(20)], expected: { result: U64_MAX, overflow: BigInt(1) } },
+    ];
+
+    for (const c of cases) {
+      it(`flags overflow from ${c.circuit}`, async () => {
+        const owner = readKpJson(`${os.homedir()}/.config/solana/id.json`);
+        await initCompDef(program, owner, c.circuit);
+
+        const mxePublicKey = await getMXEPublicKeyWithRetry(
+          provider as anchor.AnchorProvider,
+          program.programId
+        );
+        const privateKey = x25519.utils.randomSecretKey();
+        const publicKey = x25519.getPublicKey(privateKey);
+        const cipher = new RescueCipher(
+          x25519.getSharedSecret(privateKey, mxePublicKey)
+        );
+
+        const nonce = randomBytes(16);
+        const [a, b] = cipher
+          .encrypt(c.plaintext, nonce)
+          .map((ct) => Array.from(ct));
+
+        const computationOffset = new anchor.BN(randomBytes(8), "hex");
+        const eventPromise = awaitEvent(c.event, computationOffset);
+
+        await (program.methods as any)
+          [c.method](
+            computationOffset,
+            a,
+            b,
+            Array.from(publicKey),
+            new anchor.BN(deserializeLE(nonce).toString())
+          )
+          .accountsPartial(queueAccounts(c.circuit, computationOffset))
+          .rpc({ skipPreflight: true, commitment: "confirmed" });
+
+        await awaitComputationFinalization(
+          provider as anchor.AnchorProvider,
+          computationOffset,
+          program.programId,
+          "confirmed"
+        );
+
+        const event: any = await eventPromise;
+        const [result, overflow] = cipher.decrypt(
+          [event.result, event.overflow],
+          new Uint8Array(event.nonce)
+        );
+        expect({ result, overflow }).to.deep.equal(c.expected);
+      });
+    }
+  });
+
+  describe("signed circuits", () => {
+    // Signed values travel as field elements; negatives wrap around the
+    // Curve25519 base field prime.
+    const P = (BigInt(1) << BigInt(255)) - BigInt(19);
+    const toField = (x: bigint) => (x < BigInt(0) ? x + P : x);
+    const fromField = (x: bigint) => (x > P / BigInt(2) ? x - P : x);
+    // Q32.32 raw encoding used by the fixed_* circuits.
+    const Q = BigInt(1) << BigInt(32);
+    const fixed = (x: number) => BigInt(Math.round(x * 2 ** 32));
+
+    const cases: {
+      circuit: string;
+      method: string;
+      event: keyof Event;
+      a: bigint;
+      b: bigint;
+      expected: bigint;
+    }[] = [
+      { circuit: "subtract_i64", method: "subtractI64", event: "subtractI64Event", a: BigInt(120), b: BigInt(450), expected: BigInt(-330) },
+      { circuit: "add_i64", method: "addI64", event: "addI64Event", a: BigInt(-700), b: BigInt(250), expected: BigInt(-450) },
+      { circuit: "divide_i64", method: "divideI64", event: "divideI64Event", a: BigInt(-7), b: BigInt(2), expected: BigInt(-3) },
+      { circuit: "abs_diff_i64", method: "absDiffI64", event: "absDiffI64Event", a: BigInt(-40), b: BigInt(2), expected: BigInt(42) },
+      { circuit: "compare_i64", method: "compareI64", event: "compareI64Event", a: BigInt(-1), b: BigInt(1), expected: BigInt(-1) },
+      { circuit: "fixed_mul", method: "fixedMul", event: "fixedMulEvent", a: fixed(1.5), b: fixed(-2.25), expected: fixed(-3.375) },
+      { circuit: "fixed_div", method: "fixedDiv", event: "fixedDivEvent", a: fixed(1), b: fixed(-8), expected: fixed(-0.125) },
+      { circuit: "fixed_percent", method: "fixedPercent", event: "fixedPercentEvent", a: fixed(1234.5), b: fixed(4), expected: (fixed(1234.5) * fixed(4)) / (BigInt(100) * Q) },
+    ];
+
+    for (const c of cases) {
+      it(`keeps the sign through ${c.circuit}`, async () => {
+        const owner = readKpJson(`${os.homedir()}/.config/solana/id.json`);
+        await initCompDef(program, owner, c.circuit);
+
+        const mxePublicKey = await getMXEPublicKeyWithRetry(
+          provider as anchor.AnchorProvider,
+          program.programId
+        );
+        const privateKey = x25519.utils.randomSecretKey();
+        const publicKey = x25519.getPublicKey(privateKey);
+        const cipher = new RescueCipher(
+          x25519.getSharedSecret(privateKey, mxePublicKey)
+        );
+
+        const nonce = randomBytes(16);
+        const [a, b] = cipher
+          .encrypt([toField(c.a), toField(c.b)], nonce)
+          .map((ct) => Array.from(ct));
+
+        const computationOffset = new anchor.BN(randomBytes(8), "hex");
+        const eventPromise = awaitEvent(c.event, computationOffset);
+
+        await (program.methods as any)
+          [c.method](
+            computationOffset,
+            a,
+            b,
+            Array.from(publicKey),
+            new anchor.BN(deserializeLE(nonce).toString())
+          )
+          .accountsPartial(queueAccounts(c.circuit, computationOffset))
+          .rpc({ skipPreflight: true, commitment: "confirmed" });
+
+        await awaitComputationFinalization(
+          provider as anchor.AnchorProvider,
+          computationOffset,
+          program.programId,
+          "confirmed"
+        );
+
+        const event: any = await eventPromise;
+        const decrypted = cipher.decrypt(
+          [event.result],
+          new Uint8Array(event.nonce)
+        )[0];
+        expect(fromField(decrypted)).to.equal(c.expected);
+      });
+    }
+  });
+
+  describe("recipient circuits", () => {
+    // Each case re-encrypts one output to a second party's key and, for
+    // credit_score, reveals the approval in the clear.
+    const cases: {
+      circuit: string;
+      method: string;
+      event: keyof Event;
+      plaintext: bigint[];
+      outputs: string[];
+      recipient: string;
+      expected: bigint[];
+      revealed?: string;
+    }[] = [
+      { circuit: "credit_score", method: "creditScore", event: "creditScoreEvent", plaintext: [BigInt(75000), BigInt(15000), BigInt(10), BigInt(0)], outputs: ["score", "approved"], recipient: "lender", expected: [BigInt(750), BigInt(1)], revealed: "revealedApproved" },
+      { circuit: "health_risk", method: "healthRisk", event: "healthRiskEvent", plaintext: [BigInt(30), BigInt(22), BigInt(0), BigInt(5), BigInt(0)], outputs: ["riskScore", "riskCategory"], recipient: "insurer", expected: [BigInt(0), BigInt(0)] },
+    ];
+
+    for (const c of cases) {
+      it(`shares ${c.circuit}'s verdict with the ${c.recipient}`, async () => {
+        const owner = readKpJson(`${os.homedir()}/.config/solana/id.json`);
+        await initCompDef(program, owner, c.circuit);
+
+        const mxePublicKey = await getMXEPublicKeyWithRetry(
+          provider as anchor.AnchorProvider,
+          program.programId
+        );
+        const privateKey = x25519.utils.randomSecretKey();
+        const publicKey = x25519.getPublicKey(privateKey);
+        const cipher = new RescueCipher(
+          x25519.getSharedSecret(privateKey, mxePublicKey)
+        );
+        const recipientKey = x25519.utils.randomSecretKey();
+        const recipientCipher = new RescueCipher(
+          x25519.getSharedSecret(recipientKey, mxePublicKey)
+        );
+
+        const nonce = randomBytes(16);
+        const recipientNonce = randomBytes(16);
+        const inputs = cipher
+          .encrypt(c.plaintext, nonce)
+          .map((ct) => Array.from(ct));
+
+        const computationOffset = new anchor.BN(randomBytes(8), "hex");
+        const eventPromise = awaitEvent(c.event, computationOffset);
+
+        await (program.methods as any)
+          [c.method](
+            computationOffset,
+            ...inputs,
+            Array.from(x25519.getPublicKey(recipientKey)),
+            new anchor.BN(deserializeLE(recipientNonce).toString()),
+            Array.from(publicKey),
+            new anchor.BN(deserializeLE(nonce).toString())
+          )
+          .accountsPartial(queueAccounts(c.circuit, computationOffset))
+          .preInstructions([
+            ComputeBudgetProgram.requestHeapFrame({ bytes: HEAP_FRAME_BYTES }),
+            ComputeBudgetProgram.setComputeUnitLimit({ units: 1_400_000 }),
+          ])
+          .rpc({ skipPreflight: true, commitment: "confirmed" });
+
+        await awaitComputationFinalization(
+          provider as anchor.AnchorProvider,
+          computationOffset,
+          program.programId,
+          "confirmed"
+        );
+
+        const event: any = await eventPromise;
+        const decrypted = cipher.decrypt(
+          c.outputs.map((field) => event[field]),
+          new Uint8Array(event.nonce)
+        );
+        expect(decrypted).to.deep.equal(c.expected);
+
+        // The recipient only gets the last output.
+        const field = c.outputs[c.outputs.length - 1];
+        const shared = recipientCipher.decrypt(
+          [event[`${c.recipient}${field[0].toUpperCase()}${field.slice(1)}`]],
+          new Uint8Array(event[`${c.recipient}Nonce`])
+        )[0];
+        expect(shared).to.equal(c.expected[c.expected.length - 1]);
+        expect(event[`${c.recipient}PubKey`]).to.deep.equal(
+          Array.from(x25519.getPublicKey(recipientKey))
+        );
+        if (c.revealed) {
+          expect(BigInt(event[c.revealed])).to.equal(c.expected[1]);
+        }
+      });
+    }
+  });
+
+  describe("pipeline", () => {
+    const OP_ADD = 0;
+    const OP_MULTIPLY = 2;
+
+    const getIntermediateAddress = (computationOffset: anchor.BN) =>
+      PublicKey.findProgramAddressSync(
+        [Buffer.from("intermediate"), computationOffset.toArrayLike(Buffer, "le", 8)],
+        program.programId
+      )[0];
+
+    const queue = async (
+      circuit: string,
+      method: string,
+      computationOffset: anchor.BN,
+      args: any[],
+      accounts: Record<string, PublicKey> = {}
+    ) => {
+      await (program.methods as any)
+        [method](computationOffset, ...args)
+        .accountsPartial({
+          ...queueAccounts(circuit, computationOffset),
+          ...accounts,
+        })
+        .rpc({ skipPreflight: true, commitment: "confirmed" });
+    };
+
+    const finalize = (computationOffset: anchor.BN) =>
+      awaitComputationFinalization(
+        provider as anchor.AnchorProvider,
+        computationOffset,
+        program.programId,
+        "confirmed"
+      );
+
+    it("chains sealed intermediates without decrypting in between", async () => {
+      const owner = readKpJson(`${os.homedir()}/.config/solana/id.json`);
+      for (const circuit of ["seal", "pipeline_step", "unseal"]) {
+        await initCompDef(program, owner, circuit);
+      }
+
+      const mxePublicKey = await getMXEPublicKeyWithRetry(
+        provider as anchor.AnchorProvider,
+        program.programId
+      );
+      const privateKey = x25519.utils.randomSecretKey();
+      const publicKey = x25519.getPublicKey(privateKey);
+      const cipher = new RescueCipher(
+        x25519.getSharedSecret(privateKey, mxePublicKey)
+      );
+
+      const seal = async (value: bigint) => {
+        const nonce = randomBytes(16);
+        const [ciphertext] = cipher.encrypt([value], nonce);
+        const computationOffset = new anchor.BN(randomBytes(8), "hex");
+        await queue("seal", "seal", computationOffset, [
+          Array.from(ciphertext),
+          Array.from(publicKey),
+          new anchor.BN(deserializeLE(nonce).toString()),
+        ]);
+        await finalize(computationOffset);
+        return getIntermediateAddress(computationOffset);
+      };
+
+      const step = async (op: number, lhs: PublicKey, rhs: PublicKey) => {
+        const computationOffset = new anchor.BN(randomBytes(8), "hex");
+        await queue("pipeline_step", "pipelineStep", computationOffset, [op], {
+          lhs,
+          rhs,
+        });
+        await finalize(computationOffset);
+        const intermediate = getIntermediateAddress(computationOffset);
+        const account = await program.account.intermediate.fetch(intermediate);
+        expect(account.status).to.deep.equal({ completed: {} });
+        return intermediate;
+      };
+
+      // (7 + 5) * 3
+      const a = await seal(BigInt(7));
+      const b = await seal(BigInt(5));
+      const c = await seal(BigInt(3));
+      const sum = await step(OP_ADD, a, b);
+      const product = await step(OP_MULTIPLY, sum, c);
+
+      const nonce = randomBytes(16);
+      const computationOffset = new anchor.BN(randomBytes(8), "hex");
+      const eventPromise = awaitEvent("unsealEvent", computationOffset);
+      await queue(
+        "unseal",
+        "unseal",
+        computationOffset,
+        [Array.from(publicKey), new anchor.BN(deserializeLE(nonce).toString())],
+        { value: product }
+      );
+      await finalize(computationOffset);
+
+      const event: any = await eventPromise;
+      const decrypted = cipher.decrypt(
+        [event.result],
+        new Uint8Array(event.nonce)
+      )[0];
+      expect(decrypted).to.equal(BigInt(36));
+    });
+
+    it("evaluates a multi-block program in one computation", async () => {
+      const owner = readKpJson(`${os.homedir()}/.config/solana/id.json`);
+      await initCompDef(program, owner, "pipeline_eval");
+
+      const mxePublicKey = await getMXEPublicKeyWithRetry(
+        provider as anchor.AnchorProvider,
+        program.programId
+      );
+      const privateKey = x25519.utils.randomSecretKey();
+      const publicKey = x25519.getPublicKey(privateKey);
+      const cipher = new RescueCipher(
+        x25519.getSharedSecret(privateKey, mxePublicKey)
+      );
+
+      // income r0, debt r1, threshold r2
+      // r3 = r0 - r1; r4 = r3 > r2; r5 = r4 ? r3 : r5; r6 = r3 * r4
+      const registers = [5000, 1200, 2500, 0, 0, 0, 0, 0].map(BigInt);
+      const instructions = [
+        [2, 3, 0, 1],
+        [7, 4, 3, 2],
+        [13, 5, 4, 3],
+        [3, 6, 3, 4],
+      ];
+      const programBytes = new Array(64).fill(0);
+      instructions.flat().forEach((b, i) => (programBytes[i] = b));
+
+      const nonce = randomBytes(16);
+      const ciphertext = cipher.encrypt(registers, nonce).map((ct) => Array.from(ct));
+      const computationOffset = new anchor.BN(randomBytes(8), "hex");
+      const eventPromise = awaitEvent("pipelineEvalEvent", computationOffset);
+      await queue("pipeline_eval", "pipelineEval", computationOffset, [
+        programBytes,
+        ciphertext,
+        Array.from(publicKey),
+        new anchor.BN(deserializeLE(nonce).toString()),
+      ]);
+      await finalize(computationOffset);
+
+      const event: any = await eventPromise;
+      const decrypted = cipher.decrypt(event.registers, new Uint8Array(event.nonce));
+      expect(decrypted).to.deep.equal(
+        [5000, 1200, 2500, 3800, 1, 3800, 3800, 0].map(BigInt)
+      );
+    });
+  });
+
+  async function initCompDef(
+    program: Program<FlaekMxe>,
+    owner: anchor.web3.Keypair,
+    circuitName: string
+  ): Promise<string | undefined> {
+    const offset = getCompDefAccOffset(circuitName);
+    const compDefPDA = PublicKey.findProgramAddressSync(
+      [
+        getArciumAccountBaseSeed("ComputationDefinitionAccount"),
+        program.programId.toBuffer(),
+        offset,
+      ],
+      getArciumProgAddress()
+    )[0];
+
+    const methodName = `init${circuitName
+      .split("_")
+      .map((word) => word.charAt(0).toUpperCase() + word.slice(1))
+      .join("")}CompDef`;
+
+    let sig: string | undefined;
+    try {
+      sig = await (program.methods as any)
+        [methodName](CIRCUITS_BASE_URL)
+        .accountsPartial({
+          compDefAccount: compDefPDA,
+          circuitRegistry: getCircuitRegistryAddress(circuitName),
+          payer: owner.publicKey,
+          mxeAccount: getMXEAccAddress(program.programId),
+        })
+        .signers([owner])
+        .rpc({ commitment: "confirmed" });
+    } catch (err: any) {
+      if (/already in use/i.test(String(err?.message ?? ""))) {
+        return undefined;
+      }
+      throw err;
+    }
+
+    if (!CIRCUITS_BASE_URL) {
+      await uploadCircuit(
+        provider as anchor.AnchorProvider,
+        circuitName,
+        program.programId,
+        fs.readFileSync(`build/${circuitName}.arcis`),
+        true
+      );
+    }
+    return sig;
+  }
+
   async function initAddCompDef(
     program: Program<FlaekMxe>,
     owner: anchor.web3.Keypair,
@@ -233,9 +873,10 @@ describe("FlaekMxe", () => {
     let sig: string | undefined;
     try {
       sig = await program.methods
-        .initAddCompDef()
-        .accounts({
+        .initAddCompDef(offchainSource ? CIRCUITS_BASE_URL : null)
+        .accountsPartial({
           compDefAccount: compDefPDA,
+          circuitRegistry: getCircuitRegistryAddress("add"),
           payer: owner.publicKey,
           mxeAccount: getMXEAccAddress(program.programId),
         })